@@ -32,6 +32,12 @@ pub struct SupportedBlockchain {
     pub prefix: String,
     pub grpc_service: GRPC_Service,
     pub governance_proposals_link: String,
+    // CometBFT RPC endpoints (chain-registry `apis.rpc`), used for websocket subscriptions.
+    #[serde(default)]
+    pub rpc_urls: Vec<String>,
+    // Official websites (chain-registry `website`), trusted by the phishing detector.
+    #[serde(default)]
+    pub websites: Vec<String>,
 }
 
 impl PartialEq for SupportedBlockchain {
@@ -53,6 +59,24 @@ pub struct GRPC_Service {
     pub errors: Vec<String>,  // error msg if no url could be selected
 }
 
+// Osmosis without gRPC urls, shared by the unit tests.
+#[cfg(test)]
+pub fn test_blockchain() -> SupportedBlockchain {
+    SupportedBlockchain {
+        display: "Osmosis".to_string(),
+        name: "osmosis".to_string(),
+        prefix: "osmo".to_string(),
+        grpc_service: GRPC_Service {
+            grpc_urls: vec![],
+            errors: vec![],
+        },
+        rank: 1,
+        governance_proposals_link: "".to_string(),
+        rpc_urls: vec![],
+        websites: vec![],
+    }
+}
+
 impl SupportedBlockchain {
     pub fn get_name(&self) -> String {
         self.name.to_lowercase()
@@ -320,33 +344,74 @@ pub fn prepare_blockchain_list(
 }
 
 
-pub fn get_channel_list(
+// Endpoints and websites of a supported blockchain, merged from its chain-registry `chain.json` and the hard coded ones.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ChainRegistryUrls {
+    pub grpc_urls: Vec<String>,
+    pub rpc_urls: Vec<String>,
+    pub websites: Vec<String>,
+}
+
+fn read_chain_json(git_path: &str, name: &str) -> anyhow::Result<serde_json::Value> {
+    let chain_json = format!("{}/{}/chain.json", git_path, name);
+    let file = File::open(&chain_json)
+        .map_err(|err| anyhow::anyhow!("{}, File: {}", err.to_string(), chain_json))?;
+    let reader = BufReader::new(file);
+    serde_json::from_reader(reader)
+        .map_err(|err| anyhow::anyhow!("{}, File: {}", err.to_string(), chain_json))
+}
+
+// Reads every `chain.json` once.
+pub fn get_chain_registry_urls(
     git_path: &str,
     supported_blockchains: &HashMap<String, SupportedBlockchain>
-) -> anyhow::Result<Vec<(String, Vec<String>)>> {
-    let mut list: Vec<(String, Vec<String>)> = Vec::new();
+) -> anyhow::Result<Vec<(String, ChainRegistryUrls)>> {
+    let mut list: Vec<(String, ChainRegistryUrls)> = Vec::new();
 
     for (k, v) in supported_blockchains.iter() {
-        let chain_json = format!("{}/{}/chain.json", &git_path, k);
-        let file = File::open(&chain_json)
-            .map_err(|err| anyhow::anyhow!("{}, File: {}", err.to_string(), chain_json))?;
-        let reader = BufReader::new(file);
-        let chain_info: chain_registry::chain::ChainInfo = serde_json::from_reader(reader)?;
+        let chain_json = read_chain_json(git_path, k)?;
+        let chain_info = chain_registry::chain::ChainInfo::deserialize(&chain_json)?;
 
-        let mut try_these_grpc_urls: Vec<String> = chain_info
+        let mut grpc_urls: Vec<String> = chain_info
             .apis
             .grpc
             .iter()
             .map(|x| x.address.clone())
             .collect();
         for hard_coded_grpc_url in v.grpc_service.grpc_urls.iter() {
-            try_these_grpc_urls.push(hard_coded_grpc_url.to_owned());
+            grpc_urls.push(hard_coded_grpc_url.to_owned());
+        }
+
+        let mut rpc_urls: Vec<String> = v.rpc_urls.clone();
+        for rpc in chain_info.apis.rpc.iter() {
+            if !rpc_urls.contains(&rpc.address) {
+                rpc_urls.push(rpc.address.clone());
+            }
+        }
+
+        // `website` is not part of `chain_registry::chain::ChainInfo`.
+        let mut websites: Vec<String> = v.websites.clone();
+        if let Some(website) = chain_json.get("website").and_then(|x| x.as_str()) {
+            if !website.is_empty() && !websites.iter().any(|x| x == website) {
+                websites.push(website.to_string());
+            }
         }
-        list.push((k.clone(), try_these_grpc_urls));
+
+        list.push((k.clone(), ChainRegistryUrls { grpc_urls, rpc_urls, websites }));
     }
     Ok(list)
 }
 
+pub fn get_channel_list(
+    git_path: &str,
+    supported_blockchains: &HashMap<String, SupportedBlockchain>
+) -> anyhow::Result<Vec<(String, Vec<String>)>> {
+    Ok(get_chain_registry_urls(git_path, supported_blockchains)?
+        .into_iter()
+        .map(|(k, urls)| (k, urls.grpc_urls))
+        .collect())
+}
+
 pub struct SupportedBlockchainIter {
     check_urls: Pin<Box<dyn Stream<Item=(String, Result<String, anyhow::Error>)> + Send>>,
     supported_blockchains: HashMap<String, SupportedBlockchain>,
@@ -360,8 +425,15 @@ impl SupportedBlockchainIter {
         sync_interval_in_secs: Option<u64>,
     ) -> anyhow::Result<Self> {
         update_git(git_path, git_pull, sync_interval_in_secs)?;
-        let supported_blockchains = prepare_blockchain_list(json_path)?;
-        let list = get_channel_list(git_path,&supported_blockchains)?;
+        let mut supported_blockchains = prepare_blockchain_list(json_path)?;
+        let mut list: Vec<(String, Vec<String>)> = Vec::new();
+        for (k, urls) in get_chain_registry_urls(git_path, &supported_blockchains)? {
+            if let Some(v) = supported_blockchains.get_mut(&k) {
+                v.rpc_urls = urls.rpc_urls;
+                v.websites = urls.websites;
+            }
+            list.push((k, urls.grpc_urls));
+        }
         let check_urls = CheckUrls::stream(list);
 
         Ok(Self {
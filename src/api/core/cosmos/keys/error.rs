@@ -30,6 +30,8 @@ pub enum TerraRustScriptError {
     Bech32DecodeErr,
     #[error("Bech32 Decode Error: Key Failed prefix {0} or length {1} Wanted:{2}/{3}")]
     Bech32DecodeExpanded(String, usize, String, usize),
+    #[error("Bech32 Decode Error: Key prefix {0} does not end with {1}")]
+    Bech32SuffixMismatch(String, String),
     #[error("Mnemonic - Bad Phrase")]
     Phrasing,
     #[error("Mnemonic - Missing Phrase")]
@@ -11,6 +11,7 @@ use super::error::TerraRustScriptError;
 static BECH32_PUBKEY_DATA_PREFIX_SECP256K1: [u8; 5] = [0xeb, 0x5a, 0xe9, 0x87, 0x21];
 // "eb5ae98721";
 static BECH32_PUBKEY_DATA_PREFIX_ED25519: [u8; 5] = [0x16, 0x24, 0xde, 0x64, 0x20]; // "eb5ae98721";
+static OPERATOR_PREFIX_SUFFIX: &str = "valoper";
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
 /// The public key we used to generate the cosmos/tendermind/terrad addresses
@@ -144,8 +145,9 @@ impl PublicKey {
         }
     }
     /// Generate a Operator address for this public key (used by the validator)
+    /// Accepts the operator address of any chain, e.g. `terravaloper1..` or `cosmosvaloper1..`.
     pub fn from_operator_address(valoper_address: &str) -> Result<PublicKey, TerraRustScriptError> {
-        PublicKey::check_prefix_and_length("terravaloper", valoper_address, 51).and_then(|vu5| {
+        PublicKey::check_suffix(OPERATOR_PREFIX_SUFFIX, valoper_address).and_then(|vu5| {
             let vu8 = Vec::from_base32(vu5.as_slice()).map_err(|source| {
                 TerraRustScriptError::Conversion {
                     key: valoper_address.into(),
@@ -168,6 +170,18 @@ impl PublicKey {
             raw_address: Some(vec1),
         })
     }
+    fn check_suffix(suffix: &str, data: &str) -> Result<Vec<u5>, TerraRustScriptError> {
+        let (hrp, decoded_str, _) =
+            decode(data).map_err(|source| TerraRustScriptError::Conversion {
+                key: data.into(),
+                source,
+            })?;
+        if hrp.ends_with(suffix) && hrp.len() > suffix.len() {
+            Ok(decoded_str)
+        } else {
+            Err(TerraRustScriptError::Bech32SuffixMismatch(hrp, suffix.into()))
+        }
+    }
     fn check_prefix_and_length(
         prefix: &str,
         data: &str,
@@ -1,5 +1,9 @@
 use cosmos_sdk_proto::cosmos::tx::v1beta1::service_client::ServiceClient;
-use cosmos_sdk_proto::cosmos::tx::v1beta1::SimulateRequest;
+use cosmos_sdk_proto::cosmos::base::abci::v1beta1::TxResponse;
+use cosmos_sdk_proto::cosmos::tx::v1beta1::{
+    BroadcastMode, BroadcastTxRequest, SimulateRequest, SimulateResponse,
+};
+use tonic::transport::Channel;
 
 use cosmos_sdk_proto::Any;
 use cosmrs::tx::Fee;
@@ -12,6 +16,8 @@ use cosmos_sdk_proto::cosmos::crypto::secp256k1::PubKey;
 use cosmos_sdk_proto::cosmwasm::wasm::v1::MsgExecuteContract;
 
 use cosmrs::tendermint::chain::Id;
+use crypto::digest::Digest;
+use crypto::sha2::Sha256;
 
 //use moneymarket::market::ExecuteMsg;
 
@@ -24,6 +30,7 @@ use std::str::FromStr;
 
 pub mod channels;
 pub mod query;
+pub mod websocket;
 
 /*
 /// Chain ID to use for tests
@@ -78,6 +85,24 @@ pub fn public_key_from_seed_phrase(seed_phrase: String) -> anyhow::Result<keys::
     Ok(pk?.public_key(&s))
 }
 
+pub fn account_from_operator_address(valoper_address: &str, prefix: &str) -> anyhow::Result<String> {
+    let public_key = keys::public::PublicKey::from_operator_address(valoper_address)?;
+    Ok(public_key.account(prefix)?)
+}
+
+/// Address of a module account, e.g. the gov module authority (`module_address("gov", "cosmos")`).
+pub fn module_address(module_name: &str, prefix: &str) -> anyhow::Result<String> {
+    let mut sha = Sha256::new();
+    sha.input(module_name.as_bytes());
+    let mut hash = [0u8; 32];
+    sha.result(&mut hash);
+    let public_key = keys::public::PublicKey {
+        raw_pub_key: None,
+        raw_address: Some(hash[..20].to_vec()),
+    };
+    Ok(public_key.account(prefix)?)
+}
+
 pub fn private_key_from_seed_phrase(
     seed_phrase: String,
 ) -> anyhow::Result<cosmrs::crypto::secp256k1::SigningKey> {
@@ -179,6 +204,47 @@ pub async fn simulate_tx(tx_bytes: Vec<u8>) -> anyhow::Result<()> {
     //Ok(gas_used)
 }
 
+pub fn sign_tx(
+    tx_body: &cosmrs::tx::Body,
+    auth_info: &AuthInfo,
+    chain_id: &str,
+    account_number: u64,
+    signing_key: &cosmrs::crypto::secp256k1::SigningKey,
+) -> anyhow::Result<Vec<u8>> {
+    let sign_doc = SignDoc::new(tx_body, auth_info, &Id::try_from(chain_id)?, account_number)
+        .map_err(|err| anyhow::anyhow!("Error: Failed to create sign doc: {}", err))?;
+    let tx_raw = sign_doc
+        .sign(signing_key)
+        .map_err(|err| anyhow::anyhow!("Error: Failed to sign transaction: {}", err))?;
+    tx_raw
+        .to_bytes()
+        .map_err(|err| anyhow::anyhow!("Error: Failed to encode transaction: {}", err))
+}
+
+pub async fn simulate(channel: Channel, tx_bytes: Vec<u8>) -> anyhow::Result<SimulateResponse> {
+    #[allow(deprecated)]
+    let res = ServiceClient::new(channel)
+        .simulate(SimulateRequest {
+            tx: None, // deprecated
+            tx_bytes,
+        })
+        .await?
+        .into_inner();
+    Ok(res)
+}
+
+pub async fn broadcast_tx(channel: Channel, tx_bytes: Vec<u8>) -> anyhow::Result<TxResponse> {
+    let res = ServiceClient::new(channel)
+        .broadcast_tx(BroadcastTxRequest {
+            tx_bytes,
+            mode: BroadcastMode::Sync as i32,
+        })
+        .await?
+        .into_inner();
+    res.tx_response
+        .ok_or(anyhow::anyhow!("Error: Broadcast returned no tx response."))
+}
+
 /*
 pub async fn pipes() -> anyhow::Result<()> {
     let account = query_account("terra18m6x653kj67jfsn9f9st97esp8l556swh3ty0d".to_string()).await?;
@@ -0,0 +1,28 @@
+use tonic::transport::Channel;
+
+use cosmos_sdk_proto::cosmos::bank::v1beta1::query_client::QueryClient;
+use cosmos_sdk_proto::cosmos::bank::v1beta1::{
+    QueryAllBalancesRequest, QueryAllBalancesResponse, QueryParamsRequest, QueryParamsResponse,
+};
+
+pub async fn get_params(channel: Channel) -> anyhow::Result<QueryParamsResponse> {
+    let res = QueryClient::new(channel)
+        .params(QueryParamsRequest {})
+        .await?
+        .into_inner();
+    Ok(res)
+}
+
+pub async fn get_all_balances(
+    channel: Channel,
+    address: String,
+) -> anyhow::Result<QueryAllBalancesResponse> {
+    let res = QueryClient::new(channel)
+        .all_balances(QueryAllBalancesRequest {
+            address,
+            pagination: None,
+        })
+        .await?
+        .into_inner();
+    Ok(res)
+}
@@ -4,13 +4,18 @@ use tonic::transport::Channel;
 
 use cosmos_sdk_proto::cosmos::gov::v1::query_client::QueryClient;
 use cosmos_sdk_proto::cosmos::gov::v1::{
-    QueryProposalsRequest, QueryProposalsResponse,
-    QueryTallyResultRequest, QueryTallyResultResponse, QueryProposalRequest, QueryProposalResponse
+    QueryParamsRequest, QueryParamsResponse, QueryProposalsRequest, QueryProposalsResponse,
+    QueryTallyResultRequest, QueryTallyResultResponse, QueryProposalRequest, QueryProposalResponse,
+    QueryVotesRequest, QueryVotesResponse, QueryVoteRequest, QueryVoteResponse,
+    QueryDepositsRequest, QueryDepositsResponse
 };
 use cosmos_sdk_proto::cosmos::gov::v1beta1::query_client::QueryClient as QueryClientV1Beta1;
 use cosmos_sdk_proto::cosmos::gov::v1beta1::{
     QueryParamsRequest as QueryParamsRequestV1Beta1, QueryParamsResponse as QueryParamsResponseV1Beta1, QueryProposalResponse as QueryProposalResponseV1Beta1, QueryProposalRequest as QueryProposalRequestV1Beta1, QueryProposalsRequest as QueryProposalsRequestV1Beta1, QueryProposalsResponse as QueryProposalsResponseV1Beta1,
     QueryTallyResultRequest as QueryTallyResultRequestV1Beta1, QueryTallyResultResponse as QueryTallyResultResponseV1Beta1,
+    QueryVotesRequest as QueryVotesRequestV1Beta1, QueryVotesResponse as QueryVotesResponseV1Beta1,
+    QueryVoteRequest as QueryVoteRequestV1Beta1, QueryVoteResponse as QueryVoteResponseV1Beta1,
+    QueryDepositsRequest as QueryDepositsRequestV1Beta1, QueryDepositsResponse as QueryDepositsResponseV1Beta1,
 };
 
 pub async fn get_params_v1beta1(
@@ -24,6 +29,41 @@ pub async fn get_params_v1beta1(
     Ok(res)
 }
 
+pub async fn get_params_v1(
+    channel: Channel,
+    query_params_request: QueryParamsRequest,
+) -> anyhow::Result<QueryParamsResponse> {
+    let res = QueryClient::new(channel)
+        .params(query_params_request)
+        .await?
+        .into_inner();
+    Ok(res)
+}
+
+/// Calls `/cosmos.gov.v1.Query/Params` decoding the response into `T`.
+///
+/// The generated `QueryClient` drops fields that were added to `QueryParamsResponse` after the
+/// cosmos-sdk-proto version in use (e.g. the unified `params` of cosmos-sdk v0.47+).
+pub async fn get_params_v1_as<T>(
+    channel: Channel,
+    query_params_request: QueryParamsRequest,
+) -> anyhow::Result<T>
+where
+    T: prost::Message + Default + Send + 'static,
+{
+    let mut grpc = tonic::client::Grpc::new(channel);
+    grpc.ready().await?;
+    let res = grpc
+        .unary(
+            tonic::Request::new(query_params_request),
+            tonic::codegen::http::uri::PathAndQuery::from_static("/cosmos.gov.v1.Query/Params"),
+            tonic::codec::ProstCodec::<QueryParamsRequest, T>::default(),
+        )
+        .await?
+        .into_inner();
+    Ok(res)
+}
+
 pub async fn get_tally_result_v1beta1(
     channel: Channel,
     query_tally_result_request: QueryTallyResultRequestV1Beta1,
@@ -92,6 +132,72 @@ pub async fn get_proposal_v1(
     Ok(res)
 }
 
+pub async fn get_votes_v1beta1(
+    channel: Channel,
+    query_votes_request: QueryVotesRequestV1Beta1,
+) -> Result<QueryVotesResponseV1Beta1, tonic::Status> {
+    let res = QueryClientV1Beta1::new(channel)
+        .votes(query_votes_request)
+        .await?
+        .into_inner();
+    Ok(res)
+}
+
+pub async fn get_votes_v1(
+    channel: Channel,
+    query_votes_request: QueryVotesRequest,
+) -> Result<QueryVotesResponse, tonic::Status> {
+    let res = QueryClient::new(channel)
+        .votes(query_votes_request)
+        .await?
+        .into_inner();
+    Ok(res)
+}
+
+pub async fn get_vote_v1beta1(
+    channel: Channel,
+    query_vote_request: QueryVoteRequestV1Beta1,
+) -> Result<QueryVoteResponseV1Beta1, tonic::Status> {
+    let res = QueryClientV1Beta1::new(channel)
+        .vote(query_vote_request)
+        .await?
+        .into_inner();
+    Ok(res)
+}
+
+pub async fn get_vote_v1(
+    channel: Channel,
+    query_vote_request: QueryVoteRequest,
+) -> Result<QueryVoteResponse, tonic::Status> {
+    let res = QueryClient::new(channel)
+        .vote(query_vote_request)
+        .await?
+        .into_inner();
+    Ok(res)
+}
+
+pub async fn get_deposits_v1beta1(
+    channel: Channel,
+    query_deposits_request: QueryDepositsRequestV1Beta1,
+) -> Result<QueryDepositsResponseV1Beta1, tonic::Status> {
+    let res = QueryClientV1Beta1::new(channel)
+        .deposits(query_deposits_request)
+        .await?
+        .into_inner();
+    Ok(res)
+}
+
+pub async fn get_deposits_v1(
+    channel: Channel,
+    query_deposits_request: QueryDepositsRequest,
+) -> Result<QueryDepositsResponse, tonic::Status> {
+    let res = QueryClient::new(channel)
+        .deposits(query_deposits_request)
+        .await?
+        .into_inner();
+    Ok(res)
+}
+
 /*
 #[cfg(test)]
 mod test {
@@ -0,0 +1,29 @@
+use tonic::transport::Channel;
+
+use cosmos_sdk_proto::ibc::core::client::v1::query_client::QueryClient;
+use cosmos_sdk_proto::ibc::core::client::v1::{
+    QueryClientStateRequest, QueryClientStateResponse, QueryClientStatusRequest,
+    QueryClientStatusResponse,
+};
+
+pub async fn get_client_state(
+    channel: Channel,
+    query_client_state_request: QueryClientStateRequest,
+) -> anyhow::Result<QueryClientStateResponse> {
+    let res = QueryClient::new(channel)
+        .client_state(query_client_state_request)
+        .await?
+        .into_inner();
+    Ok(res)
+}
+
+pub async fn get_client_status(
+    channel: Channel,
+    query_client_status_request: QueryClientStatusRequest,
+) -> anyhow::Result<QueryClientStatusResponse> {
+    let res = QueryClient::new(channel)
+        .client_status(query_client_status_request)
+        .await?
+        .into_inner();
+    Ok(res)
+}
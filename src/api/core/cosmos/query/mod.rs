@@ -1,6 +1,14 @@
 pub mod auth;
+pub mod bank;
+pub mod distribution;
 pub mod gov;
+pub mod ibc;
+pub mod mint;
+pub mod params;
+pub mod slashing;
 pub mod staking;
+pub mod tendermint;
+pub mod upgrade;
 
 use cosmos_sdk_proto::cosmwasm::wasm::v1::query_client::QueryClient;
 use cosmos_sdk_proto::cosmwasm::wasm::v1::*;
@@ -19,6 +27,25 @@ pub async fn get_contract_info(
     Ok(res)
 }
 
+pub async fn get_code(channel: Channel, code_id: u64) -> anyhow::Result<QueryCodeResponse> {
+    let res = QueryClient::new(channel)
+        .code(QueryCodeRequest { code_id })
+        .await?
+        .into_inner();
+    Ok(res)
+}
+
+pub async fn get_contract_history(
+    channel: Channel,
+    query_contract_history_request: QueryContractHistoryRequest,
+) -> anyhow::Result<QueryContractHistoryResponse> {
+    let res = QueryClient::new(channel)
+        .contract_history(query_contract_history_request)
+        .await?
+        .into_inner();
+    Ok(res)
+}
+
 pub async fn get_smart_contract_state<T: ?Sized + serde::Serialize>(
     channel: Channel,
     address: String,
@@ -34,3 +61,11 @@ pub async fn get_smart_contract_state<T: ?Sized + serde::Serialize>(
     //println!("{:?}", &res);
     Ok(res)
 }
+
+pub async fn get_wasm_params(channel: Channel) -> anyhow::Result<QueryParamsResponse> {
+    let res = QueryClient::new(channel)
+        .params(QueryParamsRequest {})
+        .await?
+        .into_inner();
+    Ok(res)
+}
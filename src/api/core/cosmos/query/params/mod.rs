@@ -0,0 +1,15 @@
+use tonic::transport::Channel;
+
+use cosmos_sdk_proto::cosmos::params::v1beta1::query_client::QueryClient;
+use cosmos_sdk_proto::cosmos::params::v1beta1::{QueryParamsRequest, QueryParamsResponse};
+
+pub async fn get_params(
+    channel: Channel,
+    query_params_request: QueryParamsRequest,
+) -> anyhow::Result<QueryParamsResponse> {
+    let res = QueryClient::new(channel)
+        .params(query_params_request)
+        .await?
+        .into_inner();
+    Ok(res)
+}
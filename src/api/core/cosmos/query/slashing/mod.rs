@@ -0,0 +1,12 @@
+use tonic::transport::Channel;
+
+use cosmos_sdk_proto::cosmos::slashing::v1beta1::query_client::QueryClient;
+use cosmos_sdk_proto::cosmos::slashing::v1beta1::{QueryParamsRequest, QueryParamsResponse};
+
+pub async fn get_params(channel: Channel) -> anyhow::Result<QueryParamsResponse> {
+    let res = QueryClient::new(channel)
+        .params(QueryParamsRequest {})
+        .await?
+        .into_inner();
+    Ok(res)
+}
@@ -3,7 +3,8 @@ use tonic::transport::Channel;
 use cosmos_sdk_proto::cosmos::staking::v1beta1::query_client::QueryClient as StakingQueryClient;
 use cosmos_sdk_proto::cosmos::staking::v1beta1::QueryPoolResponse;
 use cosmos_sdk_proto::cosmos::staking::v1beta1::{
-    QueryPoolRequest, QueryValidatorsRequest, QueryValidatorsResponse,
+    QueryParamsRequest, QueryParamsResponse, QueryPoolRequest, QueryValidatorsRequest,
+    QueryValidatorsResponse,
 };
 
 pub async fn get_pool(channel: Channel) -> anyhow::Result<QueryPoolResponse> {
@@ -24,3 +25,11 @@ pub async fn get_validators(
         .into_inner();
     Ok(res)
 }
+
+pub async fn get_params(channel: Channel) -> anyhow::Result<QueryParamsResponse> {
+    let res = StakingQueryClient::new(channel)
+        .params(QueryParamsRequest {})
+        .await?
+        .into_inner();
+    Ok(res)
+}
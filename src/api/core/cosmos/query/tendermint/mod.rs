@@ -0,0 +1,26 @@
+use tonic::transport::Channel;
+
+use cosmos_sdk_proto::cosmos::base::tendermint::v1beta1::service_client::ServiceClient;
+use cosmos_sdk_proto::cosmos::base::tendermint::v1beta1::{
+    GetBlockByHeightRequest, GetBlockByHeightResponse, GetLatestBlockRequest,
+    GetLatestBlockResponse,
+};
+
+pub async fn get_latest_block(channel: Channel) -> anyhow::Result<GetLatestBlockResponse> {
+    let res = ServiceClient::new(channel)
+        .get_latest_block(GetLatestBlockRequest {})
+        .await?
+        .into_inner();
+    Ok(res)
+}
+
+pub async fn get_block_by_height(
+    channel: Channel,
+    height: i64,
+) -> anyhow::Result<GetBlockByHeightResponse> {
+    let res = ServiceClient::new(channel)
+        .get_block_by_height(GetBlockByHeightRequest { height })
+        .await?
+        .into_inner();
+    Ok(res)
+}
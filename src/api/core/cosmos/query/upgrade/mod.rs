@@ -0,0 +1,14 @@
+use tonic::transport::Channel;
+
+use cosmos_sdk_proto::cosmos::upgrade::v1beta1::query_client::QueryClient;
+use cosmos_sdk_proto::cosmos::upgrade::v1beta1::{
+    QueryCurrentPlanRequest, QueryCurrentPlanResponse,
+};
+
+pub async fn get_current_plan(channel: Channel) -> anyhow::Result<QueryCurrentPlanResponse> {
+    let res = QueryClient::new(channel)
+        .current_plan(QueryCurrentPlanRequest {})
+        .await?
+        .into_inner();
+    Ok(res)
+}
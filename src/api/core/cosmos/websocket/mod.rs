@@ -0,0 +1,284 @@
+use std::collections::HashSet;
+use std::pin::Pin;
+use std::time::Duration;
+
+use futures::stream::Stream;
+use futures::task::*;
+use futures::{SinkExt, StreamExt};
+use log::{debug, error, info};
+use serde_json::{json, Value};
+use tokio::sync::mpsc;
+use tokio_tungstenite::tungstenite::Message;
+
+/// Websocket endpoint of a CometBFT RPC url, e.g. `https://rpc.cosmos.network:443` → `wss://rpc.cosmos.network:443/websocket`.
+pub fn to_websocket_url(rpc_url: &str) -> String {
+    let rpc_url = rpc_url.trim().trim_end_matches('/');
+    let url = if let Some(rest) = rpc_url.strip_prefix("https://") {
+        format!("wss://{}", rest)
+    } else if let Some(rest) = rpc_url.strip_prefix("http://") {
+        format!("ws://{}", rest)
+    } else if rpc_url.starts_with("wss://") || rpc_url.starts_with("ws://") {
+        rpc_url.to_string()
+    } else {
+        format!("wss://{}", rpc_url)
+    };
+    if url.ends_with("/websocket") {
+        url
+    } else {
+        format!("{}/websocket", url)
+    }
+}
+
+// Exponential reconnect delay, reset once a subscription is confirmed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Backoff {
+    initial: Duration,
+    max: Duration,
+    current: Duration,
+}
+
+impl Backoff {
+    pub fn new(initial: Duration, max: Duration) -> Self {
+        Self {
+            initial,
+            max,
+            current: initial,
+        }
+    }
+
+    pub fn next(&mut self) -> Duration {
+        let delay = self.current;
+        self.current = (self.current * 2).min(self.max);
+        delay
+    }
+
+    pub fn reset(&mut self) {
+        self.current = self.initial;
+    }
+}
+
+impl Default for Backoff {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(1), Duration::from_secs(60))
+    }
+}
+
+/// Subscription to the CometBFT `/websocket` endpoint.
+///
+/// Yields the `result` of every event notification (with `query`, `data` and the flattened `events`).
+/// The connection is re-established with `backoff`, trying the urls in turn, and all queries are subscribed again.
+/// Queries rejected by a node are not subscribed again, the subscription ends when it is dropped or all queries are rejected.
+pub struct RpcSubscription {
+    receiver: mpsc::Receiver<Value>,
+    handle: tokio::task::JoinHandle<()>,
+}
+
+impl RpcSubscription {
+    pub fn subscribe(websocket_urls: Vec<String>, queries: Vec<String>, mut backoff: Backoff) -> Self {
+        let (sender, receiver) = mpsc::channel(1000);
+        let handle = tokio::spawn(async move {
+            if websocket_urls.is_empty() {
+                error!("No websocket url to subscribe to: {:?}", queries);
+                return;
+            }
+            let mut rejected: HashSet<usize> = HashSet::new();
+            let mut index = 0usize;
+            loop {
+                let url = &websocket_urls[index % websocket_urls.len()];
+                if let Err(err) = run_subscription(url, &queries, &mut rejected, &sender, &mut backoff).await {
+                    error!("Websocket subscription to {} failed: {}", url, err);
+                }
+                if sender.is_closed() {
+                    return;
+                }
+                if rejected.len() == queries.len() {
+                    error!("No query left to subscribe to: {:?}", queries);
+                    return;
+                }
+                index += 1;
+                let delay = backoff.next();
+                info!("Reconnecting websocket subscription in {:?}", delay);
+                tokio::time::sleep(delay).await;
+            }
+        });
+        Self { receiver, handle }
+    }
+}
+
+// Returns `Ok` once the receiver is dropped or all queries are rejected, connection errors are returned to reconnect.
+// The request id of a query is its index, so a rejection can be mapped back to the query.
+async fn run_subscription(
+    url: &str,
+    queries: &[String],
+    rejected: &mut HashSet<usize>,
+    sender: &mpsc::Sender<Value>,
+    backoff: &mut Backoff,
+) -> anyhow::Result<()> {
+    let (mut websocket, _) = tokio_tungstenite::connect_async(url).await?;
+    for (id, query) in queries.iter().enumerate().filter(|(id, _)| !rejected.contains(id)) {
+        let request = json!({
+            "jsonrpc": "2.0",
+            "method": "subscribe",
+            "id": id,
+            "params": { "query": query }
+        });
+        websocket.send(Message::Text(request.to_string())).await?;
+    }
+    while let Some(message) = websocket.next().await {
+        match message? {
+            Message::Text(text) => {
+                let value: Value = match serde_json::from_str(&text) {
+                    Ok(value) => value,
+                    Err(err) => {
+                        error!("Invalid message from {}: {}, {}", url, err, text);
+                        continue;
+                    }
+                };
+                if let Some(err) = value.get("error") {
+                    let id = value
+                        .get("id")
+                        .and_then(|id| id.as_u64())
+                        .map(|id| id as usize)
+                        .filter(|id| *id < queries.len());
+                    match id {
+                        Some(id) => {
+                            error!("Subscription to {} rejected by {}: {}", queries[id], url, err);
+                            rejected.insert(id);
+                            if rejected.len() == queries.len() {
+                                return Ok(());
+                            }
+                        }
+                        None => error!("Error from {}: {}", url, err),
+                    }
+                    continue;
+                }
+                match value.get("result") {
+                    Some(result) if result.get("data").is_some() => {
+                        if sender.send(result.clone()).await.is_err() {
+                            return Ok(());
+                        }
+                    }
+                    Some(_) => {
+                        debug!("Subscription confirmed by {}: {}", url, text);
+                        backoff.reset();
+                    }
+                    None => {}
+                }
+            }
+            Message::Ping(payload) => websocket.send(Message::Pong(payload)).await?,
+            Message::Close(frame) => {
+                return Err(anyhow::anyhow!("Error: Connection closed: {:?}", frame));
+            }
+            _ => {}
+        }
+    }
+    Err(anyhow::anyhow!("Error: Connection closed."))
+}
+
+impl Stream for RpcSubscription {
+    type Item = Value;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+impl Drop for RpcSubscription {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    // cargo test api::core::cosmos::websocket::test::test_subscription_reconnect -- --exact --nocapture
+    // cargo test api::core::cosmos::websocket::test::test_subscription_query_errors -- --exact --nocapture
+
+    use super::*;
+    use tokio::net::TcpListener;
+
+    #[tokio::test]
+    async fn test_subscription_reconnect() {
+        assert_eq!(
+            to_websocket_url("https://rpc.cosmos.network:443/"),
+            "wss://rpc.cosmos.network:443/websocket"
+        );
+
+        // Local stand-in for a node: drops the first connection, serves one event on the second.
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            for connection in 0..2 {
+                let (stream, _) = listener.accept().await.unwrap();
+                let mut websocket = tokio_tungstenite::accept_async(stream).await.unwrap();
+                let request = websocket.next().await.unwrap().unwrap();
+                assert!(request.to_text().unwrap().contains("tm.event='NewBlock'"));
+                if connection == 0 {
+                    continue;
+                }
+                websocket
+                    .send(Message::Text(json!({"jsonrpc": "2.0", "id": 0, "result": {}}).to_string()))
+                    .await
+                    .unwrap();
+                let event = json!({"jsonrpc": "2.0", "id": 0, "result": {
+                    "query": "tm.event='NewBlock'",
+                    "data": {"type": "tendermint/event/NewBlock", "value": {"block": {"header": {"height": "42"}}}},
+                    "events": {"tm.event": ["NewBlock"]}
+                }});
+                websocket.send(Message::Text(event.to_string())).await.unwrap();
+            }
+        });
+
+        let mut subscription = RpcSubscription::subscribe(
+            vec![format!("ws://{}/websocket", address)],
+            vec!["tm.event='NewBlock'".to_string()],
+            Backoff::new(Duration::from_millis(10), Duration::from_millis(100)),
+        );
+        let result = tokio::time::timeout(Duration::from_secs(10), subscription.next())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(result["data"]["value"]["block"]["header"]["height"], "42");
+    }
+
+    #[tokio::test]
+    async fn test_subscription_query_errors() {
+        // Accepts a single connection: a rejected query or a bad frame must not drop it.
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut websocket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            for _ in 0..2 {
+                websocket.next().await.unwrap().unwrap();
+            }
+            let rejection = json!({"jsonrpc": "2.0", "id": 1, "error": {"code": -32603, "message": "failed to parse query"}});
+            websocket.send(Message::Text(rejection.to_string())).await.unwrap();
+            websocket.send(Message::Text("not json".to_string())).await.unwrap();
+            websocket
+                .send(Message::Text(json!({"jsonrpc": "2.0", "id": 0, "result": {}}).to_string()))
+                .await
+                .unwrap();
+            let event = json!({"jsonrpc": "2.0", "id": 0, "result": {
+                "query": "tm.event='NewBlock'",
+                "data": {"type": "tendermint/event/NewBlock", "value": {"block": {"header": {"height": "43"}}}},
+                "events": {"tm.event": ["NewBlock"]}
+            }});
+            websocket.send(Message::Text(event.to_string())).await.unwrap();
+            // Keep the connection open until the client is done.
+            while websocket.next().await.is_some() {}
+        });
+
+        let mut subscription = RpcSubscription::subscribe(
+            vec![format!("ws://{}/websocket", address)],
+            vec!["tm.event='NewBlock'".to_string(), "invalid query".to_string()],
+            Backoff::new(Duration::from_millis(10), Duration::from_millis(100)),
+        );
+        let result = tokio::time::timeout(Duration::from_secs(10), subscription.next())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(result["data"]["value"]["block"]["header"]["height"], "43");
+    }
+}
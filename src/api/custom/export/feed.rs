@@ -0,0 +1,205 @@
+use chrono::{DateTime, Utc};
+
+use crate::api::custom::types::gov::common::ProposalTime;
+use crate::api::custom::types::gov::proposal_ext::ProposalExt;
+
+// Feed metadata, the entries are built from the proposals.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeedInfo {
+    pub title: String,
+    // Website of the feed, also used as the Atom feed id.
+    pub link: String,
+    pub description: String,
+}
+
+// Latest past transition: the voting start once reached, otherwise the submit time.
+// Deadlines are in the future for active proposals and would date the entries ahead.
+fn published_time(proposal: &ProposalExt, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    proposal
+        .get_time(&ProposalTime::VotingStartTime)
+        .filter(|time| *time <= now)
+        .or(proposal.get_time(&ProposalTime::SubmitTime))
+}
+
+// Latest change first.
+fn sorted_by_published_time(proposals: &[ProposalExt], now: DateTime<Utc>) -> Vec<&ProposalExt> {
+    let mut sorted: Vec<&ProposalExt> = proposals.iter().collect();
+    sorted.sort_by_key(|proposal| std::cmp::Reverse(published_time(proposal, now)));
+    sorted
+}
+
+fn entry_title(proposal: &ProposalExt) -> String {
+    format!(
+        "{} #{} {}: {}",
+        proposal.blockchain.display,
+        proposal.get_proposal_id(),
+        proposal.get_proposal_status().to_icon(),
+        proposal.get_title()
+    )
+}
+
+fn entry_id(proposal: &ProposalExt) -> String {
+    format!("{}-{}", proposal.blockchain.get_name(), proposal.get_proposal_id())
+}
+
+fn entry_link(proposal: &ProposalExt) -> Option<String> {
+    if proposal.blockchain.governance_proposals_link.is_empty() {
+        None
+    } else {
+        Some(proposal.governance_proposal_link())
+    }
+}
+
+fn entry_content(proposal: &ProposalExt) -> String {
+    format!("{}\n\n{}", proposal.proposal_state(), proposal.get_description())
+}
+
+fn escape_xml(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '"' => output.push_str("&quot;"),
+            '\'' => output.push_str("&apos;"),
+            // Control characters are not allowed in XML 1.0.
+            c if c.is_control() && c != '\n' && c != '\t' && c != '\r' => {}
+            c => output.push(c),
+        }
+    }
+    output
+}
+
+/// RSS 2.0 feed with one item per proposal, ordered by the voting start or else the submit time.
+pub fn to_rss(info: &FeedInfo, proposals: &[ProposalExt]) -> String {
+    let now = Utc::now();
+    let mut output = String::new();
+    output.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<rss version=\"2.0\">\n<channel>\n");
+    output.push_str(&format!("<title>{}</title>\n", escape_xml(&info.title)));
+    output.push_str(&format!("<link>{}</link>\n", escape_xml(&info.link)));
+    output.push_str(&format!("<description>{}</description>\n", escape_xml(&info.description)));
+    for proposal in sorted_by_published_time(proposals, now) {
+        output.push_str("<item>\n");
+        output.push_str(&format!("<title>{}</title>\n", escape_xml(&entry_title(proposal))));
+        if let Some(link) = entry_link(proposal) {
+            output.push_str(&format!("<link>{}</link>\n", escape_xml(&link)));
+        }
+        output.push_str(&format!(
+            "<guid isPermaLink=\"false\">{}</guid>\n",
+            escape_xml(&entry_id(proposal))
+        ));
+        if let Some(time) = published_time(proposal, now) {
+            output.push_str(&format!("<pubDate>{}</pubDate>\n", time.to_rfc2822()));
+        }
+        output.push_str(&format!(
+            "<description>{}</description>\n",
+            escape_xml(&entry_content(proposal))
+        ));
+        output.push_str("</item>\n");
+    }
+    output.push_str("</channel>\n</rss>\n");
+    output
+}
+
+/// Atom (RFC 4287) feed with one entry per proposal, ordered by the voting start or else the submit time.
+pub fn to_atom(info: &FeedInfo, proposals: &[ProposalExt]) -> String {
+    let now = Utc::now();
+    let sorted = sorted_by_published_time(proposals, now);
+    let updated = sorted
+        .first()
+        .and_then(|proposal| published_time(proposal, now))
+        .map(|time| time.to_rfc3339())
+        .unwrap_or("1970-01-01T00:00:00+00:00".to_string());
+
+    let mut output = String::new();
+    output.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<feed xmlns=\"http://www.w3.org/2005/Atom\">\n");
+    output.push_str(&format!("<title>{}</title>\n", escape_xml(&info.title)));
+    output.push_str(&format!("<subtitle>{}</subtitle>\n", escape_xml(&info.description)));
+    output.push_str(&format!("<link href=\"{}\"/>\n", escape_xml(&info.link)));
+    output.push_str(&format!("<id>{}</id>\n", escape_xml(&info.link)));
+    output.push_str(&format!("<updated>{}</updated>\n", updated));
+    for proposal in sorted {
+        output.push_str("<entry>\n");
+        output.push_str(&format!("<title>{}</title>\n", escape_xml(&entry_title(proposal))));
+        if let Some(link) = entry_link(proposal) {
+            output.push_str(&format!("<link href=\"{}\"/>\n", escape_xml(&link)));
+        }
+        output.push_str(&format!(
+            "<id>urn:cosmos-proposal:{}</id>\n",
+            escape_xml(&entry_id(proposal))
+        ));
+        let entry_updated = published_time(proposal, now)
+            .map(|time| time.to_rfc3339())
+            .unwrap_or(updated.to_owned());
+        output.push_str(&format!("<updated>{}</updated>\n", entry_updated));
+        output.push_str(&format!("<author><name>{}</name></author>\n", escape_xml(&proposal.blockchain.display)));
+        output.push_str(&format!(
+            "<content type=\"text\">{}</content>\n",
+            escape_xml(&entry_content(proposal))
+        ));
+        output.push_str("</entry>\n");
+    }
+    output.push_str("</feed>\n");
+    output
+}
+
+#[cfg(test)]
+mod test {
+
+    // cargo test api::custom::export::feed::test::test_feeds -- --exact --nocapture
+
+    use super::*;
+    use crate::api::core::cosmos::channels::{test_blockchain, SupportedBlockchain};
+    use crate::api::custom::types::gov::common::ProposalStatus;
+
+    #[test]
+    fn test_feeds() {
+        let blockchain = SupportedBlockchain {
+            governance_proposals_link: "https://www.mintscan.io/osmosis/proposals/".to_string(),
+            ..test_blockchain()
+        };
+        let proposals: Vec<ProposalExt> = [(1u64, 1000i64), (2, 2000)]
+            .into_iter()
+            .map(|(id, seconds)| {
+                ProposalExt::new(
+                    &blockchain,
+                    cosmos_sdk_proto::cosmos::gov::v1::Proposal {
+                        id,
+                        status: ProposalStatus::StatusPassed.into(),
+                        submit_time: Some(prost_types::Timestamp { seconds, nanos: 0 }),
+                        ..Default::default()
+                    },
+                )
+            })
+            .collect();
+        let info = FeedInfo {
+            title: "Osmosis <governance>".to_string(),
+            link: "https://example.com".to_string(),
+            description: "".to_string(),
+        };
+        let mut proposals = proposals;
+        // An active proposal is dated by its voting start, not by its future voting end.
+        proposals.push(ProposalExt::new(
+            &blockchain,
+            cosmos_sdk_proto::cosmos::gov::v1::Proposal {
+                id: 3,
+                status: ProposalStatus::StatusVotingPeriod.into(),
+                submit_time: Some(prost_types::Timestamp { seconds: 3000, nanos: 0 }),
+                voting_start_time: Some(prost_types::Timestamp { seconds: 4000, nanos: 0 }),
+                voting_end_time: Some(prost_types::Timestamp { seconds: 4102444800, nanos: 0 }),
+                ..Default::default()
+            },
+        ));
+        let rss = to_rss(&info, &proposals);
+        assert!(rss.contains("<pubDate>Thu, 01 Jan 1970 01:06:40 +0000</pubDate>"));
+        assert!(rss.find("osmosis-3").unwrap() < rss.find("osmosis-2").unwrap());
+        assert!(rss.contains("<title>Osmosis &lt;governance&gt;</title>"));
+        assert!(rss.find("osmosis-2").unwrap() < rss.find("osmosis-1").unwrap());
+
+        let atom = to_atom(&info, &proposals);
+        assert_eq!(atom.matches("<entry>").count(), 3);
+        assert!(atom.contains("<updated>1970-01-01T01:06:40+00:00</updated>"));
+        assert!(atom.contains("<link href=\"https://www.mintscan.io/osmosis/proposals/1\"/>"));
+    }
+}
@@ -0,0 +1,165 @@
+use chrono::{DateTime, Utc};
+
+use crate::api::custom::types::gov::common::{ProposalStatus, ProposalTime};
+use crate::api::custom::types::gov::proposal_ext::ProposalExt;
+
+const PRODID: &str = "-//cosmos-rust-package//governance//EN";
+
+// A single deadline of a proposal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalendarEvent {
+    pub uid: String,
+    pub time: DateTime<Utc>,
+    pub kind: ProposalTime,
+    pub summary: String,
+    pub description: String,
+    pub url: Option<String>,
+}
+
+impl CalendarEvent {
+    /// The voting start and end, or the deposit end if the proposal never reached the voting period.
+    pub fn from_proposal(proposal: &ProposalExt) -> Vec<CalendarEvent> {
+        let reached_voting = proposal.get_time(&ProposalTime::VotingStartTime).is_some();
+        let kinds = if reached_voting && proposal.get_proposal_status() != ProposalStatus::StatusDepositPeriod {
+            vec![ProposalTime::VotingStartTime, ProposalTime::VotingEndTime]
+        } else {
+            vec![ProposalTime::DepositEndTime]
+        };
+        let url = if proposal.blockchain.governance_proposals_link.is_empty() {
+            None
+        } else {
+            Some(proposal.governance_proposal_link())
+        };
+        kinds
+            .into_iter()
+            .filter_map(|kind| {
+                let time = proposal.get_time(&kind)?;
+                let label = match kind {
+                    ProposalTime::VotingStartTime => "voting starts",
+                    ProposalTime::VotingEndTime => "voting ends",
+                    _ => "deposit period ends",
+                };
+                let mut description = format!("{} {}", proposal.get_proposal_status().to_icon(), proposal.get_title());
+                if let Some(url) = &url {
+                    description.push_str(&format!("\n{}", url));
+                }
+                Some(CalendarEvent {
+                    uid: format!(
+                        "{}-{}-{}@cosmos-rust-package",
+                        proposal.blockchain.get_name(),
+                        proposal.get_proposal_id(),
+                        kind.to_string().to_lowercase()
+                    ),
+                    time,
+                    summary: format!(
+                        "{} #{} {}: {}",
+                        proposal.blockchain.display,
+                        proposal.get_proposal_id(),
+                        label,
+                        proposal.get_title()
+                    ),
+                    description,
+                    url: url.clone(),
+                    kind,
+                })
+            })
+            .collect()
+    }
+}
+
+/// iCalendar (RFC 5545) with one event per governance deadline of `proposals`.
+pub fn to_ics(calendar_name: &str, proposals: &[ProposalExt]) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        format!("PRODID:{}", PRODID),
+        "CALSCALE:GREGORIAN".to_string(),
+        "METHOD:PUBLISH".to_string(),
+        format!("X-WR-CALNAME:{}", escape_text(calendar_name)),
+    ];
+    // Creation time of the calendar, the deadline is only the start of the event.
+    let stamp = format_time(&Utc::now());
+    for proposal in proposals {
+        for event in CalendarEvent::from_proposal(proposal) {
+            lines.push("BEGIN:VEVENT".to_string());
+            lines.push(format!("UID:{}", escape_text(&event.uid)));
+            lines.push(format!("DTSTAMP:{}", stamp));
+            lines.push(format!("DTSTART:{}", format_time(&event.time)));
+            lines.push(format!("SUMMARY:{}", escape_text(&event.summary)));
+            lines.push(format!("DESCRIPTION:{}", escape_text(&event.description)));
+            if let Some(url) = &event.url {
+                lines.push(format!("URL:{}", url));
+            }
+            lines.push("END:VEVENT".to_string());
+        }
+    }
+    lines.push("END:VCALENDAR".to_string());
+    lines
+        .iter()
+        .map(|line| fold_line(line))
+        .collect::<Vec<String>>()
+        .join("\r\n")
+        + "\r\n"
+}
+
+fn format_time(time: &DateTime<Utc>) -> String {
+    time.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+// Lines longer than 75 octets are folded, without splitting a UTF-8 character.
+fn fold_line(line: &str) -> String {
+    let mut output = String::new();
+    let mut length = 0usize;
+    for c in line.chars() {
+        if length + c.len_utf8() > 75 {
+            output.push_str("\r\n ");
+            length = 1;
+        }
+        output.push(c);
+        length += c.len_utf8();
+    }
+    output
+}
+
+#[cfg(test)]
+mod test {
+
+    // cargo test api::custom::export::ical::test::test_to_ics -- --exact --nocapture
+
+    use super::*;
+    use crate::api::core::cosmos::channels::{test_blockchain, SupportedBlockchain};
+
+    #[test]
+    fn test_to_ics() {
+        let blockchain = SupportedBlockchain {
+            governance_proposals_link: "https://www.mintscan.io/osmosis/proposals/".to_string(),
+            ..test_blockchain()
+        };
+        let proposal = ProposalExt::new(
+            &blockchain,
+            cosmos_sdk_proto::cosmos::gov::v1::Proposal {
+                id: 7,
+                status: ProposalStatus::StatusVotingPeriod.into(),
+                voting_start_time: Some(prost_types::Timestamp { seconds: 1688208757, nanos: 0 }),
+                voting_end_time: Some(prost_types::Timestamp { seconds: 1688813557, nanos: 0 }),
+                ..Default::default()
+            },
+        );
+        let ics = to_ics("Governance", &[proposal]);
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 2);
+        assert!(ics.contains("DTSTART:20230701T105237Z"));
+        assert!(!ics.contains("DTSTAMP:20230701T105237Z"));
+        assert!(ics.contains("URL:https://www.mintscan.io/osmosis/proposals/7"));
+        assert!(ics.split("\r\n").all(|line| line.len() <= 75));
+        assert_eq!(escape_text("a,b;c\nd"), "a\\,b\\;c\\nd");
+    }
+}
@@ -0,0 +1,19 @@
+// Exports of governance deadlines for calendar apps and feed readers.
+pub mod feed;
+pub mod ical;
+
+use std::collections::BTreeMap;
+
+use crate::api::custom::types::gov::proposal_ext::ProposalExt;
+
+/// Proposals grouped by chain name, for one calendar or feed per chain.
+pub fn group_by_chain(proposals: &[ProposalExt]) -> BTreeMap<String, Vec<ProposalExt>> {
+    let mut chains: BTreeMap<String, Vec<ProposalExt>> = BTreeMap::new();
+    for proposal in proposals {
+        chains
+            .entry(proposal.blockchain.get_name())
+            .or_default()
+            .push(proposal.clone());
+    }
+    chains
+}
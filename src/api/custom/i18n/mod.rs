@@ -0,0 +1,422 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, FixedOffset, Utc};
+use num_format::ToFormattedString;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+use crate::api::custom::types::decimal_ext::to_percentage;
+
+// Languages with a built-in catalog, other languages can be added with `Localization::with_message`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default, EnumIter)]
+pub enum Language {
+    #[default]
+    English,
+    German,
+    Spanish,
+    French,
+}
+
+impl Language {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::German => "de",
+            Language::Spanish => "es",
+            Language::French => "fr",
+        }
+    }
+
+    /// Accepts language codes and tags like "de", "de-DE" or "de_AT".
+    pub fn from_code(code: &str) -> Option<Self> {
+        let language = code
+            .split(|c| c == '-' || c == '_')
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+        match language.as_str() {
+            "en" => Some(Language::English),
+            "de" => Some(Language::German),
+            "es" => Some(Language::Spanish),
+            "fr" => Some(Language::French),
+            _ => None,
+        }
+    }
+
+    fn number_locale(&self) -> num_format::Locale {
+        match self {
+            Language::English => num_format::Locale::en,
+            Language::German => num_format::Locale::de,
+            Language::Spanish => num_format::Locale::es,
+            Language::French => num_format::Locale::fr,
+        }
+    }
+
+    fn date_format(&self) -> Option<&'static str> {
+        match self {
+            // RFC 2822
+            Language::English => None,
+            Language::German => Some("%d.%m.%Y %H:%M:%S"),
+            Language::Spanish | Language::French => Some("%d/%m/%Y %H:%M:%S"),
+        }
+    }
+}
+
+// User facing texts, `{}` is replaced by the arguments in order.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter)]
+pub enum Message {
+    VotingFinished,
+    VotingEndsAt,
+    VotingStartsAt,
+    VotingEndedBeforeStart,
+    DepositPeriodOpen,
+    FinalTallyResult,
+    CurrentVotingResults,
+    YesVotes,
+    NoVotes,
+    AbstainVotes,
+    NoWithVetoVotes,
+    TotalVotes,
+    VoterTurnout,
+    BondedTokens,
+    NotBondedTokens,
+    ProjectedOutcome,
+    QuorumProgress,
+    QuorumReached,
+    QuorumNotReached,
+    FlipRequirement,
+    ValidatorsVoted,
+    ValidatorTokens,
+    NotVotedYet,
+    VoteUnknown,
+    VotingPeriod,
+    MinDeposit,
+    MaxDepositPeriod,
+    MinInitialDeposit,
+    TallyThresholds,
+    ExpeditedThreshold,
+    ExpeditedVotingPeriod,
+    ExpeditedMinDeposit,
+    ProposedUpgrade,
+    UpgradePlan,
+    UpgradeHeightReached,
+    UpgradeEstimatedTime,
+    UpgradeScheduled,
+    ScheduledUpgrade,
+    ScheduledUpgradeUnknown,
+    NoUpgradeScheduled,
+    AverageBlockTime,
+}
+
+impl Message {
+    pub fn template(&self, language: Language) -> &'static str {
+        match language {
+            Language::English => match self {
+                Message::VotingFinished => "Voting finished",
+                Message::VotingEndsAt => "Voting ends at {}",
+                Message::VotingStartsAt => "Voting starts at {}",
+                Message::VotingEndedBeforeStart => "Voting ended before it started!",
+                Message::DepositPeriodOpen => "You can help the proposal move forward by depositing now. \nThe deposit period is open until {}",
+                Message::FinalTallyResult => "Final tally result",
+                Message::CurrentVotingResults => "Current voting results",
+                Message::YesVotes => "Yes Votes",
+                Message::NoVotes => "No Votes",
+                Message::AbstainVotes => "Abstain Votes",
+                Message::NoWithVetoVotes => "NoWithVeto Votes",
+                Message::TotalVotes => "Total Votes",
+                Message::VoterTurnout => "Voter turnout: {}%",
+                Message::BondedTokens => "Bonded tokens",
+                Message::NotBondedTokens => "Not bonded tokens",
+                Message::ProjectedOutcome => "Projected outcome: {}",
+                Message::QuorumProgress => "Quorum: {} ({}% of {}%)",
+                Message::QuorumReached => "reached",
+                Message::QuorumNotReached => "not reached",
+                Message::FlipRequirement => "{} needs {} more tokens to change the outcome to {}",
+                Message::ValidatorsVoted => "🏛 Validators: {} of {} voted ({}% of the voting power)",
+                Message::ValidatorTokens => "{} ({}%, {} tokens)",
+                Message::NotVotedYet => "Not voted yet:",
+                Message::VoteUnknown => "Vote unknown (query failed):",
+                Message::VotingPeriod => "Voting period: {}",
+                Message::MinDeposit => "Min deposit: {}",
+                Message::MaxDepositPeriod => "Max deposit period: {}",
+                Message::MinInitialDeposit => "Min initial deposit: {}%",
+                Message::TallyThresholds => "Quorum: {}%,\nThreshold: {}%,\nVeto threshold: {}%",
+                Message::ExpeditedThreshold => "Expedited threshold: {}%",
+                Message::ExpeditedVotingPeriod => "Expedited voting period: {}",
+                Message::ExpeditedMinDeposit => "Expedited min deposit: {}",
+                Message::ProposedUpgrade => "Proposed upgrade:",
+                Message::UpgradePlan => "- Name: {}\n- Height: {}",
+                Message::UpgradeHeightReached => "- Height already reached (current height: {})",
+                Message::UpgradeEstimatedTime => "- Estimated time: {} (in {} blocks)",
+                Message::UpgradeScheduled => "This upgrade is currently scheduled.",
+                Message::ScheduledUpgrade => "Currently scheduled upgrade:",
+                Message::ScheduledUpgradeUnknown => "Currently scheduled upgrade: unknown",
+                Message::NoUpgradeScheduled => "No upgrade is currently scheduled.",
+                Message::AverageBlockTime => "Average block time: {}s",
+            },
+            Language::German => match self {
+                Message::VotingFinished => "Abstimmung beendet",
+                Message::VotingEndsAt => "Die Abstimmung endet am {}",
+                Message::VotingStartsAt => "Die Abstimmung beginnt am {}",
+                Message::VotingEndedBeforeStart => "Die Abstimmung endete, bevor sie begann!",
+                Message::DepositPeriodOpen => "Du kannst den Vorschlag mit einer Einzahlung voranbringen. \nDie Einzahlungsfrist läuft bis {}",
+                Message::FinalTallyResult => "Endergebnis",
+                Message::CurrentVotingResults => "Aktueller Stand der Abstimmung",
+                Message::YesVotes => "Ja-Stimmen",
+                Message::NoVotes => "Nein-Stimmen",
+                Message::AbstainVotes => "Enthaltungen",
+                Message::NoWithVetoVotes => "NoWithVeto-Stimmen",
+                Message::TotalVotes => "Stimmen gesamt",
+                Message::VoterTurnout => "Wahlbeteiligung: {}%",
+                Message::BondedTokens => "Gebundene Token",
+                Message::NotBondedTokens => "Nicht gebundene Token",
+                Message::ProjectedOutcome => "Voraussichtliches Ergebnis: {}",
+                Message::QuorumProgress => "Quorum: {} ({}% von {}%)",
+                Message::QuorumReached => "erreicht",
+                Message::QuorumNotReached => "nicht erreicht",
+                Message::FlipRequirement => "{} benötigt {} weitere Token, um das Ergebnis auf {} zu ändern",
+                Message::ValidatorsVoted => "🏛 Validatoren: {} von {} haben abgestimmt ({}% der Stimmkraft)",
+                Message::ValidatorTokens => "{} ({}%, {} Token)",
+                Message::NotVotedYet => "Noch nicht abgestimmt:",
+                Message::VoteUnknown => "Stimme unbekannt (Abfrage fehlgeschlagen):",
+                Message::VotingPeriod => "Abstimmungszeitraum: {}",
+                Message::MinDeposit => "Mindesteinzahlung: {}",
+                Message::MaxDepositPeriod => "Maximale Einzahlungsfrist: {}",
+                Message::MinInitialDeposit => "Mindestanfangseinzahlung: {}%",
+                Message::TallyThresholds => "Quorum: {}%,\nSchwelle: {}%,\nVeto-Schwelle: {}%",
+                Message::ExpeditedThreshold => "Schwelle (beschleunigt): {}%",
+                Message::ExpeditedVotingPeriod => "Abstimmungszeitraum (beschleunigt): {}",
+                Message::ExpeditedMinDeposit => "Mindesteinzahlung (beschleunigt): {}",
+                Message::ProposedUpgrade => "Vorgeschlagenes Upgrade:",
+                Message::UpgradePlan => "- Name: {}\n- Höhe: {}",
+                Message::UpgradeHeightReached => "- Höhe bereits erreicht (aktuelle Höhe: {})",
+                Message::UpgradeEstimatedTime => "- Voraussichtlicher Zeitpunkt: {} (in {} Blöcken)",
+                Message::UpgradeScheduled => "Dieses Upgrade ist derzeit geplant.",
+                Message::ScheduledUpgrade => "Derzeit geplantes Upgrade:",
+                Message::ScheduledUpgradeUnknown => "Derzeit geplantes Upgrade: unbekannt",
+                Message::NoUpgradeScheduled => "Derzeit ist kein Upgrade geplant.",
+                Message::AverageBlockTime => "Durchschnittliche Blockzeit: {}s",
+            },
+            Language::Spanish => match self {
+                Message::VotingFinished => "Votación finalizada",
+                Message::VotingEndsAt => "La votación termina el {}",
+                Message::VotingStartsAt => "La votación comienza el {}",
+                Message::VotingEndedBeforeStart => "¡La votación terminó antes de empezar!",
+                Message::DepositPeriodOpen => "Puedes ayudar a que la propuesta avance depositando ahora. \nEl período de depósito está abierto hasta el {}",
+                Message::FinalTallyResult => "Resultado final",
+                Message::CurrentVotingResults => "Resultados actuales de la votación",
+                Message::YesVotes => "Votos a favor",
+                Message::NoVotes => "Votos en contra",
+                Message::AbstainVotes => "Abstenciones",
+                Message::NoWithVetoVotes => "Votos NoWithVeto",
+                Message::TotalVotes => "Votos totales",
+                Message::VoterTurnout => "Participación: {}%",
+                Message::BondedTokens => "Tokens en staking",
+                Message::NotBondedTokens => "Tokens fuera de staking",
+                Message::ProjectedOutcome => "Resultado previsto: {}",
+                Message::QuorumProgress => "Quórum: {} ({}% de {}%)",
+                Message::QuorumReached => "alcanzado",
+                Message::QuorumNotReached => "no alcanzado",
+                Message::FlipRequirement => "{} necesita {} tokens más para cambiar el resultado a {}",
+                Message::ValidatorsVoted => "🏛 Validadores: {} de {} votaron ({}% del poder de voto)",
+                Message::ValidatorTokens => "{} ({}%, {} tokens)",
+                Message::NotVotedYet => "Aún no han votado:",
+                Message::VoteUnknown => "Voto desconocido (la consulta falló):",
+                Message::VotingPeriod => "Período de votación: {}",
+                Message::MinDeposit => "Depósito mínimo: {}",
+                Message::MaxDepositPeriod => "Período máximo de depósito: {}",
+                Message::MinInitialDeposit => "Depósito inicial mínimo: {}%",
+                Message::TallyThresholds => "Quórum: {}%,\nUmbral: {}%,\nUmbral de veto: {}%",
+                Message::ExpeditedThreshold => "Umbral acelerado: {}%",
+                Message::ExpeditedVotingPeriod => "Período de votación acelerado: {}",
+                Message::ExpeditedMinDeposit => "Depósito mínimo acelerado: {}",
+                Message::ProposedUpgrade => "Actualización propuesta:",
+                Message::UpgradePlan => "- Nombre: {}\n- Altura: {}",
+                Message::UpgradeHeightReached => "- Altura ya alcanzada (altura actual: {})",
+                Message::UpgradeEstimatedTime => "- Hora estimada: {} (en {} bloques)",
+                Message::UpgradeScheduled => "Esta actualización está programada actualmente.",
+                Message::ScheduledUpgrade => "Actualización programada actualmente:",
+                Message::ScheduledUpgradeUnknown => "Actualización programada actualmente: desconocida",
+                Message::NoUpgradeScheduled => "No hay ninguna actualización programada actualmente.",
+                Message::AverageBlockTime => "Tiempo medio de bloque: {}s",
+            },
+            Language::French => match self {
+                Message::VotingFinished => "Vote terminé",
+                Message::VotingEndsAt => "Le vote se termine le {}",
+                Message::VotingStartsAt => "Le vote commence le {}",
+                Message::VotingEndedBeforeStart => "Le vote s'est terminé avant d'avoir commencé !",
+                Message::DepositPeriodOpen => "Vous pouvez faire avancer la proposition en déposant maintenant. \nLa période de dépôt est ouverte jusqu'au {}",
+                Message::FinalTallyResult => "Résultat final",
+                Message::CurrentVotingResults => "Résultats actuels du vote",
+                Message::YesVotes => "Votes pour",
+                Message::NoVotes => "Votes contre",
+                Message::AbstainVotes => "Abstentions",
+                Message::NoWithVetoVotes => "Votes NoWithVeto",
+                Message::TotalVotes => "Total des votes",
+                Message::VoterTurnout => "Participation : {} %",
+                Message::BondedTokens => "Tokens stakés",
+                Message::NotBondedTokens => "Tokens non stakés",
+                Message::ProjectedOutcome => "Résultat prévu : {}",
+                Message::QuorumProgress => "Quorum : {} ({} % sur {} %)",
+                Message::QuorumReached => "atteint",
+                Message::QuorumNotReached => "non atteint",
+                Message::FlipRequirement => "{} a besoin de {} tokens supplémentaires pour changer le résultat en {}",
+                Message::ValidatorsVoted => "🏛 Validateurs : {} sur {} ont voté ({} % du pouvoir de vote)",
+                Message::ValidatorTokens => "{} ({} %, {} tokens)",
+                Message::NotVotedYet => "Pas encore voté :",
+                Message::VoteUnknown => "Vote inconnu (échec de la requête) :",
+                Message::VotingPeriod => "Période de vote : {}",
+                Message::MinDeposit => "Dépôt minimum : {}",
+                Message::MaxDepositPeriod => "Période de dépôt maximale : {}",
+                Message::MinInitialDeposit => "Dépôt initial minimum : {} %",
+                Message::TallyThresholds => "Quorum : {} %,\nSeuil : {} %,\nSeuil de veto : {} %",
+                Message::ExpeditedThreshold => "Seuil accéléré : {} %",
+                Message::ExpeditedVotingPeriod => "Période de vote accélérée : {}",
+                Message::ExpeditedMinDeposit => "Dépôt minimum accéléré : {}",
+                Message::ProposedUpgrade => "Mise à niveau proposée :",
+                Message::UpgradePlan => "- Nom : {}\n- Hauteur : {}",
+                Message::UpgradeHeightReached => "- Hauteur déjà atteinte (hauteur actuelle : {})",
+                Message::UpgradeEstimatedTime => "- Date estimée : {} (dans {} blocs)",
+                Message::UpgradeScheduled => "Cette mise à niveau est actuellement planifiée.",
+                Message::ScheduledUpgrade => "Mise à niveau actuellement planifiée :",
+                Message::ScheduledUpgradeUnknown => "Mise à niveau actuellement planifiée : inconnue",
+                Message::NoUpgradeScheduled => "Aucune mise à niveau n'est actuellement planifiée.",
+                Message::AverageBlockTime => "Temps de bloc moyen : {} s",
+            },
+        }
+    }
+}
+
+/// Message catalog and number/date formatting of a language.
+///
+/// `Localization::default()` is English in UTC and produces the same texts as the non-localized methods.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Localization {
+    language: Language,
+    overrides: HashMap<Message, String>,
+    // Timezone of formatted dates, UTC if not set.
+    utc_offset: Option<FixedOffset>,
+}
+
+impl Localization {
+    pub fn new(language: Language) -> Self {
+        Self {
+            language,
+            overrides: HashMap::new(),
+            utc_offset: None,
+        }
+    }
+
+    /// Falls back to English for unknown codes.
+    pub fn from_code(code: &str) -> Self {
+        Self::new(Language::from_code(code).unwrap_or_default())
+    }
+
+    /// Replaces the template of a message, e.g. to customize a text or to serve a language without built-in catalog.
+    pub fn with_message(mut self, message: Message, template: &str) -> Self {
+        self.overrides.insert(message, template.to_string());
+        self
+    }
+
+    pub fn with_utc_offset(mut self, utc_offset: FixedOffset) -> Self {
+        self.utc_offset = Some(utc_offset);
+        self
+    }
+
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    pub fn text(&self, message: Message) -> String {
+        self.format(message, &[])
+    }
+
+    pub fn format(&self, message: Message, args: &[&str]) -> String {
+        let template = self
+            .overrides
+            .get(&message)
+            .map(|template| template.as_str())
+            .unwrap_or(message.template(self.language));
+        let mut parts = template.split("{}");
+        let mut output = parts.next().unwrap_or_default().to_string();
+        for (index, part) in parts.enumerate() {
+            output.push_str(args.get(index).copied().unwrap_or_default());
+            output.push_str(part);
+        }
+        output
+    }
+
+    pub fn format_number(&self, number: u128) -> String {
+        number.to_formatted_string(&self.language.number_locale())
+    }
+
+    // Only the decimal separator is localized, `precision` rounds if given.
+    pub fn format_decimal(&self, number: Decimal, precision: Option<u32>) -> String {
+        let text = match precision {
+            Some(precision) => format!("{:.*}", precision as usize, number),
+            None => number.to_string(),
+        };
+        text.replace('.', self.language.number_locale().decimal())
+    }
+
+    // Fraction as percentage with 2 decimal places (0.334 → "33.40"), without the percent sign.
+    pub fn format_percentage(&self, fraction: Decimal) -> String {
+        self.format_decimal(to_percentage(fraction), Some(2))
+    }
+
+    pub fn format_datetime(&self, time: &DateTime<Utc>) -> String {
+        let time = time.with_timezone(&self.utc_offset.unwrap_or(FixedOffset::east_opt(0).unwrap()));
+        let timezone = if time.offset().local_minus_utc() == 0 {
+            "UTC".to_string()
+        } else {
+            time.format("%:z").to_string()
+        };
+        match self.language.date_format() {
+            Some(format) => format!("{} {}", time.format(format), timezone),
+            None => time.to_rfc2822().replace("+0000", "UTC"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    // cargo test api::custom::i18n::test::test_localization -- --exact --nocapture
+
+    use super::*;
+    use chrono::TimeZone;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn test_localization() {
+        let en = Localization::default();
+        let de = Localization::from_code("de-AT");
+        assert_eq!(de.language(), Language::German);
+        assert_eq!(Localization::from_code("xx").language(), Language::English);
+
+        assert_eq!(en.format(Message::VotingEndsAt, &["tomorrow"]), "Voting ends at tomorrow");
+        assert_eq!(en.format_number(1234567), "1,234,567");
+        assert_eq!(de.format_number(1234567), "1.234.567");
+        assert_eq!(de.format_decimal(Decimal::new(1234, 2), Some(1)), "12,3");
+
+        let time = Utc.with_ymd_and_hms(2023, 7, 1, 10, 52, 37).unwrap();
+        assert_eq!(en.format_datetime(&time), "Sat, 01 Jul 2023 10:52:37 UTC");
+        assert_eq!(de.format_datetime(&time), "01.07.2023 10:52:37 UTC");
+        let cest = de.clone().with_utc_offset(FixedOffset::east_opt(2 * 3600).unwrap());
+        assert_eq!(cest.format_datetime(&time), "01.07.2023 12:52:37 +02:00");
+
+        let custom = en.with_message(Message::VotingFinished, "Done");
+        assert_eq!(custom.text(Message::VotingFinished), "Done");
+
+        // Every built-in template has the same number of placeholders as the English one.
+        for language in Language::iter() {
+            for message in Message::iter() {
+                assert_eq!(
+                    message.template(language).matches("{}").count(),
+                    message.template(Language::English).matches("{}").count()
+                );
+            }
+        }
+    }
+}
@@ -1,4 +1,11 @@
 // adds functionality to easily convert Any types
 // adds functionality for easy display
+pub mod export;
+pub mod i18n;
 pub mod query;
+pub mod render;
+pub mod store;
+pub mod subscription;
+pub mod tx;
 pub mod types;
+pub mod watcher;
@@ -6,16 +6,33 @@ use std::string::ToString;
 use cosmos_sdk_proto::cosmos::base::query::v1beta1::PageRequest;
 use tonic::{Code};
 
+use crate::api::custom::types::gov::content_details_ext::{ContentDetail, ContentDetailsExt};
+use crate::api::custom::types::gov::params_change_ext::ParamsChangeExt;
+use crate::api::custom::types::gov::deposit_ext::{DepositExt, DepositProgress};
+use crate::api::custom::types::gov::gov_params_ext::{CoinAmount, GovParamsExt, GovParamsVersion};
 use crate::api::custom::types::gov::params_ext::ParamsExt;
-use crate::api::custom::types::gov::proposal_ext::{ProposalExt};
+use crate::api::custom::types::gov::proto::QueryParamsResponseV1;
+use crate::api::custom::types::gov::tally_projection::TallyThresholds;
+use crate::api::custom::types::ibc::client_ext::IbcClientsExt;
+use crate::api::custom::types::gov::proposal_ext::{ProposalExt, ProposalParams};
+use crate::api::custom::types::gov::proposal_features::ProposerInfo;
 
 use crate::api::custom::types::gov::tally_ext::TallyResultExt;
 use crate::api::custom::types::gov::tally_v1beta1_ext::TallyResultV1Beta1Ext;
+use crate::api::custom::types::gov::validator_votes_ext::{ValidatorVote, ValidatorVotesReport};
+use crate::api::custom::types::gov::vote_ext::VoteExt;
+use crate::api::custom::types::decimal_ext::{parse_u128, ratio};
 use crate::api::custom::types::staking::validators_ext::ValidatorsExt;
 
 
 use async_recursion::async_recursion;
-use crate::api::custom::types::gov::common::ProposalStatus;
+use futures::StreamExt;
+use log::{debug, error};
+use crate::api::custom::query::ibc::get_client_info;
+use crate::api::custom::query::params::{get_legacy_param, get_module_params};
+use crate::api::custom::query::upgrade::get_upgrade_plan;
+use crate::api::custom::query::wasm::get_wasm_details;
+use crate::api::custom::types::gov::common::{ProposalContent, ProposalStatus};
 
 pub async fn get_validators_v1beta1(
     blockchain: SupportedBlockchain,
@@ -59,6 +76,74 @@ pub async fn get_params_v1beta1(
     Ok(ParamsExt::new(blockchain, &params_type, res))
 }
 
+/// Query the gov v1 params.
+///
+/// Uses the unified `params` of cosmos-sdk v0.47+ and falls back to the separate
+/// voting, deposit and tally params of cosmos-sdk v0.46.
+pub async fn get_params_v1(blockchain: SupportedBlockchain) -> anyhow::Result<GovParamsExt> {
+    let channel = blockchain.channel().await?;
+    let res: QueryParamsResponseV1 = cosmos::query::gov::get_params_v1_as(
+        channel.clone(),
+        cosmos_sdk_proto::cosmos::gov::v1::QueryParamsRequest {
+            params_type: "tallying".to_string(),
+        },
+    )
+    .await?;
+    if let Some(params) = &res.params {
+        return GovParamsExt::from_v1(blockchain, params);
+    }
+
+    let mut separate_params = Vec::new();
+    for params_type in ["voting", "deposit"] {
+        separate_params.push(
+            cosmos::query::gov::get_params_v1(
+                channel.clone(),
+                cosmos_sdk_proto::cosmos::gov::v1::QueryParamsRequest {
+                    params_type: params_type.to_string(),
+                },
+            )
+            .await?,
+        );
+    }
+    let voting_params = separate_params[0].voting_params.clone().unwrap_or_default();
+    let deposit_params = separate_params[1].deposit_params.clone().unwrap_or_default();
+    let tally_params = res
+        .tally_params
+        .ok_or(anyhow::anyhow!("Error: Tally params are empty."))?;
+    GovParamsExt::from_separate_params(
+        blockchain,
+        GovParamsVersion::V1Legacy,
+        &voting_params.voting_period,
+        &deposit_params.min_deposit,
+        &deposit_params.max_deposit_period,
+        TallyThresholds::from_v1(&tally_params)?,
+    )
+}
+
+/// Query the gov params independent of the gov module version of the chain.
+///
+/// Tries the gov v1 params first and falls back to the v1beta1 voting, deposit and tally params
+/// if the chain does not support gov v1.
+///
+/// # Errors
+///
+/// Returns an error if the params can not be queried, other gov v1 errors than an unsupported query are not retried with v1beta1.
+pub async fn get_gov_params(blockchain: SupportedBlockchain) -> anyhow::Result<GovParamsExt> {
+    match get_params_v1(blockchain.clone()).await {
+        Ok(params) => return Ok(params),
+        Err(err) => match err.downcast_ref::<tonic::Status>() {
+            Some(tonic_status) if is_v1_unsupported(tonic_status) => {
+                debug!("Gov v1 params not supported by {}: {}", blockchain.name, tonic_status);
+            }
+            _ => return Err(err),
+        },
+    }
+    let voting_params = get_params_v1beta1(blockchain.clone(), "voting".to_string()).await?;
+    let deposit_params = get_params_v1beta1(blockchain.clone(), "deposit".to_string()).await?;
+    let tally_params = get_params_v1beta1(blockchain, "tallying".to_string()).await?;
+    GovParamsExt::from_params_v1beta1(&voting_params, &deposit_params, &tally_params)
+}
+
 pub async fn get_tally_v1beta1(
     blockchain: SupportedBlockchain,
     proposal_id: u64,
@@ -133,7 +218,8 @@ pub async fn get_proposals_v1beta1(
                 if limit.unwrap_or(0) == 1 && skip_server_error {
                     let res = get_proposals_v1beta1(blockchain.clone(), proposal_status.clone(), next_key.clone(), Some(offset.map(|x| x + 1).unwrap_or(0)), Some(1),skip_server_error).await;
                     return res;
-                } else {
+                } else if limit.unwrap_or(0) != 1 {
+                    // retry proposal by proposal, a single failing proposal is returned as error.
                     let res = get_proposals_v1beta1(blockchain.clone(), proposal_status.clone(), next_key.clone(), offset.clone(), Some(1),skip_server_error).await;
                     return res;
                 }
@@ -199,7 +285,8 @@ pub async fn get_proposals_v1(
                     // error is for this proposal, needs to be skipped.
                     let res = get_proposals_v1(blockchain.clone(), proposal_status.clone(), next_key.clone(), Some(offset.map(|x| x + 1).unwrap_or(0)), Some(1),skip_server_error).await;
                     return res;
-                } else {
+                } else if limit.unwrap_or(0) != 1 {
+                    // retry proposal by proposal, a single failing proposal is returned as error.
                     let res = get_proposals_v1(blockchain.clone(), proposal_status.clone(), next_key.clone(), offset.clone(), Some(1),skip_server_error).await;
                     return res;
                 }
@@ -315,29 +402,492 @@ pub async fn get_proposals(
     return res;
 }
 
+// Proposals the node can not decode (`OutOfRange`) are skipped, up to this many per listing.
+const MAX_SKIPPED_PROPOSALS: usize = 10;
+
+// Pages through proposals by offset until `reached_end` returns true for a page or there are no more pages.
+// Paging by offset allows stepping over a single proposal that fails with `OutOfRange`.
+async fn collect_proposal_pages<F, Fut>(
+    blockchain_name: &str,
+    mut fetch_page: F,
+    reached_end: impl Fn(&[ProposalExt]) -> bool,
+) -> Result<Vec<ProposalExt>, tonic::Status>
+where
+    F: FnMut(u64) -> Fut,
+    Fut: std::future::Future<Output = Result<(Option<Vec<u8>>, Vec<ProposalExt>), tonic::Status>>,
+{
+    let mut proposals = Vec::new();
+    let mut skipped: Vec<u64> = Vec::new();
+    let mut offset: u64 = 0;
+    loop {
+        match fetch_page(offset).await {
+            Ok((key, page)) => {
+                offset += page.len() as u64;
+                let last_page = page.is_empty()
+                    || reached_end(&page)
+                    || key.map(|key| key.is_empty()).unwrap_or(true);
+                proposals.extend(page);
+                if last_page {
+                    break;
+                }
+            }
+            Err(tonic_status)
+                if tonic_status.code() == Code::OutOfRange && skipped.len() < MAX_SKIPPED_PROPOSALS =>
+            {
+                error!(
+                    "Skipping proposal at offset {} of {}, it can not be decoded: {}",
+                    offset, blockchain_name, tonic_status
+                );
+                skipped.push(offset);
+                offset += 1;
+            }
+            Err(tonic_status) => return Err(tonic_status),
+        }
+    }
+    if !skipped.is_empty() {
+        error!(
+            "Skipped {} proposal(s) of {} at offsets {:?}",
+            skipped.len(),
+            blockchain_name,
+            skipped
+        );
+    }
+    Ok(proposals)
+}
+
+/// All proposals with `proposal_status`, following the pagination.
+///
+/// Up to `MAX_SKIPPED_PROPOSALS` proposals that the node fails to return (`OutOfRange`) are skipped and logged.
+pub async fn get_all_proposals(
+    blockchain: SupportedBlockchain,
+    proposal_status: ProposalStatus,
+) -> Result<Vec<ProposalExt>, tonic::Status> {
+    collect_proposal_pages(
+        &blockchain.name,
+        |offset| get_proposals(blockchain.clone(), proposal_status.clone(), None, Some(offset), None, false),
+        |_| false,
+    )
+    .await
+}
+
+/// Proposals with an id above `after_id`, newest first.
+/// Pages are fetched in descending order until a known proposal is reached.
+pub async fn get_proposals_after(
+    blockchain: SupportedBlockchain,
+    after_id: u64,
+) -> Result<Vec<ProposalExt>, tonic::Status> {
+    let proposals = collect_proposal_pages(
+        &blockchain.name,
+        |offset| get_proposals(blockchain.clone(), ProposalStatus::StatusNil, None, Some(offset), None, false),
+        |page| page.iter().any(|proposal| proposal.get_proposal_id() <= after_id),
+    )
+    .await?;
+    Ok(proposals
+        .into_iter()
+        .filter(|proposal| proposal.get_proposal_id() > after_id)
+        .collect())
+}
+
+/// Deposit and voting period proposals, with the live tally attached to the ones in voting period.
+pub async fn get_active_proposals(blockchain: SupportedBlockchain) -> Result<Vec<ProposalExt>, tonic::Status> {
+    let mut active = get_all_proposals(blockchain.clone(), ProposalStatus::StatusDepositPeriod).await?;
+    for mut proposal in get_all_proposals(blockchain.clone(), ProposalStatus::StatusVotingPeriod).await? {
+        match get_tally_v1beta1(blockchain.clone(), proposal.get_proposal_id()).await {
+            Ok(tally_result) => {
+                proposal.params.get_or_insert_with(ProposalParams::default).tally_result = Some(tally_result);
+            }
+            Err(err) => debug!("No live tally for proposal {} of {}: {}", proposal.get_proposal_id(), blockchain.name, err),
+        }
+        active.push(proposal);
+    }
+    Ok(active)
+}
+
+fn page_request(next_key: Option<Vec<u8>>) -> Option<PageRequest> {
+    Some(PageRequest {
+        key: next_key.unwrap_or(vec![]),
+        offset: 0,
+        limit: 100,
+        count_total: false,
+        reverse: false,
+    })
+}
+
+fn to_status(err: anyhow::Error) -> tonic::Status {
+    tonic::Status::new(Code::Internal, err.to_string())
+}
+
+fn is_v1_unsupported(tonic_status: &tonic::Status) -> bool {
+    matches!(
+        tonic_status.code(),
+        Code::Unimplemented | Code::Unknown | Code::Aborted | Code::Cancelled
+    )
+}
+
+pub async fn get_votes_v1(
+    blockchain: SupportedBlockchain,
+    proposal_id: u64,
+    next_key: Option<Vec<u8>>,
+) -> Result<(Option<Vec<u8>>, Vec<VoteExt>), tonic::Status> {
+    let channel = blockchain.channel().await?;
+    let res = cosmos::query::gov::get_votes_v1(
+        channel,
+        cosmos_sdk_proto::cosmos::gov::v1::QueryVotesRequest {
+            proposal_id,
+            pagination: page_request(next_key),
+        },
+    )
+    .await?;
+    let list = res
+        .votes
+        .into_iter()
+        .map(|vote| VoteExt::new(&blockchain, vote))
+        .collect::<anyhow::Result<Vec<VoteExt>>>()
+        .map_err(to_status)?;
+    Ok((res.pagination.map(|x| x.next_key), list))
+}
+
+pub async fn get_votes_v1beta1(
+    blockchain: SupportedBlockchain,
+    proposal_id: u64,
+    next_key: Option<Vec<u8>>,
+) -> Result<(Option<Vec<u8>>, Vec<VoteExt>), tonic::Status> {
+    let channel = blockchain.channel().await?;
+    let res = cosmos::query::gov::get_votes_v1beta1(
+        channel,
+        cosmos_sdk_proto::cosmos::gov::v1beta1::QueryVotesRequest {
+            proposal_id,
+            pagination: page_request(next_key),
+        },
+    )
+    .await?;
+    let list = res
+        .votes
+        .into_iter()
+        .map(|vote| VoteExt::from_v1beta1(&blockchain, vote))
+        .collect::<anyhow::Result<Vec<VoteExt>>>()
+        .map_err(to_status)?;
+    Ok((res.pagination.map(|x| x.next_key), list))
+}
+
+/// Retrieve a page of votes of a proposal, falling back to v1beta1 if the chain does not serve gov v1.
+///
+/// Note: Votes are only stored while the proposal is in its voting period.
+pub async fn get_votes(
+    blockchain: SupportedBlockchain,
+    proposal_id: u64,
+    next_key: Option<Vec<u8>>,
+) -> Result<(Option<Vec<u8>>, Vec<VoteExt>), tonic::Status> {
+    let res = get_votes_v1(blockchain.clone(), proposal_id, next_key.clone()).await;
+    match &res {
+        Err(tonic_status) if is_v1_unsupported(tonic_status) => {
+            get_votes_v1beta1(blockchain, proposal_id, next_key).await
+        }
+        _ => res,
+    }
+}
+
+/// Retrieve the vote of `voter` on a proposal, `None` if the voter has not voted.
+pub async fn get_vote(
+    blockchain: SupportedBlockchain,
+    proposal_id: u64,
+    voter: String,
+) -> Result<Option<VoteExt>, tonic::Status> {
+    let channel = blockchain.channel().await?;
+    let res = cosmos::query::gov::get_vote_v1(
+        channel.clone(),
+        cosmos_sdk_proto::cosmos::gov::v1::QueryVoteRequest {
+            proposal_id,
+            voter: voter.clone(),
+        },
+    )
+    .await;
+    let vote = match res {
+        Ok(res) => res
+            .vote
+            .map(|vote| VoteExt::new(&blockchain, vote))
+            .transpose(),
+        Err(tonic_status) if is_v1_unsupported(&tonic_status) => {
+            cosmos::query::gov::get_vote_v1beta1(
+                channel,
+                cosmos_sdk_proto::cosmos::gov::v1beta1::QueryVoteRequest { proposal_id, voter },
+            )
+            .await?
+            .vote
+            .map(|vote| VoteExt::from_v1beta1(&blockchain, vote))
+            .transpose()
+        }
+        // x/gov returns `NotFound` (or `InvalidArgument` on older versions) if there is no vote.
+        Err(tonic_status) if matches!(tonic_status.code(), Code::NotFound | Code::InvalidArgument) => {
+            Ok(None)
+        }
+        Err(tonic_status) => return Err(tonic_status),
+    };
+    vote.map_err(to_status)
+}
+
+pub async fn get_deposits_v1(
+    blockchain: SupportedBlockchain,
+    proposal_id: u64,
+    next_key: Option<Vec<u8>>,
+) -> Result<(Option<Vec<u8>>, Vec<DepositExt>), tonic::Status> {
+    let channel = blockchain.channel().await?;
+    let res = cosmos::query::gov::get_deposits_v1(
+        channel,
+        cosmos_sdk_proto::cosmos::gov::v1::QueryDepositsRequest {
+            proposal_id,
+            pagination: page_request(next_key),
+        },
+    )
+    .await?;
+    let list = res
+        .deposits
+        .into_iter()
+        .map(|deposit| DepositExt::new(&blockchain, deposit))
+        .collect::<anyhow::Result<Vec<DepositExt>>>()
+        .map_err(to_status)?;
+    Ok((res.pagination.map(|x| x.next_key), list))
+}
+
+pub async fn get_deposits_v1beta1(
+    blockchain: SupportedBlockchain,
+    proposal_id: u64,
+    next_key: Option<Vec<u8>>,
+) -> Result<(Option<Vec<u8>>, Vec<DepositExt>), tonic::Status> {
+    let channel = blockchain.channel().await?;
+    let res = cosmos::query::gov::get_deposits_v1beta1(
+        channel,
+        cosmos_sdk_proto::cosmos::gov::v1beta1::QueryDepositsRequest {
+            proposal_id,
+            pagination: page_request(next_key),
+        },
+    )
+    .await?;
+    let list = res
+        .deposits
+        .into_iter()
+        .map(|deposit| DepositExt::from_v1beta1(&blockchain, deposit))
+        .collect::<anyhow::Result<Vec<DepositExt>>>()
+        .map_err(to_status)?;
+    Ok((res.pagination.map(|x| x.next_key), list))
+}
+
+/// Retrieve a page of deposits of a proposal, falling back to v1beta1 if the chain does not serve gov v1.
+pub async fn get_deposits(
+    blockchain: SupportedBlockchain,
+    proposal_id: u64,
+    next_key: Option<Vec<u8>>,
+) -> Result<(Option<Vec<u8>>, Vec<DepositExt>), tonic::Status> {
+    let res = get_deposits_v1(blockchain.clone(), proposal_id, next_key.clone()).await;
+    match &res {
+        Err(tonic_status) if is_v1_unsupported(tonic_status) => {
+            get_deposits_v1beta1(blockchain, proposal_id, next_key).await
+        }
+        _ => res,
+    }
+}
+
+/// Sum up all deposits of a proposal and compare them against the chain's `min_deposit`.
+pub async fn get_deposit_progress(
+    blockchain: SupportedBlockchain,
+    proposal_id: u64,
+    expedited: bool,
+) -> anyhow::Result<DepositProgress> {
+    let mut deposits: Vec<DepositExt> = Vec::new();
+    let mut next_key: Option<Vec<u8>> = None;
+    loop {
+        let (key, mut list) = get_deposits(blockchain.clone(), proposal_id, next_key).await?;
+        deposits.append(&mut list);
+        match key {
+            Some(key) if !key.is_empty() => next_key = Some(key),
+            _ => break,
+        }
+    }
+    let gov_params = get_gov_params(blockchain).await?;
+    Ok(DepositProgress::from_deposits(&deposits, &gov_params, expedited))
+}
+
+/// Account number, sequence and balances of a proposer, for `ProposalFeatures::extract`.
+///
+/// The proposer is not part of the gov v1 proposal, it is the sender of the submit proposal tx
+/// (e.g. from a `RpcEvent::Tx` of `SUBMIT_PROPOSAL_QUERY`).
+/// If the account can not be queried (e.g. a vesting or module account) the account number and sequence are `None`.
+///
+/// # Errors
+///
+/// Returns an error if the balances can not be queried.
+pub async fn get_proposer_info(blockchain: SupportedBlockchain, proposer: &str) -> anyhow::Result<ProposerInfo> {
+    let channel = blockchain.channel().await?;
+    let account = cosmos::query::auth::query_account(channel.clone(), proposer.to_string()).await;
+    if let Err(err) = &account {
+        debug!("Failed to query the account {}: {}", proposer, err);
+    }
+    let balances = cosmos::query::bank::get_all_balances(channel, proposer.to_string()).await?;
+    Ok(ProposerInfo {
+        address: proposer.to_string(),
+        account_number: account.as_ref().ok().map(|account| account.account_number),
+        sequence: account.as_ref().ok().map(|account| account.sequence),
+        balance: CoinAmount::from_coins(&balances.balances)?,
+    })
+}
+
+/// Report how each active validator voted on a proposal and which validators have not voted yet.
+///
+/// The bonded validators are listed via `get_validators_v1beta1`, the vote of each validator is
+/// queried for the account derived from its operator address.
+///
+/// # Errors
+///
+/// Returns an error if the validator set can not be queried. Validators whose vote can not be queried are reported as unknown.
+pub async fn get_validator_votes(
+    blockchain: SupportedBlockchain,
+    proposal_id: u64,
+) -> anyhow::Result<ValidatorVotesReport> {
+    let mut validators: Vec<ValidatorsExt> = Vec::new();
+    let mut next_key: Option<Vec<u8>> = None;
+    loop {
+        let (key, mut list) = get_validators_v1beta1(blockchain.clone(), next_key).await?;
+        validators.append(&mut list);
+        match key {
+            Some(key) if !key.is_empty() => next_key = Some(key),
+            _ => break,
+        }
+    }
+
+    let bonded: Vec<(String, String, u128)> = validators
+        .iter()
+        .map(|validator| &validator.validators.0)
+        .filter(|validator| {
+            !validator.jailed
+                && validator.status
+                    == cosmos_sdk_proto::cosmos::staking::v1beta1::BondStatus::Bonded as i32
+        })
+        .filter_map(|validator| {
+            Some((
+                validator.operator_address.to_owned(),
+                validator
+                    .description
+                    .as_ref()
+                    .map(|description| description.moniker.to_owned())
+                    .unwrap_or_default(),
+                parse_u128(&validator.tokens).ok()?,
+            ))
+        })
+        .collect();
+    let total_tokens: u128 = bonded.iter().map(|(_, _, tokens)| tokens).sum();
+
+    let list: Vec<ValidatorVote> = futures::stream::iter(bonded.into_iter())
+        .map(|(operator_address, moniker, tokens)| {
+            let blockchain = blockchain.clone();
+            async move {
+                // `get_vote` returns `Ok(None)` if there is no vote, any error leaves the vote unknown.
+                let (account_address, vote) =
+                    match cosmos::account_from_operator_address(&operator_address, &blockchain.prefix) {
+                        Ok(account_address) => {
+                            let vote = get_vote(blockchain, proposal_id, account_address.clone())
+                                .await
+                                .map_err(|err| err.to_string());
+                            (account_address, vote)
+                        }
+                        Err(err) => (String::new(), Err(err.to_string())),
+                    };
+                ValidatorVote {
+                    moniker,
+                    operator_address,
+                    account_address,
+                    tokens,
+                    voting_power_share: ratio(tokens, total_tokens).unwrap_or_default(),
+                    vote,
+                }
+            }
+        })
+        .buffered(10)
+        .collect()
+        .await;
+    Ok(ValidatorVotesReport::new(blockchain, proposal_id, list))
+}
+
+/// Query the live chain data needed to explain the messages of a proposal.
+///
+/// For each `MsgUpdateParams` the current params of the module are fetched and compared against the proposed params.
+/// For a legacy `ParameterChangeProposal` the current value of each `subspace/key` is queried from the x/params module.
+/// For IBC client proposals the chain id and status of the referenced clients are queried.
+/// For upgrade proposals the currently scheduled plan and the estimated upgrade time are added.
+/// For wasm proposals the contract label, creator, admin and the current/target code (with checksum) are resolved.
+/// The result can be attached to the proposal via `ProposalExt::add_content_details`, after which
+/// `ProposalExt::get_description` lists exactly which fields change.
+///
+/// Messages for which the live data can not be queried are skipped, the description then only shows the proposed values.
+pub async fn get_content_details(
+    blockchain: SupportedBlockchain,
+    proposal: &ProposalExt,
+) -> Vec<ContentDetailsExt> {
+    let mut list: Vec<ContentDetailsExt> = Vec::new();
+    for (index, content) in proposal.messages_as_proposal_content().iter().enumerate() {
+        if let Some((module, proposed)) = content.params_update() {
+            let current = get_module_params(blockchain.clone(), module).await.ok();
+            list.push(ContentDetailsExt::new(
+                index,
+                ContentDetail::ParamsChange(ParamsChangeExt::diff(
+                    &module.to_string(),
+                    current.as_ref(),
+                    &proposed,
+                )),
+            ));
+        }
+        if let ProposalContent::ParameterChangeProposal(Some(p)) = content {
+            let mut changes = Vec::new();
+            for change in p.changes.iter() {
+                let current = get_legacy_param(blockchain.clone(), &change.subspace, &change.key).await.ok();
+                changes.push((format!("{}/{}", change.subspace, change.key), change.value.to_owned(), current));
+            }
+            list.push(ContentDetailsExt::new(
+                index,
+                ContentDetail::ParamsChange(ParamsChangeExt::from_legacy_changes(changes)),
+            ));
+        }
+        let client_ids = content.ibc_client_ids();
+        if !client_ids.is_empty() {
+            let mut clients = Vec::new();
+            for (role, client_id) in client_ids.iter() {
+                clients.push(get_client_info(blockchain.clone(), role, client_id).await);
+            }
+            list.push(ContentDetailsExt::new(
+                index,
+                ContentDetail::IbcClients(IbcClientsExt { clients }),
+            ));
+        }
+        if let Some(proposed) = content.upgrade_plan() {
+            list.push(ContentDetailsExt::new(
+                index,
+                ContentDetail::UpgradePlan(get_upgrade_plan(blockchain.clone(), proposed).await),
+            ));
+        }
+        if let Some(target) = content.wasm_target() {
+            list.push(ContentDetailsExt::new(
+                index,
+                ContentDetail::Wasm(get_wasm_details(blockchain.clone(), &target).await),
+            ));
+        }
+    }
+    list
+}
+
 #[cfg(test)]
 mod test {
 
     // cargo test -- --nocapture
     // cargo test -- --list
     // cargo test api::custom::query::gov::test::test_get_proposals_function -- --exact --nocapture
+    // cargo test api::custom::query::gov::test::test_collect_proposal_pages -- --exact --nocapture
 
     use super::*;
-    use crate::api::core::cosmos::channels::GRPC_Service;
+    use crate::api::core::cosmos::channels::test_blockchain;
 
     #[tokio::test]
     async fn test_get_proposals_function() {
-        let supported_blockchain = SupportedBlockchain {
-            display: "Osmosis".to_string(),
-            name: "osmosis".to_string(),
-            prefix: "osmo".to_string(),
-            grpc_service: GRPC_Service {
-                grpc_urls: vec!["https://osmosis-grpc.lavenderfive.com:443".to_string()],
-                error: None,
-            },
-            rank: 1,
-            governance_proposals_link: "".to_string(),
-        };
+        let mut supported_blockchain = test_blockchain();
+        supported_blockchain.grpc_service.grpc_urls = vec!["https://osmosis-grpc.lavenderfive.com:443".to_string()];
         let result = get_proposals(supported_blockchain, ProposalStatus::StatusNil, None, None,None, false).await;
         assert!(result.is_ok());
         for each in result.unwrap().1 {
@@ -353,4 +903,62 @@ mod test {
             break;
         }
     }
+
+    // Stand-in for a node listing the proposals newest first, two per page, failing on the `bad` ids.
+    async fn stub_page(ids: Vec<u64>, bad: Vec<u64>, offset: u64) -> Result<(Option<Vec<u8>>, Vec<ProposalExt>), tonic::Status> {
+        let blockchain = test_blockchain();
+        let mut page = Vec::new();
+        for id in ids.iter().skip(offset as usize).take(2) {
+            if bad.contains(id) {
+                if page.is_empty() {
+                    return Err(tonic::Status::out_of_range("proposal can not be decoded"));
+                }
+                break;
+            }
+            page.push(ProposalExt::new(
+                &blockchain,
+                cosmos_sdk_proto::cosmos::gov::v1::Proposal { id: *id, ..Default::default() },
+            ));
+        }
+        let next_key = if offset as usize + page.len() < ids.len() { vec![1] } else { vec![] };
+        Ok((Some(next_key), page))
+    }
+
+    fn proposal_ids(proposals: &[ProposalExt]) -> Vec<u64> {
+        proposals.iter().map(|proposal| proposal.get_proposal_id()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_collect_proposal_pages() {
+        let ids: Vec<u64> = vec![6, 5, 4, 3, 2, 1];
+
+        let all = collect_proposal_pages("test", |offset| stub_page(ids.clone(), vec![4], offset), |_| false)
+            .await
+            .unwrap();
+        assert_eq!(proposal_ids(&all), vec![6, 5, 3, 2, 1]);
+
+        let newest = collect_proposal_pages(
+            "test",
+            |offset| stub_page(ids.clone(), vec![4], offset),
+            |page| page.iter().any(|proposal| proposal.get_proposal_id() <= 5),
+        )
+        .await
+        .unwrap();
+        assert_eq!(proposal_ids(&newest), vec![6, 5]);
+
+        // A node that fails on every proposal ends the listing with an error instead of retrying forever.
+        let many: Vec<u64> = (1..=20).rev().collect();
+        let mut calls = 0;
+        let result = collect_proposal_pages(
+            "test",
+            |offset| {
+                calls += 1;
+                stub_page(many.clone(), many.clone(), offset)
+            },
+            |_| false,
+        )
+        .await;
+        assert_eq!(result.unwrap_err().code(), Code::OutOfRange);
+        assert_eq!(calls, MAX_SKIPPED_PROPOSALS + 1);
+    }
 }
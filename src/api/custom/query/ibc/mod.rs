@@ -0,0 +1,45 @@
+use crate::api::core::cosmos::channels::SupportedBlockchain;
+use crate::api::core::*;
+
+use crate::api::custom::types::ibc::client_ext::IbcClientInfo;
+use crate::api::custom::types::ibc::proto::TendermintClientState;
+
+/// Query the chain id and status of an IBC light client.
+///
+/// Values that can not be queried (e.g. non-tendermint clients) are left empty.
+pub async fn get_client_info(
+    blockchain: SupportedBlockchain,
+    role: &str,
+    client_id: &str,
+) -> IbcClientInfo {
+    let mut info = IbcClientInfo::new(role, client_id);
+    if let Ok(channel) = blockchain.channel().await {
+        if let Ok(res) = cosmos::query::ibc::get_client_state(
+            channel.clone(),
+            cosmos_sdk_proto::ibc::core::client::v1::QueryClientStateRequest {
+                client_id: client_id.to_string(),
+            },
+        )
+        .await
+        {
+            info.chain_id = res
+                .client_state
+                .filter(|any| any.type_url == "/ibc.lightclients.tendermint.v1.ClientState")
+                .and_then(|any| {
+                    <TendermintClientState as prost::Message>::decode(&any.value[..]).ok()
+                })
+                .map(|client_state| client_state.chain_id);
+        }
+        if let Ok(res) = cosmos::query::ibc::get_client_status(
+            channel,
+            cosmos_sdk_proto::ibc::core::client::v1::QueryClientStatusRequest {
+                client_id: client_id.to_string(),
+            },
+        )
+        .await
+        {
+            info.status = Some(res.status);
+        }
+    }
+    info
+}
@@ -1,2 +1,7 @@
 pub mod gov;
+pub mod ibc;
+pub mod params;
 pub mod staking;
+pub mod tendermint;
+pub mod upgrade;
+pub mod wasm;
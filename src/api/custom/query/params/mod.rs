@@ -0,0 +1,105 @@
+use crate::api::core::cosmos::channels::SupportedBlockchain;
+use crate::api::core::*;
+
+use crate::api::custom::types::gov::params_change_ext::ParamsModule;
+
+// The core queries return `cosmos_sdk_proto` types, which do not implement `Serialize`.
+// Re-decoding the params with the injective/osmosis std types gives us the same JSON representation
+// as the params set contained in a `MsgUpdateParams`, so both can be compared field by field.
+fn reencode_to_json<T, R>(params: &T) -> anyhow::Result<serde_json::Value>
+where
+    T: cosmos_sdk_proto::traits::Message,
+    R: osmosis_prost::Message + Default + serde::Serialize,
+{
+    let decoded: R = osmosis_prost::Message::decode(&params.encode_to_vec()[..])?;
+    Ok(serde_json::to_value(decoded)?)
+}
+
+async fn get_gov_params(blockchain: &SupportedBlockchain) -> anyhow::Result<serde_json::Value> {
+    let mut merged = serde_json::Map::new();
+    for params_type in ["voting", "deposit", "tallying"] {
+        let channel = blockchain.channel().await?;
+        let res = cosmos::query::gov::get_params_v1(
+            channel,
+            cosmos_sdk_proto::cosmos::gov::v1::QueryParamsRequest {
+                params_type: params_type.to_string(),
+            },
+        )
+        .await?;
+        let value = reencode_to_json::<_, injective_std::types::cosmos::gov::v1::QueryParamsResponse>(&res)?;
+        // gov v1 `Params` is flat, the legacy response nests the values by params type.
+        if let Some(map) = value.as_object() {
+            for (_, nested) in map.iter() {
+                if let Some(nested) = nested.as_object() {
+                    merged.extend(nested.clone());
+                }
+            }
+        }
+    }
+    Ok(serde_json::Value::Object(merged))
+}
+
+/// Query the current on-chain params of the given module.
+///
+/// The params are returned as JSON, using the same field names as the params set of the module's `MsgUpdateParams`.
+///
+/// # Errors
+///
+/// Returns an error if the node does not serve the params query of the module (e.g. x/consensus on older SDK versions).
+pub async fn get_module_params(
+    blockchain: SupportedBlockchain,
+    module: ParamsModule,
+) -> anyhow::Result<serde_json::Value> {
+    match module {
+        ParamsModule::Gov => get_gov_params(&blockchain).await,
+        ParamsModule::Staking => {
+            let res = cosmos::query::staking::get_params(blockchain.channel().await?).await?;
+            reencode_to_json::<_, injective_std::types::cosmos::staking::v1beta1::Params>(&res.params.unwrap_or_default())
+        }
+        ParamsModule::Mint => {
+            let res = cosmos::query::mint::get_params(blockchain.channel().await?).await?;
+            reencode_to_json::<_, injective_std::types::cosmos::mint::v1beta1::Params>(&res.params.unwrap_or_default())
+        }
+        ParamsModule::Distribution => {
+            let res = cosmos::query::distribution::get_params(blockchain.channel().await?).await?;
+            reencode_to_json::<_, injective_std::types::cosmos::distribution::v1beta1::Params>(&res.params.unwrap_or_default())
+        }
+        ParamsModule::Bank => {
+            let res = cosmos::query::bank::get_params(blockchain.channel().await?).await?;
+            reencode_to_json::<_, injective_std::types::cosmos::bank::v1beta1::Params>(&res.params.unwrap_or_default())
+        }
+        ParamsModule::Slashing => {
+            let res = cosmos::query::slashing::get_params(blockchain.channel().await?).await?;
+            reencode_to_json::<_, injective_std::types::cosmos::slashing::v1beta1::Params>(&res.params.unwrap_or_default())
+        }
+        ParamsModule::Wasm => {
+            let res = cosmos::query::get_wasm_params(blockchain.channel().await?).await?;
+            reencode_to_json::<_, osmosis_std::types::cosmwasm::wasm::v1::Params>(&res.params.unwrap_or_default())
+        }
+        ParamsModule::Consensus => Err(anyhow::anyhow!(
+            "Error: {} does not provide a query for the {} params.",
+            blockchain.name,
+            module
+        )),
+    }
+}
+
+/// Query the current value of a legacy x/params `subspace/key`, as JSON encoded string.
+pub async fn get_legacy_param(
+    blockchain: SupportedBlockchain,
+    subspace: &str,
+    key: &str,
+) -> anyhow::Result<String> {
+    let channel = blockchain.channel().await?;
+    let res = cosmos::query::params::get_params(
+        channel,
+        cosmos_sdk_proto::cosmos::params::v1beta1::QueryParamsRequest {
+            subspace: subspace.to_string(),
+            key: key.to_string(),
+        },
+    )
+    .await?;
+    res.param
+        .map(|param| param.value)
+        .ok_or(anyhow::anyhow!("Error: {}/{} not found.", subspace, key))
+}
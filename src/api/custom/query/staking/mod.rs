@@ -15,21 +15,12 @@ mod test {
     // cargo test api::custom::query::staking::test::test_get_pool_function -- --exact --nocapture
 
     use super::*;
-    use crate::api::core::cosmos::channels::GRPC_Service;
+    use crate::api::core::cosmos::channels::test_blockchain;
 
     #[tokio::test]
     async fn test_get_pool_function() {
-        let supported_blockchain = SupportedBlockchain {
-            display: "Osmosis".to_string(),
-            name: "osmosis".to_string(),
-            prefix: "osmo".to_string(),
-            grpc_service: GRPC_Service {
-                grpc_urls: vec!["https://osmosis-grpc.lavenderfive.com:443".to_string()],
-                error: None,
-            },
-            rank: 1,
-            governance_proposals_link: "".to_string(),
-        };
+        let mut supported_blockchain = test_blockchain();
+        supported_blockchain.grpc_service.grpc_urls = vec!["https://osmosis-grpc.lavenderfive.com:443".to_string()];
         let result = get_pool(supported_blockchain).await;
         println!("Result: {:?}", result);
         assert!(result.is_ok());
@@ -0,0 +1,73 @@
+use crate::api::core::cosmos::channels::SupportedBlockchain;
+use crate::api::core::*;
+
+// Number of blocks used to compute the average block time.
+const BLOCK_TIME_SAMPLE_SIZE: i64 = 10_000;
+
+async fn get_block_height_and_time(
+    blockchain: &SupportedBlockchain,
+    height: Option<i64>,
+) -> anyhow::Result<(i64, i64)> {
+    let channel = blockchain.channel().await?;
+    let block = match height {
+        Some(height) => cosmos::query::tendermint::get_block_by_height(channel, height).await?.block,
+        None => cosmos::query::tendermint::get_latest_block(channel).await?.block,
+    };
+    let header = block
+        .and_then(|block| block.header)
+        .ok_or(anyhow::anyhow!("Error: block without header"))?;
+    let time = header
+        .time
+        .ok_or(anyhow::anyhow!("Error: block header without time"))?;
+    Ok((header.height, time.seconds * 1000 + (time.nanos as i64) / 1_000_000))
+}
+
+// CometBFT rejects heights below the earliest stored block with "height X is not available, lowest height is Y".
+fn lowest_available_height(error: &str) -> Option<i64> {
+    let (_, rest) = error.split_once("lowest height is ")?;
+    let digits: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+    digits.parse().ok()
+}
+
+/// Returns the latest block height, its unix timestamp (seconds) and the average block time (milliseconds).
+///
+/// The average is taken over the last `BLOCK_TIME_SAMPLE_SIZE` blocks, or over all blocks if the chain is younger.
+pub async fn get_latest_height_and_block_time(
+    blockchain: SupportedBlockchain,
+) -> anyhow::Result<(i64, i64, u64)> {
+    let (latest_height, latest_time_ms) = get_block_height_and_time(&blockchain, None).await?;
+    let sample_height = (latest_height - BLOCK_TIME_SAMPLE_SIZE).max(1);
+    if sample_height >= latest_height {
+        return Err(anyhow::anyhow!("Error: not enough blocks to compute the block time"));
+    }
+    let (sample_height, sample_time_ms) = match get_block_height_and_time(&blockchain, Some(sample_height)).await {
+        Ok(block) => block,
+        // Pruned nodes and chains started with `initial_height > 1` do not have the older blocks.
+        Err(err) => match lowest_available_height(&err.to_string()) {
+            Some(lowest) if lowest > sample_height && lowest < latest_height => {
+                get_block_height_and_time(&blockchain, Some(lowest)).await?
+            }
+            _ => return Err(err),
+        },
+    };
+    let average_block_time_ms =
+        (latest_time_ms - sample_time_ms).max(0) as u64 / (latest_height - sample_height) as u64;
+    Ok((latest_height, latest_time_ms / 1000, average_block_time_ms))
+}
+
+#[cfg(test)]
+mod test {
+
+    // cargo test api::custom::query::tendermint::test::test_lowest_available_height -- --exact --nocapture
+
+    use super::*;
+
+    #[test]
+    fn test_lowest_available_height() {
+        assert_eq!(
+            lowest_available_height("status: InvalidArgument, message: \"height 1 is not available, lowest height is 5200791\""),
+            Some(5200791)
+        );
+        assert_eq!(lowest_available_height("status: Unavailable, message: \"connection refused\""), None);
+    }
+}
@@ -0,0 +1,35 @@
+use crate::api::core::cosmos::channels::SupportedBlockchain;
+use crate::api::core::*;
+
+use crate::api::custom::query::tendermint::get_latest_height_and_block_time;
+use crate::api::custom::types::upgrade::plan_ext::{PlanInfo, UpgradePlanExt};
+
+pub async fn get_current_plan(blockchain: SupportedBlockchain) -> anyhow::Result<Option<PlanInfo>> {
+    let channel = blockchain.channel().await?;
+    let res = cosmos::query::upgrade::get_current_plan(channel).await?;
+    Ok(res.plan.as_ref().map(PlanInfo::from_plan))
+}
+
+/// Combines the proposed plan with the currently scheduled plan and the chain's block time,
+/// so that the upgrade time can be estimated.
+///
+/// Values that can not be queried are left empty, except for the scheduled plan: its query error is kept in
+/// `scheduled_error`, so that a failed query is not mistaken for "no upgrade scheduled".
+pub async fn get_upgrade_plan(
+    blockchain: SupportedBlockchain,
+    proposed: Option<PlanInfo>,
+) -> UpgradePlanExt {
+    let (scheduled, scheduled_error) = match get_current_plan(blockchain.clone()).await {
+        Ok(scheduled) => (scheduled, None),
+        Err(err) => (None, Some(err.to_string())),
+    };
+    let block_time = get_latest_height_and_block_time(blockchain).await.ok();
+    UpgradePlanExt {
+        proposed,
+        scheduled,
+        scheduled_error,
+        latest_height: block_time.map(|(height, _, _)| height),
+        latest_time: block_time.map(|(_, time, _)| time),
+        average_block_time_ms: block_time.map(|(_, _, average)| average),
+    }
+}
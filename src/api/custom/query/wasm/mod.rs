@@ -0,0 +1,80 @@
+use crate::api::core::cosmos::channels::SupportedBlockchain;
+use crate::api::core::*;
+
+use cosmos_sdk_proto::cosmos::base::query::v1beta1::PageRequest;
+
+use crate::api::custom::types::wasm::contract_ext::{
+    CodeInfoExt, ContractHistoryEntry, ContractInfoExt, WasmDetailsExt, WasmTarget,
+};
+
+pub async fn get_code_info(blockchain: SupportedBlockchain, code_id: u64) -> anyhow::Result<CodeInfoExt> {
+    let channel = blockchain.channel().await?;
+    let res = cosmos::query::get_code(channel, code_id).await?;
+    res.code_info
+        .as_ref()
+        .map(CodeInfoExt::from_code_info_response)
+        .ok_or(anyhow::anyhow!("Error: Code ID {} not found.", code_id))
+}
+
+pub async fn get_contract_info(
+    blockchain: SupportedBlockchain,
+    address: String,
+) -> anyhow::Result<ContractInfoExt> {
+    let channel = blockchain.channel().await?;
+    let res = cosmos::query::get_contract_info(channel.clone(), address.clone()).await?;
+    let mut contract_info = ContractInfoExt::from_contract_info_response(&res)
+        .ok_or(anyhow::anyhow!("Error: Contract {} not found.", address))?;
+
+    let history = cosmos::query::get_contract_history(
+        channel,
+        cosmos_sdk_proto::cosmwasm::wasm::v1::QueryContractHistoryRequest {
+            address,
+            pagination: Some(PageRequest {
+                key: vec![],
+                offset: 0,
+                limit: 100,
+                count_total: false,
+                reverse: false,
+            }),
+        },
+    )
+    .await;
+    if let Ok(history) = history {
+        contract_info.history = history
+            .entries
+            .iter()
+            .map(|entry| ContractHistoryEntry {
+                operation: entry.operation().as_str_name().to_string(),
+                code_id: entry.code_id,
+            })
+            .collect();
+    }
+    Ok(contract_info)
+}
+
+/// Resolves the contract and code ids of a wasm proposal message.
+///
+/// Values that can not be queried are left empty.
+pub async fn get_wasm_details(blockchain: SupportedBlockchain, target: &WasmTarget) -> WasmDetailsExt {
+    let contract = match &target.contract {
+        Some(address) => get_contract_info(blockchain.clone(), address.to_owned()).await.ok(),
+        None => None,
+    };
+    let current_code = match &contract {
+        Some(contract) => get_code_info(blockchain.clone(), contract.code_id).await.ok(),
+        None => None,
+    };
+    let target_code = match target.code_id {
+        Some(code_id) if Some(code_id) != contract.as_ref().map(|contract| contract.code_id) => {
+            get_code_info(blockchain, code_id).await.ok()
+        }
+        Some(_) => current_code.clone(),
+        None => None,
+    };
+    WasmDetailsExt {
+        contract,
+        current_code,
+        target_code,
+        new_admin: target.new_admin.clone(),
+    }
+}
@@ -0,0 +1,327 @@
+use crate::api::custom::types::gov::params_ext::ParamsExt;
+use crate::api::custom::types::gov::proposal_ext::ProposalExt;
+use crate::api::custom::types::gov::tally_ext::TallyResultExt;
+use crate::api::custom::types::staking::pool_ext::PoolExt;
+
+// Telegram rejects messages longer than 4096 characters (after entity parsing).
+pub const TELEGRAM_MAX_LENGTH: usize = 4096;
+
+const ELLIPSIS: &str = "…";
+
+/// Renders proposals, tallies, params and pools for a target markup.
+///
+/// Implementations only define escaping and the markup of bold text and links,
+/// the layout is shared. All user supplied text (titles, descriptions) is escaped,
+/// and the rendered proposal is truncated to `max_length` characters by shortening the description.
+pub trait ProposalRenderer {
+    fn max_length(&self) -> usize;
+
+    fn escape(&self, text: &str) -> String;
+
+    // `text` is already escaped.
+    fn bold(&self, text: &str) -> String;
+
+    // `text` is already escaped, `url` is raw.
+    fn link(&self, text: &str, url: &str) -> String;
+
+    /// Escapes `text` and cuts it so that the escaped result (with "…") is at most `max_chars` characters.
+    /// Escape sequences are never split.
+    fn escape_truncated(&self, text: &str, max_chars: usize) -> String {
+        let escaped = self.escape(text);
+        if escaped.chars().count() <= max_chars {
+            return escaped;
+        }
+        let ellipsis = self.escape(ELLIPSIS);
+        let budget = max_chars.saturating_sub(ellipsis.chars().count());
+        let mut output = String::new();
+        let mut length = 0usize;
+        let mut buffer = [0u8; 4];
+        for c in text.chars() {
+            let escaped_char = self.escape(c.encode_utf8(&mut buffer));
+            let char_length = escaped_char.chars().count();
+            if length + char_length > budget {
+                break;
+            }
+            length += char_length;
+            output.push_str(&escaped_char);
+        }
+        let output = output.trim_end().to_string();
+        format!("{}{}", output, ellipsis)
+    }
+
+    fn render_proposal_preview(&self, proposal: &ProposalExt) -> String {
+        let mut parts = vec![
+            self.bold(&self.escape(&proposal.blockchain.display)),
+            self.escape(
+                &proposal
+                    .messages_as_proposal_content()
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<String>>()
+                    .join("\n"),
+            ),
+            format!(
+                "{}\n{}",
+                self.escape(&format!(
+                    "#{}  -  {}",
+                    proposal.get_proposal_id(),
+                    proposal.get_proposal_status().to_icon()
+                )),
+                self.bold(&self.escape(&proposal.get_title()))
+            ),
+        ];
+        if let Some(label) = proposal.fraud_label() {
+            parts.push(self.escape(&label));
+        }
+        parts.join("\n\n")
+    }
+
+    fn render_proposal_state(&self, proposal: &ProposalExt) -> String {
+        self.escape(&proposal.proposal_state())
+    }
+
+    /// Preview, state, link and description of a proposal, within `max_length`.
+    fn render_proposal(&self, proposal: &ProposalExt) -> String {
+        let mut parts = vec![
+            self.render_proposal_preview(proposal),
+            self.render_proposal_state(proposal),
+        ];
+        if !proposal.blockchain.governance_proposals_link.is_empty() {
+            parts.push(self.link(
+                &self.escape(&format!("Proposal #{}", proposal.get_proposal_id())),
+                &proposal.governance_proposal_link(),
+            ));
+        }
+        let header = parts
+            .into_iter()
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<String>>()
+            .join("\n\n");
+
+        let description = proposal.get_description();
+        let header_length = header.chars().count();
+        if description.trim().is_empty() || header_length + 2 >= self.max_length() {
+            return self.truncate_lines(header);
+        }
+        let budget = self.max_length() - header_length - 2;
+        format!("{}\n\n{}", header, self.escape_truncated(description.trim(), budget))
+    }
+
+    fn render_tally(&self, tally: &TallyResultExt) -> String {
+        let mut output = self.escape(&tally.current_tally());
+        if let Ok(details) = tally.tally_details() {
+            if !details.is_empty() {
+                output.push_str(&self.escape(&details));
+            }
+        }
+        output
+    }
+
+    fn render_params(&self, params: &ParamsExt) -> String {
+        self.escape(params.to_string().trim())
+    }
+
+    fn render_pool(&self, pool: &PoolExt) -> String {
+        pool.get_pool_details()
+            .map(|details| self.escape(details.trim()))
+            .unwrap_or_default()
+    }
+
+    // A header that does not fit on its own is cut at a line break, so that no markup is split.
+    fn truncate_lines(&self, rendered: String) -> String {
+        if rendered.chars().count() <= self.max_length() {
+            return rendered;
+        }
+        let mut output = String::new();
+        for line in rendered.split('\n') {
+            if output.chars().count() + line.chars().count() + 1 > self.max_length() {
+                break;
+            }
+            if !output.is_empty() {
+                output.push('\n');
+            }
+            output.push_str(line);
+        }
+        output
+    }
+}
+
+// Plain text, the format of `preview_msg` and `proposal_state`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlainRenderer {
+    pub max_length: usize,
+}
+
+impl Default for PlainRenderer {
+    fn default() -> Self {
+        Self {
+            max_length: TELEGRAM_MAX_LENGTH,
+        }
+    }
+}
+
+impl ProposalRenderer for PlainRenderer {
+    fn max_length(&self) -> usize {
+        self.max_length
+    }
+
+    fn escape(&self, text: &str) -> String {
+        text.to_string()
+    }
+
+    fn bold(&self, text: &str) -> String {
+        text.to_string()
+    }
+
+    fn link(&self, text: &str, url: &str) -> String {
+        format!("{}: {}", text, url)
+    }
+}
+
+// CommonMark.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarkdownRenderer {
+    pub max_length: usize,
+}
+
+impl Default for MarkdownRenderer {
+    fn default() -> Self {
+        Self {
+            max_length: TELEGRAM_MAX_LENGTH,
+        }
+    }
+}
+
+impl ProposalRenderer for MarkdownRenderer {
+    fn max_length(&self) -> usize {
+        self.max_length
+    }
+
+    fn escape(&self, text: &str) -> String {
+        escape_with(text, |c| "\\`*_{}[]()#+-.!|<>~".contains(c))
+    }
+
+    fn bold(&self, text: &str) -> String {
+        format!("**{}**", text)
+    }
+
+    fn link(&self, text: &str, url: &str) -> String {
+        format!("[{}](<{}>)", text, url.replace('<', "%3C").replace('>', "%3E"))
+    }
+}
+
+// HTML as accepted by Telegram (`parse_mode=HTML`), newlines are kept as is.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HtmlRenderer {
+    pub max_length: usize,
+}
+
+impl Default for HtmlRenderer {
+    fn default() -> Self {
+        Self {
+            max_length: TELEGRAM_MAX_LENGTH,
+        }
+    }
+}
+
+impl ProposalRenderer for HtmlRenderer {
+    fn max_length(&self) -> usize {
+        self.max_length
+    }
+
+    fn escape(&self, text: &str) -> String {
+        let mut output = String::with_capacity(text.len());
+        for c in text.chars() {
+            match c {
+                '&' => output.push_str("&amp;"),
+                '<' => output.push_str("&lt;"),
+                '>' => output.push_str("&gt;"),
+                '"' => output.push_str("&quot;"),
+                c => output.push(c),
+            }
+        }
+        output
+    }
+
+    fn bold(&self, text: &str) -> String {
+        format!("<b>{}</b>", text)
+    }
+
+    fn link(&self, text: &str, url: &str) -> String {
+        format!("<a href=\"{}\">{}</a>", self.escape(url), text)
+    }
+}
+
+// Telegram `parse_mode=MarkdownV2`, see https://core.telegram.org/bots/api#markdownv2-style
+#[derive(Debug, Clone, PartialEq)]
+pub struct TelegramMarkdownV2Renderer {
+    pub max_length: usize,
+}
+
+impl Default for TelegramMarkdownV2Renderer {
+    fn default() -> Self {
+        Self {
+            max_length: TELEGRAM_MAX_LENGTH,
+        }
+    }
+}
+
+impl ProposalRenderer for TelegramMarkdownV2Renderer {
+    fn max_length(&self) -> usize {
+        self.max_length
+    }
+
+    fn escape(&self, text: &str) -> String {
+        escape_with(text, |c| "\\_*[]()~`>#+-=|{}.!".contains(c))
+    }
+
+    fn bold(&self, text: &str) -> String {
+        format!("*{}*", text)
+    }
+
+    // Inside the url only `)` and `\` have to be escaped.
+    fn link(&self, text: &str, url: &str) -> String {
+        format!("[{}]({})", text, escape_with(url, |c| c == ')' || c == '\\'))
+    }
+}
+
+fn escape_with(text: &str, needs_escape: impl Fn(char) -> bool) -> String {
+    let mut output = String::with_capacity(text.len());
+    for c in text.chars() {
+        if needs_escape(c) {
+            output.push('\\');
+        }
+        output.push(c);
+    }
+    output
+}
+
+#[cfg(test)]
+mod test {
+
+    // cargo test api::custom::render::test::test_renderers -- --exact --nocapture
+
+    use super::*;
+
+    #[test]
+    fn test_renderers() {
+        let text = "a_b *c* <d> 1.5";
+        assert_eq!(PlainRenderer::default().escape(text), text);
+        assert_eq!(MarkdownRenderer::default().escape(text), "a\\_b \\*c\\* \\<d\\> 1\\.5");
+        assert_eq!(HtmlRenderer::default().escape(text), "a_b *c* &lt;d&gt; 1.5");
+        assert_eq!(
+            TelegramMarkdownV2Renderer::default().escape(text),
+            "a\\_b \\*c\\* <d\\> 1\\.5"
+        );
+        assert_eq!(
+            TelegramMarkdownV2Renderer::default().link("link", "https://x.y/(1)"),
+            "[link](https://x.y/(1\\))"
+        );
+
+        // Truncation never splits an escape sequence.
+        let renderer = TelegramMarkdownV2Renderer { max_length: 100 };
+        let truncated = renderer.escape_truncated("ab.cd", 5);
+        assert_eq!(truncated, "ab\\.…");
+        assert!(renderer.escape_truncated(&"x.".repeat(100), 20).chars().count() <= 20);
+    }
+}
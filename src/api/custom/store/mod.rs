@@ -0,0 +1,326 @@
+use chrono::{DateTime, Utc};
+use log::{error, info};
+use serde::{Deserialize, Serialize};
+
+use crate::api::core::cosmos::channels::SupportedBlockchain;
+use crate::api::custom::query::gov::{get_active_proposals, get_gov_params, get_proposal, get_proposals_after};
+use crate::api::custom::types::gov::common::ProposalStatus;
+use crate::api::custom::types::gov::gov_params_ext::GovParamsExt;
+use crate::api::custom::types::gov::proposal_ext::ProposalExt;
+use crate::api::custom::types::versioned_ext::Versioned;
+
+const PROPOSALS_TREE: &str = "proposals";
+const GOV_PARAMS_TREE: &str = "gov_params";
+
+// Records are keyed by `<chain name> 0x00 <proposal id (big endian)>`, so that the proposals
+// of a chain are a contiguous range ordered by id.
+fn chain_prefix(chain_name: &str) -> Vec<u8> {
+    let mut prefix = chain_name.to_lowercase().into_bytes();
+    prefix.push(0u8);
+    prefix
+}
+
+fn proposal_key(chain_name: &str, proposal_id: u64) -> Vec<u8> {
+    let mut key = chain_prefix(chain_name);
+    key.extend_from_slice(&proposal_id.to_be_bytes());
+    key
+}
+
+// Filter for `ProposalStore::query`, all set conditions must match.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ProposalQuery {
+    pub chain_name: Option<String>,
+    pub status: Option<ProposalStatus>,
+    pub submitted_after: Option<DateTime<Utc>>,
+    pub submitted_before: Option<DateTime<Utc>>,
+    // Matched against `ProposalExt::get_proposal_types`, case insensitive (e.g. "TextProposal", "upgrade").
+    pub proposal_type: Option<String>,
+}
+
+impl ProposalQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn chain(mut self, chain_name: &str) -> Self {
+        self.chain_name = Some(chain_name.to_lowercase());
+        self
+    }
+
+    pub fn status(mut self, status: ProposalStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn submitted_between(mut self, after: DateTime<Utc>, before: DateTime<Utc>) -> Self {
+        self.submitted_after = Some(after);
+        self.submitted_before = Some(before);
+        self
+    }
+
+    pub fn proposal_type(mut self, proposal_type: &str) -> Self {
+        self.proposal_type = Some(proposal_type.to_lowercase());
+        self
+    }
+
+    pub fn matches(&self, proposal: &ProposalExt) -> bool {
+        if let Some(status) = &self.status {
+            if &proposal.get_proposal_status() != status {
+                return false;
+            }
+        }
+        if self.submitted_after.is_some() || self.submitted_before.is_some() {
+            let submitted = match proposal.proposal.0.submit_time.as_ref() {
+                Some(time) => time.seconds,
+                None => return false,
+            };
+            if self.submitted_after.map(|after| submitted < after.timestamp()).unwrap_or(false)
+                || self.submitted_before.map(|before| submitted > before.timestamp()).unwrap_or(false)
+            {
+                return false;
+            }
+        }
+        if let Some(proposal_type) = &self.proposal_type {
+            if !proposal
+                .get_proposal_types()
+                .iter()
+                .any(|t| t.to_lowercase().contains(proposal_type.as_str()))
+            {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub struct SyncReport {
+    pub added: usize,
+    pub updated: usize,
+    pub removed: usize,
+}
+
+/// Embedded on-disk store of `ProposalExt` records (including the attached tally and params),
+/// keyed by chain and proposal id, plus the latest `GovParamsExt` of each chain.
+pub struct ProposalStore {
+    proposals: sled::Tree,
+    gov_params: sled::Tree,
+}
+
+impl ProposalStore {
+    pub fn open(path: &str) -> anyhow::Result<Self> {
+        Self::from_db(sled::open(path)?)
+    }
+
+    pub fn from_db(db: sled::Db) -> anyhow::Result<Self> {
+        Ok(Self {
+            proposals: db.open_tree(PROPOSALS_TREE)?,
+            gov_params: db.open_tree(GOV_PARAMS_TREE)?,
+        })
+    }
+
+    pub fn insert(&self, proposal: &ProposalExt) -> anyhow::Result<()> {
+        let key = proposal_key(&proposal.blockchain.get_name(), proposal.get_proposal_id());
+        self.proposals.insert(key, proposal.to_versioned_vec()?)?;
+        Ok(())
+    }
+
+    pub fn get(&self, chain_name: &str, proposal_id: u64) -> anyhow::Result<Option<ProposalExt>> {
+        match self.proposals.get(proposal_key(chain_name, proposal_id))? {
+            Some(value) => Ok(Some(ProposalExt::from_versioned_slice(&value)?)),
+            None => Ok(None),
+        }
+    }
+
+    pub fn remove(&self, chain_name: &str, proposal_id: u64) -> anyhow::Result<()> {
+        self.proposals.remove(proposal_key(chain_name, proposal_id))?;
+        Ok(())
+    }
+
+    /// All stored proposals of a chain, ordered by id.
+    pub fn proposals(&self, chain_name: &str) -> anyhow::Result<Vec<ProposalExt>> {
+        self.proposals
+            .scan_prefix(chain_prefix(chain_name))
+            .values()
+            .map(|value| ProposalExt::from_versioned_slice(&value?))
+            .collect()
+    }
+
+    pub fn highest_proposal_id(&self, chain_name: &str) -> anyhow::Result<Option<u64>> {
+        let prefix = chain_prefix(chain_name);
+        match self.proposals.scan_prefix(&prefix).keys().next_back() {
+            Some(key) => {
+                let key = key?;
+                let id: [u8; 8] = key[prefix.len()..].try_into()?;
+                Ok(Some(u64::from_be_bytes(id)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub fn query(&self, query: &ProposalQuery) -> anyhow::Result<Vec<ProposalExt>> {
+        let values = match &query.chain_name {
+            Some(chain_name) => self.proposals.scan_prefix(chain_prefix(chain_name)).values(),
+            None => self.proposals.iter().values(),
+        };
+        let mut result = Vec::new();
+        for value in values {
+            let proposal = ProposalExt::from_versioned_slice(&value?)?;
+            if query.matches(&proposal) {
+                result.push(proposal);
+            }
+        }
+        Ok(result)
+    }
+
+    pub fn insert_gov_params(&self, gov_params: &GovParamsExt) -> anyhow::Result<()> {
+        self.gov_params
+            .insert(gov_params.blockchain.get_name(), gov_params.to_versioned_vec()?)?;
+        Ok(())
+    }
+
+    pub fn gov_params(&self, chain_name: &str) -> anyhow::Result<Option<GovParamsExt>> {
+        match self.gov_params.get(chain_name.to_lowercase())? {
+            Some(value) => Ok(Some(GovParamsExt::from_versioned_slice(&value)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Fetches the proposals submitted since the last sync and refreshes the ones that were
+    /// in deposit or voting period, instead of the full history of the chain.
+    ///
+    /// Proposals that were removed by the chain (deposit period expired) are removed from the store.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the proposals can not be queried or the store can not be written.
+    pub async fn sync(&self, blockchain: &SupportedBlockchain) -> anyhow::Result<SyncReport> {
+        let chain_name = blockchain.get_name();
+        let mut report = SyncReport::default();
+
+        match get_gov_params(blockchain.clone()).await {
+            Ok(gov_params) => self.insert_gov_params(&gov_params)?,
+            Err(err) => error!("Failed to query the gov params of {}: {}", chain_name, err),
+        }
+
+        let stored_active: Vec<ProposalExt> = self
+            .proposals(&chain_name)?
+            .into_iter()
+            .filter(|proposal| !proposal.is_final_state())
+            .collect();
+
+        let highest_proposal_id = self.highest_proposal_id(&chain_name)?;
+        let new_proposals = get_proposals_after(blockchain.clone(), highest_proposal_id.unwrap_or(0)).await?;
+        let mut active = get_active_proposals(blockchain.clone()).await?;
+        for proposal in new_proposals {
+            if !active.iter().any(|a| a.get_proposal_id() == proposal.get_proposal_id()) {
+                active.push(proposal);
+            }
+        }
+
+        for proposal in active.iter() {
+            let is_new = highest_proposal_id
+                .map(|id| proposal.get_proposal_id() > id)
+                .unwrap_or(true);
+            if is_new {
+                report.added += 1;
+            } else {
+                report.updated += 1;
+            }
+            self.insert(proposal)?;
+        }
+
+        // Proposals that left the deposit or voting period since the last sync.
+        for old in stored_active
+            .iter()
+            .filter(|old| !active.iter().any(|a| a.get_proposal_id() == old.get_proposal_id()))
+        {
+            match get_proposal(blockchain.clone(), old.get_proposal_id()).await {
+                Ok(Some(current)) => {
+                    self.insert(&current)?;
+                    report.updated += 1;
+                }
+                Ok(None) => {
+                    self.remove(&chain_name, old.get_proposal_id())?;
+                    report.removed += 1;
+                }
+                Err(err) if err.code() == tonic::Code::NotFound => {
+                    self.remove(&chain_name, old.get_proposal_id())?;
+                    report.removed += 1;
+                }
+                Err(err) => error!("Failed to refresh proposal {} of {}: {}", old.get_proposal_id(), chain_name, err),
+            }
+        }
+        self.proposals.flush_async().await?;
+        info!("Synced proposals of {}: {:?}", chain_name, report);
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    // cargo test api::custom::store::test::test_proposal_store -- --exact --nocapture
+
+    use super::*;
+    use crate::api::core::cosmos::channels::test_blockchain;
+    use crate::api::custom::types::gov::gov_params_ext::GovParamsVersion;
+    use crate::api::custom::types::gov::tally_projection::TallyThresholds;
+    use chrono::TimeZone;
+    use rust_decimal::Decimal;
+
+    #[test]
+    fn test_proposal_store() {
+        let store = ProposalStore::from_db(sled::Config::new().temporary(true).open().unwrap()).unwrap();
+        let blockchain = test_blockchain();
+        for (id, status) in [(2u64, ProposalStatus::StatusPassed), (10, ProposalStatus::StatusVotingPeriod)] {
+            store
+                .insert(&ProposalExt::new(
+                    &blockchain,
+                    cosmos_sdk_proto::cosmos::gov::v1::Proposal {
+                        id,
+                        status: status.into(),
+                        submit_time: Some(prost_types::Timestamp { seconds: id as i64 * 1000, nanos: 0 }),
+                        ..Default::default()
+                    },
+                ))
+                .unwrap();
+        }
+
+        assert_eq!(store.highest_proposal_id("osmosis").unwrap(), Some(10));
+        assert_eq!(store.highest_proposal_id("juno").unwrap(), None);
+        assert_eq!(store.get("osmosis", 2).unwrap().unwrap().get_proposal_id(), 2);
+
+        let voting = store
+            .query(&ProposalQuery::new().chain("osmosis").status(ProposalStatus::StatusVotingPeriod))
+            .unwrap();
+        assert_eq!(voting.len(), 1);
+        let early = store
+            .query(&ProposalQuery::new().submitted_between(
+                Utc.timestamp_opt(0, 0).unwrap(),
+                Utc.timestamp_opt(5000, 0).unwrap(),
+            ))
+            .unwrap();
+        assert_eq!(early.len(), 1);
+        assert_eq!(early[0].get_proposal_id(), 2);
+
+        let gov_params = GovParamsExt::from_separate_params(
+            blockchain,
+            GovParamsVersion::V1Beta1,
+            &None,
+            &[],
+            &None,
+            TallyThresholds {
+                quorum: Decimal::new(2, 1),
+                threshold: Decimal::new(5, 1),
+                veto_threshold: Decimal::new(334, 3),
+                expedited_threshold: None,
+            },
+        )
+        .unwrap();
+        store.insert_gov_params(&gov_params).unwrap();
+        let stored: serde_json::Value = serde_json::from_slice(&store.gov_params.get("osmosis").unwrap().unwrap()).unwrap();
+        assert_eq!(stored["schema_version"], GovParamsExt::SCHEMA_VERSION);
+        assert_eq!(store.gov_params("Osmosis").unwrap(), Some(gov_params));
+    }
+}
@@ -0,0 +1,85 @@
+use std::pin::Pin;
+
+use futures::stream::Stream;
+use futures::task::*;
+use futures::StreamExt;
+
+use crate::api::core::cosmos::channels::SupportedBlockchain;
+use crate::api::core::cosmos::websocket::{to_websocket_url, Backoff, RpcSubscription};
+use crate::api::custom::types::rpc_event_ext::RpcEventExt;
+
+// Queries for the events the governance bot is interested in.
+pub const NEW_BLOCK_QUERY: &str = "tm.event='NewBlock'";
+pub const SUBMIT_PROPOSAL_QUERY: &str = "submit_proposal.proposal_id EXISTS";
+
+// CometBFT query strings can not escape quotes, so addresses containing one are rejected.
+pub fn sender_query(sender: &str) -> anyhow::Result<String> {
+    if sender.contains('\'') {
+        return Err(anyhow::anyhow!("Error: Invalid sender {:?} in subscription query.", sender));
+    }
+    Ok(format!("tm.event='Tx' AND message.sender='{}'", sender))
+}
+
+/// Typed stream of a CometBFT websocket subscription.
+pub struct RpcEventStream {
+    subscription: RpcSubscription,
+}
+
+impl Stream for RpcEventStream {
+    type Item = anyhow::Result<RpcEventExt>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match self.subscription.poll_next_unpin(cx) {
+            Poll::Ready(Some(result)) => Poll::Ready(Some(RpcEventExt::from_rpc_result(&result))),
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// Subscribes to CometBFT events of a chain (e.g. `NEW_BLOCK_QUERY`), using its `rpc_urls`.
+///
+/// # Errors
+///
+/// Returns an error if the chain has no RPC endpoint.
+pub fn subscribe(blockchain: &SupportedBlockchain, queries: Vec<String>) -> anyhow::Result<RpcEventStream> {
+    subscribe_with_backoff(blockchain, queries, Backoff::default())
+}
+
+pub fn subscribe_with_backoff(
+    blockchain: &SupportedBlockchain,
+    queries: Vec<String>,
+    backoff: Backoff,
+) -> anyhow::Result<RpcEventStream> {
+    if blockchain.rpc_urls.is_empty() {
+        return Err(anyhow::anyhow!(
+            "Error: {} has no RPC endpoint to subscribe to.",
+            blockchain.name
+        ));
+    }
+    let websocket_urls = blockchain
+        .rpc_urls
+        .iter()
+        .map(|rpc_url| to_websocket_url(rpc_url))
+        .collect();
+    Ok(RpcEventStream {
+        subscription: RpcSubscription::subscribe(websocket_urls, queries, backoff),
+    })
+}
+
+#[cfg(test)]
+mod test {
+
+    // cargo test api::custom::subscription::test::test_sender_query -- --exact --nocapture
+
+    use super::*;
+
+    #[test]
+    fn test_sender_query() {
+        assert_eq!(
+            sender_query("osmo1sender").unwrap(),
+            "tm.event='Tx' AND message.sender='osmo1sender'"
+        );
+        assert!(sender_query("osmo1' OR tm.event='NewBlock").is_err());
+    }
+}
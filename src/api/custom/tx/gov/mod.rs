@@ -0,0 +1,263 @@
+pub mod proposal_draft;
+
+use cosmos_sdk_proto::cosmos::base::abci::v1beta1::TxResponse;
+use cosmos_sdk_proto::traits::MessageExt;
+use cosmos_sdk_proto::Any;
+use cosmrs::crypto::secp256k1::SigningKey;
+use rust_decimal::Decimal;
+
+use crate::api::core::cosmos::channels::SupportedBlockchain;
+use crate::api::custom::query::gov::get_gov_params;
+use crate::api::custom::tx::{sign_and_broadcast, signer_address, TxOptions};
+use crate::api::custom::types::decimal_ext::{parse_sdk_dec, to_sdk_dec, to_sdk_dec_atomics};
+use crate::api::custom::types::gov::common::VoteOption;
+use crate::api::custom::types::gov::gov_params_ext::{CoinAmount, GovParamsVersion};
+use crate::api::custom::types::gov::vote_ext::WeightedVoteOptionExt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GovAction {
+    Vote(VoteOption),
+    VoteWeighted(Vec<WeightedVoteOptionExt>),
+    Deposit(Vec<CoinAmount>),
+}
+
+pub fn msg_vote(version: GovParamsVersion, proposal_id: u64, voter: &str, option: VoteOption) -> anyhow::Result<Any> {
+    let any = match version {
+        GovParamsVersion::V1Beta1 => cosmos_sdk_proto::cosmos::gov::v1beta1::MsgVote {
+            proposal_id,
+            voter: voter.to_string(),
+            option: option.into(),
+        }
+        .to_any()?,
+        GovParamsVersion::V1Legacy | GovParamsVersion::V1 => cosmos_sdk_proto::cosmos::gov::v1::MsgVote {
+            proposal_id,
+            voter: voter.to_string(),
+            option: option.into(),
+            metadata: "".to_string(),
+        }
+        .to_any()?,
+    };
+    Ok(any)
+}
+
+// Encodes the weights and checks that they still add up to exactly 1 with 18 decimal places,
+// x/gov rejects weighted votes that do not.
+fn encode_weights(
+    options: &[WeightedVoteOptionExt],
+    encode: fn(Decimal) -> String,
+) -> anyhow::Result<Vec<(i32, String)>> {
+    let encoded: Vec<(i32, String)> = options
+        .iter()
+        .map(|weighted| (weighted.option.into(), encode(weighted.weight)))
+        .collect();
+    let mut total = Decimal::ZERO;
+    for (_, weight) in encoded.iter() {
+        total += parse_sdk_dec(weight)?;
+    }
+    if total != Decimal::ONE {
+        return Err(anyhow::anyhow!(
+            "Error: Vote weights must add up to 1, got {}.",
+            total
+        ));
+    }
+    Ok(encoded)
+}
+
+pub fn msg_vote_weighted(
+    version: GovParamsVersion,
+    proposal_id: u64,
+    voter: &str,
+    options: &[WeightedVoteOptionExt],
+) -> anyhow::Result<Any> {
+    let any = match version {
+        GovParamsVersion::V1Beta1 => cosmos_sdk_proto::cosmos::gov::v1beta1::MsgVoteWeighted {
+            proposal_id,
+            voter: voter.to_string(),
+            options: encode_weights(options, to_sdk_dec_atomics)?
+                .into_iter()
+                .map(|(option, weight)| cosmos_sdk_proto::cosmos::gov::v1beta1::WeightedVoteOption { option, weight })
+                .collect(),
+        }
+        .to_any()?,
+        GovParamsVersion::V1Legacy | GovParamsVersion::V1 => cosmos_sdk_proto::cosmos::gov::v1::MsgVoteWeighted {
+            proposal_id,
+            voter: voter.to_string(),
+            options: encode_weights(options, to_sdk_dec)?
+                .into_iter()
+                .map(|(option, weight)| cosmos_sdk_proto::cosmos::gov::v1::WeightedVoteOption { option, weight })
+                .collect(),
+            metadata: "".to_string(),
+        }
+        .to_any()?,
+    };
+    Ok(any)
+}
+
+pub fn msg_deposit(
+    version: GovParamsVersion,
+    proposal_id: u64,
+    depositor: &str,
+    amount: &[CoinAmount],
+) -> anyhow::Result<Any> {
+    let amount = amount.iter().map(|coin| coin.to_coin()).collect();
+    let any = match version {
+        GovParamsVersion::V1Beta1 => cosmos_sdk_proto::cosmos::gov::v1beta1::MsgDeposit {
+            proposal_id,
+            depositor: depositor.to_string(),
+            amount,
+        }
+        .to_any()?,
+        GovParamsVersion::V1Legacy | GovParamsVersion::V1 => cosmos_sdk_proto::cosmos::gov::v1::MsgDeposit {
+            proposal_id,
+            depositor: depositor.to_string(),
+            amount,
+        }
+        .to_any()?,
+    };
+    Ok(any)
+}
+
+/// Wraps the messages in an authz `MsgExec`, executed by `grantee` on behalf of the granter (the signer of the inner messages).
+pub fn msg_exec(grantee: &str, msgs: Vec<Any>) -> anyhow::Result<Any> {
+    Ok(cosmos_sdk_proto::cosmos::authz::v1beta1::MsgExec {
+        grantee: grantee.to_string(),
+        msgs,
+    }
+    .to_any()?)
+}
+
+pub fn build_gov_msg(
+    version: GovParamsVersion,
+    proposal_id: u64,
+    sender: &str,
+    action: &GovAction,
+) -> anyhow::Result<Any> {
+    match action {
+        GovAction::Vote(option) => msg_vote(version, proposal_id, sender, *option),
+        GovAction::VoteWeighted(options) => msg_vote_weighted(version, proposal_id, sender, options),
+        GovAction::Deposit(amount) => msg_deposit(version, proposal_id, sender, amount),
+    }
+}
+
+/// Vote or deposit on a proposal, using the gov message version supported by the chain.
+///
+/// If `granter` is set, the action is taken on behalf of the granter through an authz `MsgExec`
+/// signed by `signing_key` (e.g. a validator that granted voting to a hot key).
+///
+/// # Errors
+///
+/// Returns an error if the gov params can not be queried, the message is invalid or the transaction fails.
+pub async fn execute_gov_action(
+    blockchain: SupportedBlockchain,
+    signing_key: &SigningKey,
+    proposal_id: u64,
+    action: GovAction,
+    granter: Option<String>,
+    options: &TxOptions,
+) -> anyhow::Result<TxResponse> {
+    let version = get_gov_params(blockchain.clone()).await?.version;
+    let signer = signer_address(&blockchain, signing_key)?;
+    let msg = match &granter {
+        Some(granter) => msg_exec(&signer, vec![build_gov_msg(version, proposal_id, granter, &action)?])?,
+        None => build_gov_msg(version, proposal_id, &signer, &action)?,
+    };
+    sign_and_broadcast(&blockchain, signing_key, vec![msg], options).await
+}
+
+#[cfg(test)]
+mod test {
+
+    // cargo test api::custom::tx::gov::test::test_msg_vote_weighted -- --exact --nocapture
+    // cargo test api::custom::tx::gov::test::test_msg_vote_and_deposit -- --exact --nocapture
+
+    use super::*;
+    use cosmos_sdk_proto::prost::Message;
+    use std::str::FromStr;
+
+    const VOTER: &str = "osmo1voter";
+
+    fn weights(weights: &[(VoteOption, &str)]) -> Vec<WeightedVoteOptionExt> {
+        weights
+            .iter()
+            .map(|(option, weight)| WeightedVoteOptionExt {
+                option: *option,
+                weight: Decimal::from_str(weight).unwrap(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_msg_vote_weighted() {
+        let options = weights(&[(VoteOption::Yes, "0.7"), (VoteOption::No, "0.3")]);
+
+        // v1beta1 weights are sdk.Dec atomics on the wire.
+        let any = msg_vote_weighted(GovParamsVersion::V1Beta1, 7, VOTER, &options).unwrap();
+        assert_eq!(any.type_url, "/cosmos.gov.v1beta1.MsgVoteWeighted");
+        let msg = cosmos_sdk_proto::cosmos::gov::v1beta1::MsgVoteWeighted::decode(any.value.as_slice()).unwrap();
+        assert_eq!(msg.proposal_id, 7);
+        assert_eq!(msg.voter, VOTER);
+        let encoded: Vec<(i32, &str)> = msg.options.iter().map(|o| (o.option, o.weight.as_str())).collect();
+        assert_eq!(
+            encoded,
+            vec![(1, "700000000000000000"), (3, "300000000000000000")]
+        );
+
+        // v1 weights are decimal strings.
+        let any = msg_vote_weighted(GovParamsVersion::V1, 7, VOTER, &options).unwrap();
+        assert_eq!(any.type_url, "/cosmos.gov.v1.MsgVoteWeighted");
+        let msg = cosmos_sdk_proto::cosmos::gov::v1::MsgVoteWeighted::decode(any.value.as_slice()).unwrap();
+        let encoded: Vec<(i32, &str)> = msg.options.iter().map(|o| (o.option, o.weight.as_str())).collect();
+        assert_eq!(
+            encoded,
+            vec![(1, "0.700000000000000000"), (3, "0.300000000000000000")]
+        );
+        for option in msg.options.iter() {
+            assert!(parse_sdk_dec(&option.weight).is_ok());
+        }
+
+        // Weights that do not add up to 1 are rejected for both versions.
+        let invalid = weights(&[(VoteOption::Yes, "0.5"), (VoteOption::Abstain, "0.4")]);
+        assert!(msg_vote_weighted(GovParamsVersion::V1Beta1, 7, VOTER, &invalid).is_err());
+        assert!(msg_vote_weighted(GovParamsVersion::V1, 7, VOTER, &invalid).is_err());
+    }
+
+    #[test]
+    fn test_msg_vote_and_deposit() {
+        let any = msg_vote(GovParamsVersion::V1Beta1, 3, VOTER, VoteOption::NoWithVeto).unwrap();
+        assert_eq!(any.type_url, "/cosmos.gov.v1beta1.MsgVote");
+        let msg = cosmos_sdk_proto::cosmos::gov::v1beta1::MsgVote::decode(any.value.as_slice()).unwrap();
+        assert_eq!((msg.proposal_id, msg.voter.as_str(), msg.option), (3, VOTER, 4));
+
+        let any = msg_vote(GovParamsVersion::V1Legacy, 3, VOTER, VoteOption::Yes).unwrap();
+        assert_eq!(any.type_url, "/cosmos.gov.v1.MsgVote");
+        let msg = cosmos_sdk_proto::cosmos::gov::v1::MsgVote::decode(any.value.as_slice()).unwrap();
+        assert_eq!((msg.proposal_id, msg.voter.as_str(), msg.option), (3, VOTER, 1));
+
+        let amount = vec![CoinAmount {
+            denom: "uosmo".to_string(),
+            amount: 340_282_366_920_938_463_463_374_607_431_768_211_455,
+        }];
+        let any = msg_deposit(GovParamsVersion::V1Beta1, 3, VOTER, &amount).unwrap();
+        assert_eq!(any.type_url, "/cosmos.gov.v1beta1.MsgDeposit");
+        let msg = cosmos_sdk_proto::cosmos::gov::v1beta1::MsgDeposit::decode(any.value.as_slice()).unwrap();
+        assert_eq!((msg.proposal_id, msg.depositor.as_str()), (3, VOTER));
+        assert_eq!(msg.amount[0].denom, "uosmo");
+        assert_eq!(msg.amount[0].amount, "340282366920938463463374607431768211455");
+
+        let any = msg_deposit(GovParamsVersion::V1, 3, VOTER, &amount).unwrap();
+        assert_eq!(any.type_url, "/cosmos.gov.v1.MsgDeposit");
+        let msg = cosmos_sdk_proto::cosmos::gov::v1::MsgDeposit::decode(any.value.as_slice()).unwrap();
+        assert_eq!(
+            msg.amount.iter().map(|coin| CoinAmount::from_coin(coin).unwrap()).collect::<Vec<_>>(),
+            amount
+        );
+
+        // The authz wrapper keeps the inner message untouched.
+        let inner = build_gov_msg(GovParamsVersion::V1, 3, "osmo1granter", &GovAction::Vote(VoteOption::Yes)).unwrap();
+        let any = msg_exec("osmo1grantee", vec![inner.clone()]).unwrap();
+        assert_eq!(any.type_url, "/cosmos.authz.v1beta1.MsgExec");
+        let msg = cosmos_sdk_proto::cosmos::authz::v1beta1::MsgExec::decode(any.value.as_slice()).unwrap();
+        assert_eq!(msg.grantee, "osmo1grantee");
+        assert_eq!(msg.msgs, vec![inner]);
+    }
+}
@@ -0,0 +1,429 @@
+use cosmos_sdk_proto::cosmos::base::abci::v1beta1::TxResponse;
+use cosmos_sdk_proto::traits::MessageExt;
+use cosmos_sdk_proto::Any;
+use cosmrs::crypto::secp256k1::SigningKey;
+use prost::Message;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+
+use crate::api::core::cosmos;
+use crate::api::core::cosmos::channels::SupportedBlockchain;
+use crate::api::custom::query::gov::get_gov_params;
+use crate::api::custom::tx::{sign, sign_and_broadcast, signer_address, TxOptions};
+use crate::api::custom::types::decimal_ext::to_decimal;
+use crate::api::custom::types::gov::deposit_ext::DepositProgress;
+use crate::api::custom::types::gov::gov_params_ext::{CoinAmount, GovParamsExt, GovParamsVersion};
+use crate::api::custom::types::gov::proto::{
+    MsgExecLegacyContent, MsgSignerTag1, MsgSignerTag2, MsgSignerTag3, MsgSignerTag6, MsgSubmitProposalV1,
+};
+
+const MSG_SUBMIT_PROPOSAL_V1_TYPE_URL: &str = "/cosmos.gov.v1.MsgSubmitProposal";
+const MSG_EXEC_LEGACY_CONTENT_TYPE_URL: &str = "/cosmos.gov.v1.MsgExecLegacyContent";
+
+// Field tag of the signer (`authority`, `sender`, `signer`, ..) of the messages a proposal can execute.
+const SIGNER_FIELD_TAGS: [(&str, u32); 30] = [
+    ("/cosmos.authz.v1beta1.MsgExec", 1),
+    ("/cosmos.bank.v1beta1.MsgSend", 1),
+    ("/cosmos.bank.v1beta1.MsgUpdateParams", 1),
+    ("/cosmos.consensus.v1.MsgUpdateParams", 1),
+    ("/cosmos.distribution.v1beta1.MsgCommunityPoolSpend", 1),
+    ("/cosmos.distribution.v1beta1.MsgUpdateParams", 1),
+    ("/cosmos.gov.v1.MsgExecLegacyContent", 2),
+    ("/cosmos.gov.v1.MsgUpdateParams", 1),
+    ("/cosmos.mint.v1beta1.MsgUpdateParams", 1),
+    ("/cosmos.slashing.v1beta1.MsgUpdateParams", 1),
+    ("/cosmos.staking.v1beta1.MsgUpdateParams", 1),
+    ("/cosmos.upgrade.v1beta1.MsgCancelUpgrade", 1),
+    ("/cosmos.upgrade.v1beta1.MsgSoftwareUpgrade", 1),
+    ("/cosmwasm.wasm.v1.MsgClearAdmin", 1),
+    ("/cosmwasm.wasm.v1.MsgExecuteContract", 1),
+    ("/cosmwasm.wasm.v1.MsgInstantiateContract", 1),
+    ("/cosmwasm.wasm.v1.MsgInstantiateContract2", 1),
+    ("/cosmwasm.wasm.v1.MsgMigrateContract", 1),
+    ("/cosmwasm.wasm.v1.MsgPinCodes", 1),
+    ("/cosmwasm.wasm.v1.MsgStoreCode", 1),
+    ("/cosmwasm.wasm.v1.MsgSudoContract", 1),
+    ("/cosmwasm.wasm.v1.MsgUnpinCodes", 1),
+    ("/cosmwasm.wasm.v1.MsgUpdateAdmin", 1),
+    ("/cosmwasm.wasm.v1.MsgUpdateInstantiateConfig", 1),
+    ("/cosmwasm.wasm.v1.MsgUpdateParams", 1),
+    ("/ibc.core.client.v1.MsgIBCSoftwareUpgrade", 3),
+    ("/ibc.core.client.v1.MsgRecoverClient", 3),
+    ("/ibc.core.client.v1.MsgUpdateClient", 3),
+    ("/ibc.core.client.v1.MsgUpdateParams", 1),
+    ("/ibc.core.client.v1.MsgUpgradeClient", 6),
+];
+
+/// Reads the signer of a proposal message, an empty string if it is not set.
+///
+/// # Errors
+///
+/// Returns an error if the signer field of the message type is unknown or the message does not decode.
+pub fn message_signer(msg: &Any) -> anyhow::Result<String> {
+    let tag = SIGNER_FIELD_TAGS
+        .iter()
+        .find(|(type_url, _)| *type_url == msg.type_url)
+        .map(|(_, tag)| *tag)
+        .ok_or(anyhow::anyhow!("Error: Signer of {} unknown.", msg.type_url))?;
+    let signer = match tag {
+        1 => MsgSignerTag1::decode(msg.value.as_slice()).map(|msg| msg.signer),
+        2 => MsgSignerTag2::decode(msg.value.as_slice()).map(|msg| msg.signer),
+        3 => MsgSignerTag3::decode(msg.value.as_slice()).map(|msg| msg.signer),
+        _ => MsgSignerTag6::decode(msg.value.as_slice()).map(|msg| msg.signer),
+    };
+    signer.map_err(|err| anyhow::anyhow!("Error: Can not decode {}: {}", msg.type_url, err))
+}
+
+// Result of the pre-flight checks of a `ProposalDraft`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProposalPreflight {
+    pub version: GovParamsVersion,
+    pub proposer: String,
+    // Gov module account, the signer of the proposal messages.
+    pub authority: String,
+    // Progress of the initial deposit towards `min_deposit`, the proposal enters the deposit period if not reached.
+    pub deposit_progress: DepositProgress,
+    pub gas_used: u64,
+}
+
+/// Builder for a governance proposal.
+///
+/// On gov v1 chains the proposal carries `messages` executed by the gov module,
+/// on gov v1beta1 chains a single legacy `content` (e.g. a `TextProposal`).
+/// A legacy content on a v1 chain is executed with `MsgExecLegacyContent` after the messages.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ProposalDraft {
+    pub title: String,
+    pub summary: String,
+    pub metadata: String,
+    pub messages: Vec<Any>,
+    pub legacy_content: Option<Any>,
+    pub initial_deposit: Vec<CoinAmount>,
+    pub expedited: bool,
+}
+
+impl ProposalDraft {
+    pub fn new(title: &str, summary: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            summary: summary.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// A text proposal, as `TextProposal` content (wrapped in `MsgExecLegacyContent` on v1 chains).
+    pub fn text_proposal(title: &str, description: &str) -> anyhow::Result<Self> {
+        let content = cosmos_sdk_proto::cosmos::gov::v1beta1::TextProposal {
+            title: title.to_string(),
+            description: description.to_string(),
+        }
+        .to_any()?;
+        Ok(Self::new(title, description).legacy_content(content))
+    }
+
+    pub fn message(mut self, msg: Any) -> Self {
+        self.messages.push(msg);
+        self
+    }
+
+    pub fn messages(mut self, msgs: Vec<Any>) -> Self {
+        self.messages.extend(msgs);
+        self
+    }
+
+    pub fn metadata(mut self, metadata: &str) -> Self {
+        self.metadata = metadata.to_string();
+        self
+    }
+
+    pub fn legacy_content(mut self, content: Any) -> Self {
+        self.legacy_content = Some(content);
+        self
+    }
+
+    pub fn initial_deposit(mut self, deposit: Vec<CoinAmount>) -> Self {
+        self.initial_deposit = deposit;
+        self
+    }
+
+    pub fn expedited(mut self, expedited: bool) -> Self {
+        self.expedited = expedited;
+        self
+    }
+
+    /// Checks the draft against the gov params of the chain and returns the progress of the initial deposit.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error listing all problems found:
+    /// - the initial deposit is below `min_initial_deposit_ratio` of `min_deposit` or uses a denom not accepted as deposit,
+    /// - a message is not signed by the gov module account `authority`,
+    /// - the draft uses features the gov version of the chain does not support.
+    pub fn validate(&self, gov_params: &GovParamsExt, authority: &str) -> anyhow::Result<DepositProgress> {
+        let mut issues: Vec<String> = Vec::new();
+
+        if self.title.trim().is_empty() {
+            issues.push("The title is empty.".to_string());
+        }
+        match gov_params.version {
+            GovParamsVersion::V1Beta1 => {
+                if self.legacy_content.is_none() {
+                    issues.push("Gov v1beta1 proposals require a legacy content.".to_string());
+                }
+                if !self.messages.is_empty() {
+                    issues.push("Gov v1beta1 proposals can not contain messages.".to_string());
+                }
+            }
+            GovParamsVersion::V1Legacy | GovParamsVersion::V1 => {
+                for (index, msg) in self.messages.iter().enumerate() {
+                    match message_signer(msg) {
+                        Ok(signer) if signer == authority => {}
+                        Ok(signer) if signer.is_empty() => issues.push(format!(
+                            "Message #{} ({}) has no signer, expected the gov module account {}.",
+                            index, msg.type_url, authority
+                        )),
+                        Ok(signer) => issues.push(format!(
+                            "Message #{} ({}) is signed by {}, expected the gov module account {}.",
+                            index, msg.type_url, signer, authority
+                        )),
+                        Err(err) => issues.push(format!(
+                            "Message #{} ({}) can not be checked for the gov module account {}: {}",
+                            index, msg.type_url, authority, err
+                        )),
+                    }
+                }
+            }
+        }
+        if self.expedited && !gov_params.supports_expedited() {
+            issues.push("The chain does not support expedited proposals.".to_string());
+        }
+
+        let deposit_progress = DepositProgress::new(
+            &self.initial_deposit,
+            DepositProgress::required(gov_params, self.expedited),
+        );
+        for coin in deposit_progress.deposited.iter() {
+            if gov_params.min_deposit_amount(&coin.denom, self.expedited).is_none() {
+                issues.push(format!("The deposit denom {} is not accepted.", coin.denom));
+            }
+        }
+        if let Some(min_initial_deposit_ratio) = gov_params.min_initial_deposit_ratio.filter(|ratio| !ratio.is_zero()) {
+            for required in deposit_progress.min_deposit.iter() {
+                let min_initial = min_initial_deposit(required.amount, min_initial_deposit_ratio)?;
+                let deposited = deposit_progress.deposited_amount(&required.denom);
+                if deposited < min_initial {
+                    issues.push(format!(
+                        "The initial deposit of {} is below the required minimum of {}.",
+                        CoinAmount {
+                            denom: required.denom.to_owned(),
+                            amount: deposited,
+                        },
+                        CoinAmount {
+                            denom: required.denom.to_owned(),
+                            amount: min_initial,
+                        }
+                    ));
+                }
+            }
+        }
+
+        if issues.is_empty() {
+            Ok(deposit_progress)
+        } else {
+            Err(anyhow::anyhow!("Error: Invalid proposal draft:\n- {}", issues.join("\n- ")))
+        }
+    }
+
+    /// Builds the `MsgSubmitProposal` for the gov version of the chain, `authority` is the gov module account.
+    pub fn to_msg(&self, version: GovParamsVersion, proposer: &str, authority: &str) -> anyhow::Result<Any> {
+        let initial_deposit = self.initial_deposit.iter().map(|coin| coin.to_coin()).collect();
+        match version {
+            GovParamsVersion::V1Beta1 => Ok(cosmos_sdk_proto::cosmos::gov::v1beta1::MsgSubmitProposal {
+                content: self.legacy_content.clone(),
+                initial_deposit,
+                proposer: proposer.to_string(),
+            }
+            .to_any()?),
+            GovParamsVersion::V1Legacy | GovParamsVersion::V1 => {
+                let mut messages = self.messages.clone();
+                if let Some(content) = &self.legacy_content {
+                    messages.push(Any {
+                        type_url: MSG_EXEC_LEGACY_CONTENT_TYPE_URL.to_string(),
+                        value: MsgExecLegacyContent {
+                            content: Some(content.to_owned()),
+                            authority: authority.to_string(),
+                        }
+                        .encode_to_vec(),
+                    });
+                }
+                let mut msg = MsgSubmitProposalV1 {
+                    messages,
+                    initial_deposit,
+                    proposer: proposer.to_string(),
+                    metadata: self.metadata.to_owned(),
+                    title: self.title.to_owned(),
+                    summary: self.summary.to_owned(),
+                    expedited: self.expedited,
+                };
+                // v0.46 rejects unknown fields in transactions, the title and summary are part of the metadata there.
+                if version == GovParamsVersion::V1Legacy {
+                    msg.title.clear();
+                    msg.summary.clear();
+                    msg.expedited = false;
+                }
+                Ok(Any {
+                    type_url: MSG_SUBMIT_PROPOSAL_V1_TYPE_URL.to_string(),
+                    value: msg.encode_to_vec(),
+                })
+            }
+        }
+    }
+
+    /// Validates the draft against the gov params of the chain and simulates the submission.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the draft is invalid (see `validate`) or the simulation fails.
+    pub async fn preflight(
+        &self,
+        blockchain: &SupportedBlockchain,
+        signing_key: &SigningKey,
+        options: &TxOptions,
+    ) -> anyhow::Result<ProposalPreflight> {
+        let gov_params = get_gov_params(blockchain.clone()).await?;
+        let authority = cosmos::module_address("gov", &blockchain.prefix)?;
+        let deposit_progress = self.validate(&gov_params, &authority)?;
+
+        let proposer = signer_address(blockchain, signing_key)?;
+        let msg = self.to_msg(gov_params.version, &proposer, &authority)?;
+        let tx_bytes = sign(blockchain, signing_key, vec![msg], options).await?;
+        let simulation = cosmos::simulate(blockchain.channel().await?, tx_bytes).await?;
+
+        Ok(ProposalPreflight {
+            version: gov_params.version,
+            proposer,
+            authority,
+            deposit_progress,
+            gas_used: simulation.gas_info.map(|gas_info| gas_info.gas_used).unwrap_or(0),
+        })
+    }
+
+    /// Runs the pre-flight checks and broadcasts the proposal.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a pre-flight check fails or the transaction is rejected.
+    pub async fn submit(
+        &self,
+        blockchain: &SupportedBlockchain,
+        signing_key: &SigningKey,
+        options: &TxOptions,
+    ) -> anyhow::Result<TxResponse> {
+        let preflight = self.preflight(blockchain, signing_key, options).await?;
+        if preflight.gas_used > options.gas_limit {
+            return Err(anyhow::anyhow!(
+                "Error: Simulation used {} gas, above the gas limit of {}.",
+                preflight.gas_used,
+                options.gas_limit
+            ));
+        }
+        let msg = self.to_msg(preflight.version, &preflight.proposer, &preflight.authority)?;
+        sign_and_broadcast(blockchain, signing_key, vec![msg], options).await
+    }
+}
+
+// `min_deposit * min_initial_deposit_ratio`, truncated like x/gov.
+fn min_initial_deposit(min_deposit: u128, ratio: Decimal) -> anyhow::Result<u128> {
+    let amount = to_decimal(min_deposit)?
+        .checked_mul(ratio)
+        .ok_or(anyhow::anyhow!("Error: Can not multiply {} by {}.", min_deposit, ratio))?;
+    amount
+        .trunc()
+        .to_u128()
+        .ok_or(anyhow::anyhow!("Error: Invalid minimum initial deposit {}.", amount))
+}
+
+#[cfg(test)]
+mod test {
+
+    // cargo test api::custom::tx::gov::proposal_draft::test::test_validate_proposal_draft -- --exact --nocapture
+
+    use super::*;
+    use crate::api::core::cosmos::channels::test_blockchain;
+    use crate::api::custom::types::gov::tally_projection::TallyThresholds;
+
+    #[test]
+    fn test_validate_proposal_draft() {
+        let authority = cosmos::module_address("gov", "cosmos").unwrap();
+        assert_eq!(authority, "cosmos10d07y265gmmuvt4z0w9aw880jnsr700j6zn9kn");
+
+        let mut gov_params = GovParamsExt::from_separate_params(
+            SupportedBlockchain {
+                display: "Cosmos Hub".to_string(),
+                name: "cosmoshub".to_string(),
+                prefix: "cosmos".to_string(),
+                ..test_blockchain()
+            },
+            GovParamsVersion::V1,
+            &None,
+            &[CoinAmount { denom: "uatom".to_string(), amount: 1000 }.to_coin()],
+            &None,
+            TallyThresholds {
+                quorum: Decimal::new(4, 1),
+                threshold: Decimal::new(5, 1),
+                veto_threshold: Decimal::new(334, 3),
+                expedited_threshold: None,
+            },
+        )
+        .unwrap();
+        gov_params.min_initial_deposit_ratio = Some(Decimal::new(25, 2));
+
+        // `MsgCancelUpgrade` only has the `authority` field.
+        let cancel_upgrade = Any {
+            type_url: "/cosmos.upgrade.v1beta1.MsgCancelUpgrade".to_string(),
+            value: MsgSignerTag1 {
+                signer: authority.to_owned(),
+            }
+            .encode_to_vec(),
+        };
+        assert_eq!(message_signer(&cancel_upgrade).unwrap(), authority);
+
+        // `MsgUpdateClient` starts with the `client_id`, the signer is field 3.
+        let mut update_client = MsgSignerTag1 {
+            signer: "07-tendermint-0".to_string(),
+        }
+        .encode_to_vec();
+        update_client.extend(MsgSignerTag3 { signer: authority.to_owned() }.encode_to_vec());
+        let update_client = Any {
+            type_url: "/ibc.core.client.v1.MsgUpdateClient".to_string(),
+            value: update_client,
+        };
+        assert_eq!(message_signer(&update_client).unwrap(), authority);
+        let unknown = Any {
+            type_url: "/unknown.v1.Msg".to_string(),
+            value: vec![],
+        };
+        assert!(message_signer(&unknown).is_err());
+        assert!(ProposalDraft::new("Unknown", "").message(unknown).validate(&gov_params, &authority).is_err());
+
+        let draft = ProposalDraft::new("Cancel upgrade", "Cancels the planned upgrade")
+            .message(cancel_upgrade)
+            .initial_deposit(vec![CoinAmount { denom: "uatom".to_string(), amount: 250 }]);
+        let progress = draft.validate(&gov_params, &authority).unwrap();
+        assert!(!progress.is_reached());
+
+        let too_low = draft.clone().initial_deposit(vec![CoinAmount { denom: "uatom".to_string(), amount: 249 }]);
+        assert!(too_low.validate(&gov_params, &authority).is_err());
+        assert!(draft.validate(&gov_params, "cosmos1other").is_err());
+
+        // On v1 chains the legacy content is executed by `MsgExecLegacyContent`.
+        let text = ProposalDraft::text_proposal("Text", "Description").unwrap();
+        let msg = text.to_msg(GovParamsVersion::V1Legacy, "cosmos1proposer", &authority).unwrap();
+        let msg = MsgSubmitProposalV1::decode(msg.value.as_slice()).unwrap();
+        assert_eq!(msg.messages.len(), 1);
+        assert_eq!(msg.messages[0].type_url, MSG_EXEC_LEGACY_CONTENT_TYPE_URL);
+        assert_eq!(message_signer(&msg.messages[0]).unwrap(), authority);
+        let content = MsgExecLegacyContent::decode(msg.messages[0].value.as_slice()).unwrap().content;
+        assert_eq!(content, text.legacy_content);
+    }
+}
@@ -0,0 +1,99 @@
+pub mod gov;
+
+use std::str::FromStr;
+
+use cosmos_sdk_proto::cosmos::base::abci::v1beta1::TxResponse;
+use cosmos_sdk_proto::Any;
+use cosmrs::crypto::secp256k1::SigningKey;
+use cosmrs::tx::{Fee, SignerInfo};
+
+use crate::api::core::cosmos::channels::SupportedBlockchain;
+use crate::api::core::*;
+use crate::api::custom::types::gov::gov_params_ext::CoinAmount;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TxOptions {
+    pub fee: CoinAmount,
+    pub gas_limit: u64,
+    pub memo: String,
+}
+
+/// The chain id as reported by the latest block header.
+pub async fn get_chain_id(blockchain: &SupportedBlockchain) -> anyhow::Result<String> {
+    let channel = blockchain.channel().await?;
+    let res = cosmos::query::tendermint::get_latest_block(channel).await?;
+    let chain_id = res
+        .block
+        .and_then(|block| block.header)
+        .map(|header| header.chain_id)
+        .ok_or(anyhow::anyhow!("Error: Latest block has no header."))?;
+    Ok(chain_id)
+}
+
+/// The bech32 account address of the signing key on the given chain.
+pub fn signer_address(blockchain: &SupportedBlockchain, signing_key: &SigningKey) -> anyhow::Result<String> {
+    let account_id = signing_key
+        .public_key()
+        .account_id(&blockchain.prefix)
+        .map_err(|err| anyhow::anyhow!("Error: Invalid account prefix: {}", err))?;
+    Ok(account_id.to_string())
+}
+
+/// Signs the messages with `signing_key` (SIGN_MODE_DIRECT) and returns the raw transaction bytes.
+pub async fn sign(
+    blockchain: &SupportedBlockchain,
+    signing_key: &SigningKey,
+    msgs: Vec<Any>,
+    options: &TxOptions,
+) -> anyhow::Result<Vec<u8>> {
+    let channel = blockchain.channel().await?;
+    let base_account = cosmos::query::auth::query_account(
+        channel,
+        signer_address(blockchain, signing_key)?,
+    )
+    .await?;
+    let chain_id = get_chain_id(blockchain).await?;
+
+    let fee = Fee::from_amount_and_gas(
+        cosmrs::Coin {
+            denom: cosmrs::Denom::from_str(&options.fee.denom)
+                .map_err(|err| anyhow::anyhow!("Error: Invalid fee denom: {}", err))?,
+            amount: options.fee.amount,
+        },
+        options.gas_limit,
+    );
+    let tx_body = cosmrs::tx::Body::new(msgs, options.memo.to_owned(), 0u32);
+    let auth_info =
+        SignerInfo::single_direct(Some(signing_key.public_key()), base_account.sequence).auth_info(fee);
+    cosmos::sign_tx(
+        &tx_body,
+        &auth_info,
+        &chain_id,
+        base_account.account_number,
+        signing_key,
+    )
+}
+
+/// Signs and broadcasts the messages, returning the `TxResponse` of the `CheckTx` (sync broadcast mode).
+///
+/// # Errors
+///
+/// Returns an error if the signer account can not be queried, signing fails or the node rejects the transaction.
+pub async fn sign_and_broadcast(
+    blockchain: &SupportedBlockchain,
+    signing_key: &SigningKey,
+    msgs: Vec<Any>,
+    options: &TxOptions,
+) -> anyhow::Result<TxResponse> {
+    let tx_bytes = sign(blockchain, signing_key, msgs, options).await?;
+    let res = cosmos::broadcast_tx(blockchain.channel().await?, tx_bytes).await?;
+    if res.code != 0 {
+        return Err(anyhow::anyhow!(
+            "Error: Transaction {} failed with code {}: {}",
+            res.txhash,
+            res.code,
+            res.raw_log
+        ));
+    }
+    Ok(res)
+}
@@ -0,0 +1,94 @@
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::{Decimal, RoundingStrategy};
+use std::str::FromStr;
+
+// `sdk.Dec` values have a fixed precision of 18 decimal places.
+const SDK_DEC_PRECISION: u32 = 18;
+
+/// Parses an integer amount as returned by the node, e.g. a vote count or the bonded tokens.
+pub fn parse_u128(value: &str) -> anyhow::Result<u128> {
+    value
+        .trim()
+        .parse::<u128>()
+        .map_err(|err| anyhow::anyhow!("Error: Invalid integer amount '{}': {}", value, err))
+}
+
+/// Parses an `sdk.Dec` that is either encoded as a decimal string ("0.334000000000000000")
+/// or as its raw 18 decimal places integer representation ("334000000000000000").
+pub fn parse_sdk_dec(value: &str) -> anyhow::Result<Decimal> {
+    let value = value.trim();
+    if value.contains('.') {
+        return Decimal::from_str(value)
+            .map_err(|err| anyhow::anyhow!("Error: Invalid decimal '{}': {}", value, err));
+    }
+    let mut decimal = Decimal::from_str(value)
+        .map_err(|err| anyhow::anyhow!("Error: Invalid decimal '{}': {}", value, err))?;
+    decimal
+        .set_scale(SDK_DEC_PRECISION)
+        .map_err(|err| anyhow::anyhow!("Error: Invalid decimal '{}': {}", value, err))?;
+    Ok(decimal.normalize())
+}
+
+pub fn to_decimal(value: u128) -> anyhow::Result<Decimal> {
+    Decimal::from_u128(value).ok_or(anyhow::anyhow!(
+        "Error: Amount {} exceeds the decimal range.",
+        value
+    ))
+}
+
+/// Exact `numerator / denominator`, fails for a zero denominator.
+pub fn ratio(numerator: u128, denominator: u128) -> anyhow::Result<Decimal> {
+    if denominator == 0 {
+        return Err(anyhow::anyhow!("Error: Division by zero."));
+    }
+    to_decimal(numerator)?
+        .checked_div(to_decimal(denominator)?)
+        .ok_or(anyhow::anyhow!(
+            "Error: Can not divide {} by {}.",
+            numerator,
+            denominator
+        ))
+}
+
+/// Converts a fraction into a percentage truncated to two decimal places (0.33456 → 33.45).
+pub fn to_percentage(fraction: Decimal) -> Decimal {
+    (fraction * Decimal::new(100, 0)).round_dp_with_strategy(2, RoundingStrategy::ToZero)
+}
+
+/// Formats a decimal as `sdk.Dec` string with 18 decimal places ("0.500000000000000000").
+pub fn to_sdk_dec(decimal: Decimal) -> String {
+    format!("{:.18}", decimal)
+}
+
+/// Formats a decimal as the raw 18 decimal places integer representation of an `sdk.Dec` ("500000000000000000"),
+/// as used on the wire by gogoproto `customtype = "Dec"` fields (e.g. gov v1beta1 vote weights).
+pub fn to_sdk_dec_atomics(decimal: Decimal) -> String {
+    let atomics = to_sdk_dec(decimal).replace('.', "");
+    let trimmed = atomics.trim_start_matches('0');
+    if trimmed.is_empty() {
+        "0".to_string()
+    } else {
+        trimmed.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    // cargo test api::custom::types::decimal_ext::test::test_parse_sdk_dec -- --exact --nocapture
+
+    use super::*;
+
+    #[test]
+    fn test_parse_sdk_dec() {
+        assert_eq!(parse_sdk_dec("334000000000000000").unwrap(), Decimal::new(334, 3));
+        assert_eq!(parse_sdk_dec("0.334000000000000000").unwrap(), Decimal::new(334, 3));
+        assert!(parse_sdk_dec("0.3x").is_err());
+        assert!(parse_u128("-1").is_err());
+        assert_eq!(to_percentage(ratio(1, 3).unwrap()), Decimal::new(3333, 2));
+        assert!(ratio(1, 0).is_err());
+        assert_eq!(to_sdk_dec(Decimal::new(5, 1)), "0.500000000000000000");
+        assert_eq!(to_sdk_dec_atomics(Decimal::new(5, 1)), "500000000000000000");
+        assert_eq!(parse_sdk_dec(&to_sdk_dec_atomics(Decimal::new(334, 3))).unwrap(), Decimal::new(334, 3));
+    }
+}
@@ -6,6 +6,11 @@ use strum_macros::EnumIter;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::api::custom::types::gov::params_change_ext::{flatten_json, normalize_legacy_value, ParamsModule};
+use crate::api::custom::types::upgrade::plan_ext::PlanInfo;
+use crate::api::custom::types::wasm::code_ext::WasmCodeMetadata;
+use crate::api::custom::types::wasm::contract_ext::WasmTarget;
+
 pub trait ContentExt {
     fn from_any(any: &cosmos_sdk_proto::Any) -> Self;
     fn get_title(&self) -> Option<String>;
@@ -126,21 +131,11 @@ impl ContentExt for Option<MsgSoftwareUpgrade>{
     }
     fn get_description(&self) -> Option<String> {
         self.as_ref().map(|p| {
-            let plan_description = match &p.plan {
-                Some(plan) => {
-                    format!(
-                        "Upgrade Plan:\n- Name: {}\n- Height: {}\n- Info: {}",
-                        plan.name, plan.height, plan.info
-                    )
-                },
-                None => "No upgrade plan specified".to_string(),
-            };
-
             format!(
                 "This proposal requests a software upgrade.\n\
              Authority: {}\n\
              {}\n",
-                p.authority, plan_description
+                p.authority, upgrade_plan_to_string(&p.plan)
             )
         })
     }
@@ -182,6 +177,145 @@ impl ContentExt for Option<MsgInstantiateContract>{
     }
 }
 
+type MsgMigrateContract = cosmos_sdk_proto::cosmwasm::wasm::v1::MsgMigrateContract;
+
+impl ContentExt for Option<MsgMigrateContract>{
+    fn from_any(any: &cosmos_sdk_proto::Any) -> Self {
+        cosmos_sdk_proto::traits::Message::decode(&any.value[..]).ok()
+    }
+    fn get_title(&self) -> Option<String> {
+        self.as_ref().map(|p| format!("Migrate Smart Contract to Code ID: {}", p.code_id))
+    }
+    fn get_description(&self) -> Option<String> {
+        self.as_ref().map(|p| {
+            format!(
+                "This proposal migrates a smart contract to new code:\n\
+             Sender: {}\n\
+             Contract: {}\n\
+             Code ID: {}\n\
+             Msg: {}",
+                p.sender,
+                p.contract,
+                p.code_id,
+                String::from_utf8_lossy(&p.msg)
+            )
+        })
+    }
+}
+
+type MsgUpdateAdmin = cosmos_sdk_proto::cosmwasm::wasm::v1::MsgUpdateAdmin;
+
+impl ContentExt for Option<MsgUpdateAdmin>{
+    fn from_any(any: &cosmos_sdk_proto::Any) -> Self {
+        cosmos_sdk_proto::traits::Message::decode(&any.value[..]).ok()
+    }
+    fn get_title(&self) -> Option<String> {
+        self.as_ref().map(|_p| format!("Update Smart Contract Admin"))
+    }
+    fn get_description(&self) -> Option<String> {
+        self.as_ref().map(|p| {
+            format!(
+                "This proposal sets a new admin for a smart contract:\n\
+             Sender: {}\n\
+             Contract: {}\n\
+             New Admin: {}",
+                p.sender, p.contract, p.new_admin
+            )
+        })
+    }
+}
+
+type MsgClearAdmin = cosmos_sdk_proto::cosmwasm::wasm::v1::MsgClearAdmin;
+
+impl ContentExt for Option<MsgClearAdmin>{
+    fn from_any(any: &cosmos_sdk_proto::Any) -> Self {
+        cosmos_sdk_proto::traits::Message::decode(&any.value[..]).ok()
+    }
+    fn get_title(&self) -> Option<String> {
+        self.as_ref().map(|_p| format!("Clear Smart Contract Admin"))
+    }
+    fn get_description(&self) -> Option<String> {
+        self.as_ref().map(|p| {
+            format!(
+                "This proposal removes the admin of a smart contract, the contract can no longer be migrated:\n\
+             Sender: {}\n\
+             Contract: {}",
+                p.sender, p.contract
+            )
+        })
+    }
+}
+
+type MsgSudoContract = osmosis_std::types::cosmwasm::wasm::v1::MsgSudoContract;
+
+impl ContentExt for Option<MsgSudoContract>{
+    fn from_any(any: &cosmos_sdk_proto::Any) -> Self {
+        osmosis_prost::Message::decode(&any.value[..]).ok()
+    }
+    fn get_title(&self) -> Option<String> {
+        self.as_ref().map(|_p| format!("Sudo Smart Contract Call"))
+    }
+    fn get_description(&self) -> Option<String> {
+        self.as_ref().map(|p| {
+            format!(
+                "This proposal calls the privileged sudo entry point of a smart contract:\n\
+             Authority: {}\n\
+             Contract: {}\n\
+             Msg: {}",
+                p.authority,
+                p.contract,
+                String::from_utf8_lossy(&p.msg)
+            )
+        })
+    }
+}
+
+fn wasm_code_to_string(wasm_byte_code: &[u8]) -> String {
+    match WasmCodeMetadata::from_wasm_byte_code_cached(wasm_byte_code) {
+        Ok(metadata) => metadata.to_string(),
+        Err(err) => format!("Wasm code could not be inspected: {}", err),
+    }
+}
+
+type StoreCodeProposal = cosmos_sdk_proto::cosmwasm::wasm::v1::StoreCodeProposal;
+
+impl ContentExt for Option<StoreCodeProposal>{
+    fn from_any(any: &cosmos_sdk_proto::Any) -> Self {
+        any.to_msg().ok()
+    }
+    fn get_title(&self) -> Option<String> {
+        self.as_ref().map(|p|  p.title.clone())
+    }
+    fn get_description(&self) -> Option<String> {
+        self.as_ref().map(|p| {
+            format!("{}\n\nRun as: {}\n\n{}",
+                p.description, p.run_as, wasm_code_to_string(&p.wasm_byte_code)
+            )
+        })
+    }
+}
+
+type MsgStoreCode = cosmos_sdk_proto::cosmwasm::wasm::v1::MsgStoreCode;
+
+impl ContentExt for Option<MsgStoreCode>{
+    fn from_any(any: &cosmos_sdk_proto::Any) -> Self {
+        cosmos_sdk_proto::traits::Message::decode(&any.value[..]).ok()
+    }
+    fn get_title(&self) -> Option<String> {
+        self.as_ref().map(|_p| format!("Store Smart Contract Code"))
+    }
+    fn get_description(&self) -> Option<String> {
+        self.as_ref().map(|p| {
+            format!(
+                "This proposal uploads new smart contract code.\n\
+             Sender: {}\n\n\
+             {}",
+                p.sender, wasm_code_to_string(&p.wasm_byte_code)
+            )
+        })
+    }
+}
+
 type SetScalingFactorControllerProposal = osmosis_std::types::osmosis::gamm::v1beta1::SetScalingFactorControllerProposal;
 
 impl ContentExt for Option<SetScalingFactorControllerProposal>{
@@ -221,30 +355,274 @@ impl ContentExt for Option<PinCodesProposal>{
     }
 }
 
+fn upgrade_plan_to_string(plan: &Option<cosmos_sdk_proto::cosmos::upgrade::v1beta1::Plan>) -> String {
+    match plan {
+        Some(plan) => {
+            format!(
+                "Upgrade Plan:\n- Name: {}\n- Height: {}\n- Info: {}",
+                plan.name, plan.height, plan.info
+            )
+        },
+        None => "No upgrade plan specified".to_string(),
+    }
+}
+
+type SoftwareUpgradeProposal = cosmos_sdk_proto::cosmos::upgrade::v1beta1::SoftwareUpgradeProposal;
+
+impl ContentExt for Option<SoftwareUpgradeProposal>{
+    fn from_any(any: &cosmos_sdk_proto::Any) -> Self {
+        any.to_msg().ok()
+    }
+    fn get_title(&self) -> Option<String> {
+        self.as_ref().map(|p|  p.title.clone())
+    }
+    fn get_description(&self) -> Option<String> {
+        self.as_ref().map(|p| {
+            format!("{}\n\n{}",
+                p.description, upgrade_plan_to_string(&p.plan)
+            )
+        })
+    }
+}
+
+type MsgCancelUpgrade = cosmos_sdk_proto::cosmos::upgrade::v1beta1::MsgCancelUpgrade;
+
+impl ContentExt for Option<MsgCancelUpgrade>{
+    fn from_any(any: &cosmos_sdk_proto::Any) -> Self {
+        cosmos_sdk_proto::traits::Message::decode(&any.value[..]).ok()
+    }
+    fn get_title(&self) -> Option<String> {
+        self.as_ref().map(|_p| format!("Cancel Software Upgrade Proposal"))
+    }
+    fn get_description(&self) -> Option<String> {
+        self.as_ref().map(|p| {
+            format!(
+                "This proposal cancels the currently scheduled software upgrade.\n\
+             Authority: {}\n",
+                p.authority
+            )
+        })
+    }
+}
+
+type MsgIbcSoftwareUpgrade = crate::api::custom::types::ibc::proto::MsgIbcSoftwareUpgrade;
+
+impl ContentExt for Option<MsgIbcSoftwareUpgrade>{
+    fn from_any(any: &cosmos_sdk_proto::Any) -> Self {
+        cosmos_sdk_proto::traits::Message::decode(&any.value[..]).ok()
+    }
+    fn get_title(&self) -> Option<String> {
+        self.as_ref().map(|_p| format!("IBC Software Upgrade Proposal"))
+    }
+    fn get_description(&self) -> Option<String> {
+        self.as_ref().map(|p| {
+            format!(
+                "This proposal requests a software upgrade that also upgrades the IBC client state.\n\
+             Signer: {}\n\
+             Upgraded client state: {}\n\
+             {}\n",
+                p.signer,
+                p.upgraded_client_state.as_ref().map(|any| any.type_url.as_str()).unwrap_or("none"),
+                upgrade_plan_to_string(&p.plan)
+            )
+        })
+    }
+}
+
+type ClientUpdateProposal = cosmos_sdk_proto::ibc::core::client::v1::ClientUpdateProposal;
+
+impl ContentExt for Option<ClientUpdateProposal>{
+    fn from_any(any: &cosmos_sdk_proto::Any) -> Self {
+        any.to_msg().ok()
+    }
+    fn get_title(&self) -> Option<String> {
+        self.as_ref().map(|p|  p.title.clone())
+    }
+    fn get_description(&self) -> Option<String> {
+        self.as_ref().map(|p| {
+            format!("{}\n\n\
+             Subject client: {}\n\
+             Substitute client: {}",
+                p.description, p.subject_client_id, p.substitute_client_id
+            )
+        })
+    }
+}
+
+type MsgRecoverClient = crate::api::custom::types::ibc::proto::MsgRecoverClient;
+
+impl ContentExt for Option<MsgRecoverClient>{
+    fn from_any(any: &cosmos_sdk_proto::Any) -> Self {
+        cosmos_sdk_proto::traits::Message::decode(&any.value[..]).ok()
+    }
+    fn get_title(&self) -> Option<String> {
+        self.as_ref().map(|p| format!("Recover IBC Client {}", p.subject_client_id))
+    }
+    fn get_description(&self) -> Option<String> {
+        self.as_ref().map(|p| {
+            format!(
+                "This proposal replaces the state of an expired or frozen IBC client with the state of a substitute client.\n\
+             Subject client: {}\n\
+             Substitute client: {}\n\
+             Signer: {}\n",
+                p.subject_client_id, p.substitute_client_id, p.signer
+            )
+        })
+    }
+}
+
+type MsgUpdateClient = cosmos_sdk_proto::ibc::core::client::v1::MsgUpdateClient;
+
+impl ContentExt for Option<MsgUpdateClient>{
+    fn from_any(any: &cosmos_sdk_proto::Any) -> Self {
+        cosmos_sdk_proto::traits::Message::decode(&any.value[..]).ok()
+    }
+    fn get_title(&self) -> Option<String> {
+        self.as_ref().map(|p| format!("Update IBC Client {}", p.client_id))
+    }
+    fn get_description(&self) -> Option<String> {
+        self.as_ref().map(|p| {
+            format!(
+                "This proposal submits a header to update the IBC client {}.\n\
+             Signer: {}\n",
+                p.client_id, p.signer
+            )
+        })
+    }
+}
+
+type ParameterChangeProposal = cosmos_sdk_proto::cosmos::params::v1beta1::ParameterChangeProposal;
+
+impl ContentExt for Option<ParameterChangeProposal>{
+    fn from_any(any: &cosmos_sdk_proto::Any) -> Self {
+        any.to_msg().ok()
+    }
+    fn get_title(&self) -> Option<String> {
+        self.as_ref().map(|p|  p.title.clone())
+    }
+    fn get_description(&self) -> Option<String> {
+        self.as_ref().map(|p| {
+            let changes_description = p.changes.iter().map(|change| {
+                format!("{}/{}: {}", change.subspace, change.key, normalize_legacy_value(&change.value))
+            }).collect::<Vec<String>>().join("\n- ");
+            format!("{}\n\nChanges:\n- {}",
+                p.description, changes_description
+            )
+        })
+    }
+}
+
+// Extracts the params set of a `MsgUpdateParams` as JSON, leaving out the `authority`.
+// Most modules wrap the params in a `params` field, x/consensus lists them at the top level.
+fn update_params_to_json<T: Serialize>(msg: &T) -> Option<serde_json::Value> {
+    let mut value = serde_json::to_value(msg).ok()?;
+    if let Some(params) = value.get("params") {
+        return Some(params.clone());
+    }
+    if let Some(map) = value.as_object_mut() {
+        map.remove("authority");
+    }
+    Some(value)
+}
+
+fn update_params_title(module: ParamsModule) -> String {
+    format!("Update {} Params", module)
+}
+
+fn update_params_description(module: ParamsModule, authority: &str, params: Option<serde_json::Value>) -> String {
+    let mut fields = Vec::new();
+    if let Some(params) = &params {
+        flatten_json(params, "", &mut fields);
+    }
+    format!(
+        "This proposal updates the parameters of the {} module.\n\
+         Authority: {}\n\
+         New params:\n- {}",
+        module,
+        authority,
+        fields
+            .iter()
+            .map(|(field, value)| format!("{}: {}", field, value))
+            .collect::<Vec<String>>()
+            .join("\n- ")
+    )
+}
+
+type MsgUpdateGovParams = injective_std::types::cosmos::gov::v1::MsgUpdateParams;
+type MsgUpdateStakingParams = injective_std::types::cosmos::staking::v1beta1::MsgUpdateParams;
+type MsgUpdateMintParams = injective_std::types::cosmos::mint::v1beta1::MsgUpdateParams;
+type MsgUpdateDistributionParams = injective_std::types::cosmos::distribution::v1beta1::MsgUpdateParams;
+type MsgUpdateBankParams = injective_std::types::cosmos::bank::v1beta1::MsgUpdateParams;
+type MsgUpdateSlashingParams = injective_std::types::cosmos::slashing::v1beta1::MsgUpdateParams;
+type MsgUpdateConsensusParams = injective_std::types::cosmos::consensus::v1::MsgUpdateParams;
+type MsgUpdateWasmParams = osmosis_std::types::cosmwasm::wasm::v1::MsgUpdateParams;
+
+// The `MsgUpdateParams` of the modules only differ in their params, which are shown as json.
+macro_rules! impl_update_params_content {
+    ($($msg:ty => $module:expr),* $(,)?) => {
+        $(
+            impl ContentExt for Option<$msg> {
+                fn from_any(any: &cosmos_sdk_proto::Any) -> Self {
+                    osmosis_prost::Message::decode(&any.value[..]).ok()
+                }
+                fn get_title(&self) -> Option<String> {
+                    self.as_ref().map(|_p| update_params_title($module))
+                }
+                fn get_description(&self) -> Option<String> {
+                    self.as_ref().map(|p| update_params_description($module, &p.authority, update_params_to_json(p)))
+                }
+            }
+        )*
+    };
+}
+
+impl_update_params_content!(
+    MsgUpdateGovParams => ParamsModule::Gov,
+    MsgUpdateStakingParams => ParamsModule::Staking,
+    MsgUpdateMintParams => ParamsModule::Mint,
+    MsgUpdateDistributionParams => ParamsModule::Distribution,
+    MsgUpdateBankParams => ParamsModule::Bank,
+    MsgUpdateSlashingParams => ParamsModule::Slashing,
+    MsgUpdateConsensusParams => ParamsModule::Consensus,
+    MsgUpdateWasmParams => ParamsModule::Wasm,
+);
+
 #[derive(strum_macros::Display, Debug, Clone, PartialEq)]
 pub enum ProposalContent {
     MsgExec(Option<MsgExec>),
-    //MsgUpdateParams(Option<injective_std::types::cosmos::mint::v1beta1::MsgUpdateParams>),
+    MsgUpdateGovParams(Option<MsgUpdateGovParams>),
+    MsgUpdateStakingParams(Option<MsgUpdateStakingParams>),
+    MsgUpdateMintParams(Option<MsgUpdateMintParams>),
+    MsgUpdateDistributionParams(Option<MsgUpdateDistributionParams>),
+    MsgUpdateBankParams(Option<MsgUpdateBankParams>),
+    MsgUpdateSlashingParams(Option<MsgUpdateSlashingParams>),
+    MsgUpdateConsensusParams(Option<MsgUpdateConsensusParams>),
+    MsgUpdateWasmParams(Option<MsgUpdateWasmParams>),
     MsgCommunityPoolSpend(Option<MsgCommunityPoolSpend>),
     MsgExecuteContract(Option<MsgExecuteContract>),
     MsgUpdateInstantiateConfig(Option<MsgUpdateInstantiateConfig>),
     MsgSoftwareUpgrade(Option<MsgSoftwareUpgrade>),
+    MsgCancelUpgrade(Option<MsgCancelUpgrade>),
+    MsgIbcSoftwareUpgrade(Option<MsgIbcSoftwareUpgrade>),
+    MsgRecoverClient(Option<MsgRecoverClient>),
+    MsgUpdateClient(Option<MsgUpdateClient>),
     MsgInstantiateContract(Option<MsgInstantiateContract>),
+    MsgMigrateContract(Option<MsgMigrateContract>),
+    MsgUpdateAdmin(Option<MsgUpdateAdmin>),
+    MsgClearAdmin(Option<MsgClearAdmin>),
+    MsgSudoContract(Option<MsgSudoContract>),
+    MsgStoreCode(Option<MsgStoreCode>),
     TextProposal(Option<cosmos_sdk_proto::cosmos::gov::v1beta1::TextProposal>),
     CommunityPoolSpendProposal(
         Option<cosmos_sdk_proto::cosmos::distribution::v1beta1::CommunityPoolSpendProposal>,
     ),
-    ParameterChangeProposal(
-        Option<cosmos_sdk_proto::cosmos::params::v1beta1::ParameterChangeProposal>,
-    ),
-    SoftwareUpgradeProposal(
-        Option<cosmos_sdk_proto::cosmos::upgrade::v1beta1::SoftwareUpgradeProposal>,
-    ),
-    ClientUpdateProposal(Option<cosmos_sdk_proto::ibc::core::client::v1::ClientUpdateProposal>),
+    ParameterChangeProposal(Option<ParameterChangeProposal>),
+    SoftwareUpgradeProposal(Option<SoftwareUpgradeProposal>),
+    ClientUpdateProposal(Option<ClientUpdateProposal>),
     UpdatePoolIncentivesProposal(
         Option<osmosis_std::types::osmosis::poolincentives::v1beta1::UpdatePoolIncentivesProposal>,
     ),
-    StoreCodeProposal(Option<cosmos_sdk_proto::cosmwasm::wasm::v1::StoreCodeProposal>),
+    StoreCodeProposal(Option<StoreCodeProposal>),
     RemoveSuperfluidAssetsProposal(
         Option<osmosis_std::types::osmosis::superfluid::v1beta1::RemoveSuperfluidAssetsProposal>,
     ),
@@ -284,10 +662,30 @@ impl ProposalContent{
             "/cosmos.authz.v1beta1.MsgExec" => ProposalContent::MsgExec(
                 ContentExt::from_any(any),
             ),
-            /*
-            "/cosmos.mint.v1beta1.MsgUpdateParams" => ProposalContent::MsgUpdateParams(
-                osmosis_prost::Message::decode(&any.value[..]).ok(),
-            ),*/
+            "/cosmos.gov.v1.MsgUpdateParams" => ProposalContent::MsgUpdateGovParams(
+                ContentExt::from_any(any),
+            ),
+            "/cosmos.staking.v1beta1.MsgUpdateParams" => ProposalContent::MsgUpdateStakingParams(
+                ContentExt::from_any(any),
+            ),
+            "/cosmos.mint.v1beta1.MsgUpdateParams" => ProposalContent::MsgUpdateMintParams(
+                ContentExt::from_any(any),
+            ),
+            "/cosmos.distribution.v1beta1.MsgUpdateParams" => ProposalContent::MsgUpdateDistributionParams(
+                ContentExt::from_any(any),
+            ),
+            "/cosmos.bank.v1beta1.MsgUpdateParams" => ProposalContent::MsgUpdateBankParams(
+                ContentExt::from_any(any),
+            ),
+            "/cosmos.slashing.v1beta1.MsgUpdateParams" => ProposalContent::MsgUpdateSlashingParams(
+                ContentExt::from_any(any),
+            ),
+            "/cosmos.consensus.v1.MsgUpdateParams" => ProposalContent::MsgUpdateConsensusParams(
+                ContentExt::from_any(any),
+            ),
+            "/cosmwasm.wasm.v1.MsgUpdateParams" => ProposalContent::MsgUpdateWasmParams(
+                ContentExt::from_any(any),
+            ),
             "/cosmos.distribution.v1beta1.MsgCommunityPoolSpend"=> ProposalContent::MsgCommunityPoolSpend(
                 ContentExt::from_any(any),
             ),
@@ -300,9 +698,36 @@ impl ProposalContent{
             "/cosmos.upgrade.v1beta1.MsgSoftwareUpgrade" => ProposalContent::MsgSoftwareUpgrade(
                 ContentExt::from_any(any),
             ),
+            "/cosmos.upgrade.v1beta1.MsgCancelUpgrade" => ProposalContent::MsgCancelUpgrade(
+                ContentExt::from_any(any),
+            ),
+            "/ibc.core.client.v1.MsgIBCSoftwareUpgrade" => ProposalContent::MsgIbcSoftwareUpgrade(
+                ContentExt::from_any(any),
+            ),
+            "/ibc.core.client.v1.MsgRecoverClient" => ProposalContent::MsgRecoverClient(
+                ContentExt::from_any(any),
+            ),
+            "/ibc.core.client.v1.MsgUpdateClient" => ProposalContent::MsgUpdateClient(
+                ContentExt::from_any(any),
+            ),
             "/cosmwasm.wasm.v1.MsgInstantiateContract" => ProposalContent::MsgInstantiateContract(
                 ContentExt::from_any(any),
             ),
+            "/cosmwasm.wasm.v1.MsgMigrateContract" => ProposalContent::MsgMigrateContract(
+                ContentExt::from_any(any),
+            ),
+            "/cosmwasm.wasm.v1.MsgUpdateAdmin" => ProposalContent::MsgUpdateAdmin(
+                ContentExt::from_any(any),
+            ),
+            "/cosmwasm.wasm.v1.MsgClearAdmin" => ProposalContent::MsgClearAdmin(
+                ContentExt::from_any(any),
+            ),
+            "/cosmwasm.wasm.v1.MsgSudoContract" => ProposalContent::MsgSudoContract(
+                ContentExt::from_any(any),
+            ),
+            "/cosmwasm.wasm.v1.MsgStoreCode" => ProposalContent::MsgStoreCode(
+                ContentExt::from_any(any),
+            ),
             "/cosmos.gov.v1beta1.TextProposal" => ProposalContent::TextProposal(
                 any.to_msg().ok(),
             ),
@@ -313,22 +738,22 @@ impl ProposalContent{
             }
             "/cosmos.params.v1beta1.ParameterChangeProposal" => {
                 ProposalContent::ParameterChangeProposal(
-                    any.to_msg().ok(),
+                    ContentExt::from_any(any),
                 )
             }
             "/cosmos.upgrade.v1beta1.SoftwareUpgradeProposal" => {
                 ProposalContent::SoftwareUpgradeProposal(
-                    any.to_msg().ok(),
+                    ContentExt::from_any(any),
                 )
             }
             "/ibc.core.client.v1.ClientUpdateProposal" => ProposalContent::ClientUpdateProposal(
-                any.to_msg().ok(),
+                ContentExt::from_any(any),
             ),
             "/osmosis.poolincentives.v1beta1.UpdatePoolIncentivesProposal" => {
                 ProposalContent::UpdatePoolIncentivesProposal(osmosis_prost::Message::decode(&any.value[..]).ok())
             }
             "/cosmwasm.wasm.v1.StoreCodeProposal" => ProposalContent::StoreCodeProposal(
-                any.to_msg().ok(),
+                ContentExt::from_any(any),
             ),
             "/cosmwasm.wasm.v1.InstantiateContractProposal" => {
                 ProposalContent::InstantiateContractProposal(
@@ -394,21 +819,9 @@ impl ProposalContent{
             ProposalContent::CommunityPoolSpendProposal(p) => {
                 p.clone().map(|x| x.description.to_owned())
             }
-            ProposalContent::ParameterChangeProposal(p) => {
-                p.clone().map(|x| x.description.to_owned())
-            }
-            ProposalContent::SoftwareUpgradeProposal(p) => {
-                p.clone().map(|x| x.description.to_owned())
-            }
-            ProposalContent::ClientUpdateProposal(p) => {
-                p.clone().map(|x| x.description.to_owned())
-            }
             ProposalContent::UpdatePoolIncentivesProposal(p) => {
                 p.clone().map(|x| x.description.to_owned())
             }
-            ProposalContent::StoreCodeProposal(p) => {
-                p.clone().map(|x| x.description.to_owned())
-            }
             ProposalContent::RemoveSuperfluidAssetsProposal(p) => {
                 p.clone().map(|x| x.description.to_owned())
             }
@@ -448,6 +861,30 @@ impl ProposalContent{
             ProposalContent::MsgExec(p) => {
                 p.get_description()
             }
+            ProposalContent::MsgUpdateGovParams(p) => {
+                p.get_description()
+            }
+            ProposalContent::MsgUpdateStakingParams(p) => {
+                p.get_description()
+            }
+            ProposalContent::MsgUpdateMintParams(p) => {
+                p.get_description()
+            }
+            ProposalContent::MsgUpdateDistributionParams(p) => {
+                p.get_description()
+            }
+            ProposalContent::MsgUpdateBankParams(p) => {
+                p.get_description()
+            }
+            ProposalContent::MsgUpdateSlashingParams(p) => {
+                p.get_description()
+            }
+            ProposalContent::MsgUpdateConsensusParams(p) => {
+                p.get_description()
+            }
+            ProposalContent::MsgUpdateWasmParams(p) => {
+                p.get_description()
+            }
             ProposalContent::MsgCommunityPoolSpend(p) => {
                 p.get_description()
             }
@@ -463,12 +900,51 @@ impl ProposalContent{
             ProposalContent::MsgInstantiateContract(p) => {
                 p.get_description()
             }
+            ProposalContent::MsgMigrateContract(p) => {
+                p.get_description()
+            }
+            ProposalContent::MsgUpdateAdmin(p) => {
+                p.get_description()
+            }
+            ProposalContent::MsgClearAdmin(p) => {
+                p.get_description()
+            }
+            ProposalContent::MsgSudoContract(p) => {
+                p.get_description()
+            }
+            ProposalContent::MsgStoreCode(p) => {
+                p.get_description()
+            }
+            ProposalContent::StoreCodeProposal(p) => {
+                p.get_description()
+            }
             ProposalContent::SetScalingFactorControllerProposal(p) => {
                 p.get_description()
             }
             ProposalContent::PinCodesProposal(p) => {
                 p.get_description()
             }
+            ProposalContent::ParameterChangeProposal(p) => {
+                p.get_description()
+            }
+            ProposalContent::SoftwareUpgradeProposal(p) => {
+                p.get_description()
+            }
+            ProposalContent::ClientUpdateProposal(p) => {
+                p.get_description()
+            }
+            ProposalContent::MsgCancelUpgrade(p) => {
+                p.get_description()
+            }
+            ProposalContent::MsgIbcSoftwareUpgrade(p) => {
+                p.get_description()
+            }
+            ProposalContent::MsgRecoverClient(p) => {
+                p.get_description()
+            }
+            ProposalContent::MsgUpdateClient(p) => {
+                p.get_description()
+            }
             ProposalContent::UnknownProposalType(type_url) =>
                 Some(format!("Error: UnknownProposalTypeError: ProposalContent can not be decoded for unknown ProposalType.\n\nType URL:\n{}", type_url))
             ,
@@ -476,15 +952,85 @@ impl ProposalContent{
                 format!("Error: DecodeError: ProposalContent could not be decoded for ProposalType.")
             ).replace("\\n", "\n")
     }
+    // Returns the module and the proposed params set if this content is a `MsgUpdateParams`.
+    pub fn params_update(&self) -> Option<(ParamsModule, serde_json::Value)> {
+        match &self {
+            ProposalContent::MsgUpdateGovParams(p) => p.as_ref().and_then(update_params_to_json).map(|v| (ParamsModule::Gov, v)),
+            ProposalContent::MsgUpdateStakingParams(p) => p.as_ref().and_then(update_params_to_json).map(|v| (ParamsModule::Staking, v)),
+            ProposalContent::MsgUpdateMintParams(p) => p.as_ref().and_then(update_params_to_json).map(|v| (ParamsModule::Mint, v)),
+            ProposalContent::MsgUpdateDistributionParams(p) => p.as_ref().and_then(update_params_to_json).map(|v| (ParamsModule::Distribution, v)),
+            ProposalContent::MsgUpdateBankParams(p) => p.as_ref().and_then(update_params_to_json).map(|v| (ParamsModule::Bank, v)),
+            ProposalContent::MsgUpdateSlashingParams(p) => p.as_ref().and_then(update_params_to_json).map(|v| (ParamsModule::Slashing, v)),
+            ProposalContent::MsgUpdateConsensusParams(p) => p.as_ref().and_then(update_params_to_json).map(|v| (ParamsModule::Consensus, v)),
+            ProposalContent::MsgUpdateWasmParams(p) => p.as_ref().and_then(update_params_to_json).map(|v| (ParamsModule::Wasm, v)),
+            _ => None,
+        }
+    }
+
+    // Returns the `(role, client_id)` of every IBC client this content refers to.
+    pub fn ibc_client_ids(&self) -> Vec<(String, String)> {
+        match &self {
+            ProposalContent::ClientUpdateProposal(Some(p)) => vec![
+                ("Subject".to_string(), p.subject_client_id.to_owned()),
+                ("Substitute".to_string(), p.substitute_client_id.to_owned()),
+            ],
+            ProposalContent::MsgRecoverClient(Some(p)) => vec![
+                ("Subject".to_string(), p.subject_client_id.to_owned()),
+                ("Substitute".to_string(), p.substitute_client_id.to_owned()),
+            ],
+            ProposalContent::MsgUpdateClient(Some(p)) => vec![
+                ("Updated".to_string(), p.client_id.to_owned()),
+            ],
+            _ => vec![],
+        }
+    }
+
+    // Decompresses and inspects the wasm byte code of store code content, `None` for other content.
+    pub fn wasm_code_metadata(&self) -> Option<anyhow::Result<WasmCodeMetadata>> {
+        match &self {
+            ProposalContent::StoreCodeProposal(Some(p)) => Some(WasmCodeMetadata::from_wasm_byte_code_cached(&p.wasm_byte_code)),
+            ProposalContent::MsgStoreCode(Some(p)) => Some(WasmCodeMetadata::from_wasm_byte_code_cached(&p.wasm_byte_code)),
+            _ => None,
+        }
+    }
+
+    // Returns the contract/code a wasm message refers to, `None` for non-wasm content.
+    pub fn wasm_target(&self) -> Option<WasmTarget> {
+        let target = match &self {
+            ProposalContent::MsgExecuteContract(Some(p)) => WasmTarget { contract: Some(p.contract.to_owned()), ..Default::default() },
+            ProposalContent::MsgInstantiateContract(Some(p)) => WasmTarget { code_id: Some(p.code_id), ..Default::default() },
+            ProposalContent::MsgUpdateInstantiateConfig(Some(p)) => WasmTarget { code_id: Some(p.code_id), ..Default::default() },
+            ProposalContent::MsgMigrateContract(Some(p)) => WasmTarget { contract: Some(p.contract.to_owned()), code_id: Some(p.code_id), ..Default::default() },
+            ProposalContent::MsgUpdateAdmin(Some(p)) => WasmTarget { contract: Some(p.contract.to_owned()), new_admin: Some(p.new_admin.to_owned()), ..Default::default() },
+            ProposalContent::MsgClearAdmin(Some(p)) => WasmTarget { contract: Some(p.contract.to_owned()), new_admin: Some("".to_string()), ..Default::default() },
+            ProposalContent::MsgSudoContract(Some(p)) => WasmTarget { contract: Some(p.contract.to_owned()), ..Default::default() },
+            ProposalContent::InstantiateContractProposal(Some(p)) => WasmTarget { code_id: Some(p.code_id), ..Default::default() },
+            ProposalContent::MigrateContractProposal(Some(p)) => WasmTarget { contract: Some(p.contract.to_owned()), code_id: Some(p.code_id), ..Default::default() },
+            ProposalContent::SudoContractProposal(Some(p)) => WasmTarget { contract: Some(p.contract.to_owned()), ..Default::default() },
+            ProposalContent::ExecuteContractProposal(Some(p)) => WasmTarget { contract: Some(p.contract.to_owned()), ..Default::default() },
+            ProposalContent::UpdateAdminProposal(Some(p)) => WasmTarget { contract: Some(p.contract.to_owned()), new_admin: Some(p.new_admin.to_owned()), ..Default::default() },
+            ProposalContent::ClearAdminProposal(Some(p)) => WasmTarget { contract: Some(p.contract.to_owned()), new_admin: Some("".to_string()), ..Default::default() },
+            _ => return None,
+        };
+        Some(target)
+    }
+
+    // Returns `Some(plan)` for upgrade related content, the plan is `None` for a `MsgCancelUpgrade`.
+    pub fn upgrade_plan(&self) -> Option<Option<PlanInfo>> {
+        match &self {
+            ProposalContent::SoftwareUpgradeProposal(Some(p)) => Some(p.plan.as_ref().map(PlanInfo::from_plan)),
+            ProposalContent::MsgSoftwareUpgrade(Some(p)) => Some(p.plan.as_ref().map(PlanInfo::from_plan)),
+            ProposalContent::MsgIbcSoftwareUpgrade(Some(p)) => Some(p.plan.as_ref().map(PlanInfo::from_plan)),
+            ProposalContent::MsgCancelUpgrade(Some(_)) => Some(None),
+            _ => None,
+        }
+    }
+
     pub fn get_title(&self) -> String {
         match &self {
             ProposalContent::TextProposal(p) => p.clone().map(|x| x.title.to_owned()),
             ProposalContent::CommunityPoolSpendProposal(p) => p.clone().map(|x| x.title.to_owned()),
-            ProposalContent::ParameterChangeProposal(p) => p.clone().map(|x| x.title.to_owned()),
-            ProposalContent::SoftwareUpgradeProposal(p) => p.clone().map(|x| x.title.to_owned()),
-            ProposalContent::ClientUpdateProposal(p) => p.clone().map(|x| x.title.to_owned()),
             ProposalContent::UpdatePoolIncentivesProposal(p) => p.clone().map(|x| x.title.to_owned()),
-            ProposalContent::StoreCodeProposal(p) => p.clone().map(|x| x.title.to_owned()),
             ProposalContent::RemoveSuperfluidAssetsProposal(p) => p.clone().map(|x| x.title.to_owned()),
             ProposalContent::InstantiateContractProposal(p) => p.clone().map(|x| x.title.to_owned()),
             ProposalContent::ReplacePoolIncentivesProposal(p) => p.clone().map(|x| x.title.to_owned()),
@@ -500,6 +1046,30 @@ impl ProposalContent{
             ProposalContent::MsgExec(p) => {
                 p.get_title()
             }
+            ProposalContent::MsgUpdateGovParams(p) => {
+                p.get_title()
+            }
+            ProposalContent::MsgUpdateStakingParams(p) => {
+                p.get_title()
+            }
+            ProposalContent::MsgUpdateMintParams(p) => {
+                p.get_title()
+            }
+            ProposalContent::MsgUpdateDistributionParams(p) => {
+                p.get_title()
+            }
+            ProposalContent::MsgUpdateBankParams(p) => {
+                p.get_title()
+            }
+            ProposalContent::MsgUpdateSlashingParams(p) => {
+                p.get_title()
+            }
+            ProposalContent::MsgUpdateConsensusParams(p) => {
+                p.get_title()
+            }
+            ProposalContent::MsgUpdateWasmParams(p) => {
+                p.get_title()
+            }
             ProposalContent::MsgCommunityPoolSpend(p) => {
                 p.get_title()
             }
@@ -515,12 +1085,51 @@ impl ProposalContent{
             ProposalContent::MsgInstantiateContract(p) => {
                 p.get_title()
             }
+            ProposalContent::MsgMigrateContract(p) => {
+                p.get_title()
+            }
+            ProposalContent::MsgUpdateAdmin(p) => {
+                p.get_title()
+            }
+            ProposalContent::MsgClearAdmin(p) => {
+                p.get_title()
+            }
+            ProposalContent::MsgSudoContract(p) => {
+                p.get_title()
+            }
+            ProposalContent::MsgStoreCode(p) => {
+                p.get_title()
+            }
+            ProposalContent::StoreCodeProposal(p) => {
+                p.get_title()
+            }
             ProposalContent::SetScalingFactorControllerProposal(p) => {
                 p.get_title()
             }
             ProposalContent::PinCodesProposal(p) => {
                 p.get_title()
             }
+            ProposalContent::ParameterChangeProposal(p) => {
+                p.get_title()
+            }
+            ProposalContent::SoftwareUpgradeProposal(p) => {
+                p.get_title()
+            }
+            ProposalContent::ClientUpdateProposal(p) => {
+                p.get_title()
+            }
+            ProposalContent::MsgCancelUpgrade(p) => {
+                p.get_title()
+            }
+            ProposalContent::MsgIbcSoftwareUpgrade(p) => {
+                p.get_title()
+            }
+            ProposalContent::MsgRecoverClient(p) => {
+                p.get_title()
+            }
+            ProposalContent::MsgUpdateClient(p) => {
+                p.get_title()
+            }
             ProposalContent::UnknownProposalType(_type_url) => {
                 Some("UnknownProposalTypeError".to_string())
             }
@@ -605,6 +1214,34 @@ impl ProposalStatus {
     }
 }
 
+#[derive(
+Deserialize, Serialize, strum_macros::Display, Debug, Clone, Copy, Eq, PartialEq, EnumIter, Hash,
+)]
+pub enum VoteOption {
+    Yes = 0x01,
+    Abstain = 0x02,
+    No = 0x03,
+    NoWithVeto = 0x04,
+}
+
+impl VoteOption {
+    pub fn from_i32(value: i32) -> Option<Self> {
+        match value {
+            0x01 => Some(VoteOption::Yes),
+            0x02 => Some(VoteOption::Abstain),
+            0x03 => Some(VoteOption::No),
+            0x04 => Some(VoteOption::NoWithVeto),
+            _ => None,
+        }
+    }
+}
+
+impl From<VoteOption> for i32 {
+    fn from(option: VoteOption) -> i32 {
+        option as i32
+    }
+}
+
 #[derive(Deserialize, Serialize, strum_macros::Display, Debug, Clone, PartialEq, EnumIter)]
 pub enum ProposalTime {
     SubmitTime,
@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::hash::Hash;
+
+use crate::api::custom::types::gov::params_change_ext::ParamsChangeExt;
+use crate::api::custom::types::ibc::client_ext::IbcClientsExt;
+use crate::api::custom::types::upgrade::plan_ext::UpgradePlanExt;
+use crate::api::custom::types::wasm::contract_ext::WasmDetailsExt;
+
+// Live chain data that is queried for a single proposal message to explain what it actually does.
+#[derive(Serialize, Deserialize, strum_macros::Display, Debug, Clone, PartialEq, Hash)]
+pub enum ContentDetail {
+    ParamsChange(ParamsChangeExt),
+    IbcClients(IbcClientsExt),
+    UpgradePlan(UpgradePlanExt),
+    Wasm(WasmDetailsExt),
+}
+
+impl ContentDetail {
+    pub fn to_details_string(&self) -> String {
+        match self {
+            ContentDetail::ParamsChange(params_change) => params_change.to_string(),
+            ContentDetail::IbcClients(ibc_clients) => ibc_clients.to_string(),
+            ContentDetail::UpgradePlan(upgrade_plan) => upgrade_plan.to_string(),
+            ContentDetail::Wasm(wasm) => wasm.to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Hash)]
+pub struct ContentDetailsExt {
+    pub message_index: usize,
+    pub detail: ContentDetail,
+}
+
+impl ContentDetailsExt {
+    pub fn new(message_index: usize, detail: ContentDetail) -> Self {
+        Self {
+            message_index,
+            detail,
+        }
+    }
+}
+
+impl fmt::Display for ContentDetailsExt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.detail.to_details_string())
+    }
+}
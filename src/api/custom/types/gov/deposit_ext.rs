@@ -0,0 +1,209 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use rust_decimal::Decimal;
+
+use crate::api::core::cosmos::channels::SupportedBlockchain;
+use crate::api::custom::types::decimal_ext::{ratio, to_percentage};
+use crate::api::custom::types::gov::gov_params_ext::{CoinAmount, GovParamsExt};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DepositExt {
+    pub blockchain: SupportedBlockchain,
+    pub proposal_id: u64,
+    pub depositor: String,
+    pub amount: Vec<CoinAmount>,
+}
+
+impl DepositExt {
+    pub fn new(
+        blockchain: &SupportedBlockchain,
+        deposit: cosmos_sdk_proto::cosmos::gov::v1::Deposit,
+    ) -> anyhow::Result<Self> {
+        Ok(Self {
+            blockchain: blockchain.clone(),
+            proposal_id: deposit.proposal_id,
+            amount: CoinAmount::from_coins(&deposit.amount)?,
+            depositor: deposit.depositor,
+        })
+    }
+
+    pub fn from_v1beta1(
+        blockchain: &SupportedBlockchain,
+        deposit: cosmos_sdk_proto::cosmos::gov::v1beta1::Deposit,
+    ) -> anyhow::Result<Self> {
+        Ok(Self {
+            blockchain: blockchain.clone(),
+            proposal_id: deposit.proposal_id,
+            amount: CoinAmount::from_coins(&deposit.amount)?,
+            depositor: deposit.depositor,
+        })
+    }
+}
+
+impl fmt::Display for DepositExt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} deposited {}",
+            self.depositor,
+            self.amount
+                .iter()
+                .map(|coin| coin.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+// How close the deposits of a proposal are to the `min_deposit` required to enter the voting period.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DepositProgress {
+    pub deposited: Vec<CoinAmount>,
+    pub min_deposit: Vec<CoinAmount>,
+}
+
+impl DepositProgress {
+    pub fn new(deposited: &[CoinAmount], min_deposit: Vec<CoinAmount>) -> Self {
+        let mut total: Vec<CoinAmount> = Vec::new();
+        for coin in deposited {
+            match total.iter_mut().find(|existing| existing.denom == coin.denom) {
+                Some(existing) => existing.amount += coin.amount,
+                None => total.push(coin.clone()),
+            }
+        }
+        Self {
+            deposited: total,
+            min_deposit,
+        }
+    }
+
+    pub fn from_deposits(deposits: &[DepositExt], gov_params: &GovParamsExt, expedited: bool) -> Self {
+        let deposited: Vec<CoinAmount> = deposits
+            .iter()
+            .flat_map(|deposit| deposit.amount.iter().cloned())
+            .collect();
+        Self::new(&deposited, Self::required(gov_params, expedited))
+    }
+
+    pub fn required(gov_params: &GovParamsExt, expedited: bool) -> Vec<CoinAmount> {
+        if expedited && !gov_params.expedited_min_deposit.is_empty() {
+            gov_params.expedited_min_deposit.clone()
+        } else {
+            gov_params.min_deposit.clone()
+        }
+    }
+
+    pub fn deposited_amount(&self, denom: &str) -> u128 {
+        self.deposited
+            .iter()
+            .find(|coin| coin.denom == denom)
+            .map(|coin| coin.amount)
+            .unwrap_or(0)
+    }
+
+    // The smallest fraction of `min_deposit` reached across all required denoms, capped at 1.
+    pub fn progress(&self) -> Decimal {
+        self.min_deposit
+            .iter()
+            .filter(|required| required.amount != 0)
+            .map(|required| {
+                ratio(self.deposited_amount(&required.denom), required.amount)
+                    .unwrap_or(Decimal::ZERO)
+                    .min(Decimal::ONE)
+            })
+            .min()
+            .unwrap_or(Decimal::ONE)
+    }
+
+    pub fn is_reached(&self) -> bool {
+        self.progress() >= Decimal::ONE
+    }
+
+    pub fn missing(&self) -> Vec<CoinAmount> {
+        self.min_deposit
+            .iter()
+            .filter_map(|required| {
+                let missing = required.amount.saturating_sub(self.deposited_amount(&required.denom));
+                if missing == 0 {
+                    None
+                } else {
+                    Some(CoinAmount {
+                        denom: required.denom.to_owned(),
+                        amount: missing,
+                    })
+                }
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for DepositProgress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let required = self
+            .min_deposit
+            .iter()
+            .map(|required| {
+                format!(
+                    "{} of {}",
+                    CoinAmount {
+                        denom: required.denom.to_owned(),
+                        amount: self.deposited_amount(&required.denom),
+                    },
+                    required
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(f, "💰 Deposit: {:.2}% ({})", to_percentage(self.progress()), required)?;
+        let missing = self.missing();
+        if !missing.is_empty() {
+            write!(
+                f,
+                "\nMissing: {}",
+                missing
+                    .iter()
+                    .map(|coin| coin.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    // cargo test api::custom::types::gov::deposit_ext::test::test_deposit_progress -- --exact --nocapture
+
+    use super::*;
+
+    fn coin(denom: &str, amount: u128) -> CoinAmount {
+        CoinAmount { denom: denom.to_string(), amount }
+    }
+
+    #[test]
+    fn test_deposit_progress() {
+        // Deposits are summed per denom, the least funded required denom determines the progress.
+        let progress = DepositProgress::new(
+            &[coin("uosmo", 50), coin("uosmo", 30), coin("uion", 10), coin("uatom", 5)],
+            vec![coin("uosmo", 100), coin("uion", 10)],
+        );
+        assert_eq!(progress.deposited_amount("uosmo"), 80);
+        assert_eq!(progress.progress(), Decimal::new(8, 1));
+        assert!(!progress.is_reached());
+        assert_eq!(progress.missing(), vec![coin("uosmo", 20)]);
+
+        let over = DepositProgress::new(&[coin("uosmo", 500)], vec![coin("uosmo", 100)]);
+        assert_eq!(over.progress(), Decimal::ONE);
+        assert!(over.missing().is_empty());
+
+        // A zero min deposit is always reached.
+        let zero = DepositProgress::new(&[], vec![coin("uosmo", 0)]);
+        assert_eq!(zero.progress(), Decimal::ONE);
+        assert!(zero.is_reached());
+        assert!(zero.missing().is_empty());
+        assert!(DepositProgress::new(&[], vec![]).is_reached());
+    }
+}
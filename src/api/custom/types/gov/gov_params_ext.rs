@@ -0,0 +1,275 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::time::Duration;
+
+use cosmos_sdk_proto::cosmos::base::v1beta1::Coin;
+use rust_decimal::Decimal;
+
+use crate::api::core::cosmos::channels::SupportedBlockchain;
+use crate::api::custom::i18n::{Localization, Message};
+use crate::api::custom::types::decimal_ext::{parse_sdk_dec, parse_u128};
+use crate::api::custom::types::gov::params_ext::{DurationExt, ParamsExt};
+use crate::api::custom::types::gov::proto::GovParamsV1;
+use crate::api::custom::types::gov::tally_projection::TallyThresholds;
+
+#[derive(Serialize, Deserialize, strum_macros::Display, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GovParamsVersion {
+    // Separate voting/deposit/tally params (cosmos.gov.v1beta1).
+    V1Beta1,
+    // Separate voting/deposit/tally params (cosmos.gov.v1, cosmos-sdk v0.46).
+    V1Legacy,
+    // Unified `cosmos.gov.v1.Params` (cosmos-sdk v0.47+).
+    V1,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CoinAmount {
+    pub denom: String,
+    pub amount: u128,
+}
+
+impl CoinAmount {
+    pub fn from_coin(coin: &Coin) -> anyhow::Result<Self> {
+        Ok(Self {
+            denom: coin.denom.to_owned(),
+            amount: parse_u128(&coin.amount)?,
+        })
+    }
+
+    pub fn from_coins(coins: &[Coin]) -> anyhow::Result<Vec<Self>> {
+        coins.iter().map(Self::from_coin).collect()
+    }
+
+    pub fn to_coin(&self) -> Coin {
+        Coin {
+            denom: self.denom.to_owned(),
+            amount: self.amount.to_string(),
+        }
+    }
+
+    pub fn to_string_localized(&self, localization: &Localization) -> String {
+        format!("{} {}", localization.format_number(self.amount), self.denom)
+    }
+}
+
+impl fmt::Display for CoinAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_string_localized(&Localization::default()))
+    }
+}
+
+// Governance params normalized across gov module versions.
+// Fields the chain does not support are `None` (or empty for coin lists).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct GovParamsExt {
+    pub blockchain: SupportedBlockchain,
+    pub version: GovParamsVersion,
+    pub min_deposit: Vec<CoinAmount>,
+    pub max_deposit_period: Option<Duration>,
+    pub voting_period: Option<Duration>,
+    pub quorum: Decimal,
+    pub threshold: Decimal,
+    pub veto_threshold: Decimal,
+    pub min_initial_deposit_ratio: Option<Decimal>,
+    pub proposal_cancel_ratio: Option<Decimal>,
+    pub proposal_cancel_dest: Option<String>,
+    pub expedited_voting_period: Option<Duration>,
+    pub expedited_threshold: Option<Decimal>,
+    pub expedited_min_deposit: Vec<CoinAmount>,
+    pub burn_vote_quorum: Option<bool>,
+    pub burn_proposal_deposit_prevote: Option<bool>,
+    pub burn_vote_veto: Option<bool>,
+    pub min_deposit_ratio: Option<Decimal>,
+}
+
+fn to_std_duration(duration: &Option<prost_types::Duration>) -> Option<Duration> {
+    duration
+        .as_ref()
+        .filter(|duration| duration.seconds != 0i64 || duration.nanos != 0i32)
+        .and_then(|duration| Duration::try_from(duration.clone()).ok())
+}
+
+// Optional `sdk.Dec` fields are empty strings on chains that do not know them.
+fn parse_optional_dec(value: &str) -> anyhow::Result<Option<Decimal>> {
+    if value.trim().is_empty() {
+        Ok(None)
+    } else {
+        parse_sdk_dec(value).map(Some)
+    }
+}
+
+impl GovParamsExt {
+    /// Normalizes the separate voting, deposit and tally params returned by v1beta1 and v0.46 v1 chains.
+    pub fn from_separate_params(
+        blockchain: SupportedBlockchain,
+        version: GovParamsVersion,
+        voting_period: &Option<prost_types::Duration>,
+        min_deposit: &[Coin],
+        max_deposit_period: &Option<prost_types::Duration>,
+        thresholds: TallyThresholds,
+    ) -> anyhow::Result<Self> {
+        Ok(Self {
+            blockchain,
+            version,
+            min_deposit: CoinAmount::from_coins(min_deposit)?,
+            max_deposit_period: to_std_duration(max_deposit_period),
+            voting_period: to_std_duration(voting_period),
+            quorum: thresholds.quorum,
+            threshold: thresholds.threshold,
+            veto_threshold: thresholds.veto_threshold,
+            min_initial_deposit_ratio: None,
+            proposal_cancel_ratio: None,
+            proposal_cancel_dest: None,
+            expedited_voting_period: None,
+            expedited_threshold: None,
+            expedited_min_deposit: Vec::new(),
+            burn_vote_quorum: None,
+            burn_proposal_deposit_prevote: None,
+            burn_vote_veto: None,
+            min_deposit_ratio: None,
+        })
+    }
+
+    /// Normalizes the voting, deposit and tally params queried from a v1beta1 chain.
+    pub fn from_params_v1beta1(voting: &ParamsExt, deposit: &ParamsExt, tally: &ParamsExt) -> anyhow::Result<Self> {
+        let voting_params = voting.params.0.voting_params.clone().unwrap_or_default();
+        let deposit_params = deposit.params.0.deposit_params.clone().unwrap_or_default();
+        let tally_params = tally
+            .params
+            .0
+            .tally_params
+            .as_ref()
+            .ok_or(anyhow::anyhow!("Error: Tally params are empty."))?;
+        Self::from_separate_params(
+            tally.blockchain.clone(),
+            GovParamsVersion::V1Beta1,
+            &voting_params.voting_period,
+            &deposit_params.min_deposit,
+            &deposit_params.max_deposit_period,
+            TallyThresholds::from_v1beta1(tally_params)?,
+        )
+    }
+
+    pub fn from_v1(blockchain: SupportedBlockchain, params: &GovParamsV1) -> anyhow::Result<Self> {
+        Ok(Self {
+            blockchain,
+            version: GovParamsVersion::V1,
+            min_deposit: CoinAmount::from_coins(&params.min_deposit)?,
+            max_deposit_period: to_std_duration(&params.max_deposit_period),
+            voting_period: to_std_duration(&params.voting_period),
+            quorum: parse_sdk_dec(&params.quorum)?,
+            threshold: parse_sdk_dec(&params.threshold)?,
+            veto_threshold: parse_sdk_dec(&params.veto_threshold)?,
+            min_initial_deposit_ratio: parse_optional_dec(&params.min_initial_deposit_ratio)?,
+            proposal_cancel_ratio: parse_optional_dec(&params.proposal_cancel_ratio)?,
+            proposal_cancel_dest: Some(params.proposal_cancel_dest.to_owned())
+                .filter(|dest| !dest.is_empty()),
+            expedited_voting_period: to_std_duration(&params.expedited_voting_period),
+            expedited_threshold: parse_optional_dec(&params.expedited_threshold)?,
+            expedited_min_deposit: CoinAmount::from_coins(&params.expedited_min_deposit)?,
+            burn_vote_quorum: Some(params.burn_vote_quorum),
+            burn_proposal_deposit_prevote: Some(params.burn_proposal_deposit_prevote),
+            burn_vote_veto: Some(params.burn_vote_veto),
+            min_deposit_ratio: parse_optional_dec(&params.min_deposit_ratio)?,
+        })
+    }
+
+    pub fn tally_thresholds(&self) -> TallyThresholds {
+        TallyThresholds {
+            quorum: self.quorum,
+            threshold: self.threshold,
+            veto_threshold: self.veto_threshold,
+            expedited_threshold: self.expedited_threshold,
+        }
+    }
+
+    pub fn supports_expedited(&self) -> bool {
+        self.expedited_threshold.is_some() || self.expedited_voting_period.is_some()
+    }
+
+    pub fn min_deposit_amount(&self, denom: &str, expedited: bool) -> Option<u128> {
+        let min_deposit = if expedited && !self.expedited_min_deposit.is_empty() {
+            &self.expedited_min_deposit
+        } else {
+            &self.min_deposit
+        };
+        min_deposit
+            .iter()
+            .find(|coin| coin.denom == denom)
+            .map(|coin| coin.amount)
+    }
+}
+
+fn format_duration(duration: &Duration) -> String {
+    DurationExt(&prost_types::Duration {
+        seconds: duration.as_secs() as i64,
+        nanos: duration.subsec_nanos() as i32,
+    })
+    .get_formatted_duration()
+}
+
+fn format_coins(coins: &[CoinAmount], localization: &Localization) -> String {
+    coins
+        .iter()
+        .map(|coin| coin.to_string_localized(localization))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl GovParamsExt {
+    pub fn to_string_localized(&self, localization: &Localization) -> String {
+        let mut parts = Vec::new();
+        if let Some(voting_period) = &self.voting_period {
+            parts.push(localization.format(Message::VotingPeriod, &[&format_duration(voting_period)]));
+        }
+        if !self.min_deposit.is_empty() {
+            parts.push(localization.format(Message::MinDeposit, &[&format_coins(&self.min_deposit, localization)]));
+        }
+        if let Some(max_deposit_period) = &self.max_deposit_period {
+            parts.push(localization.format(Message::MaxDepositPeriod, &[&format_duration(max_deposit_period)]));
+        }
+        if let Some(min_initial_deposit_ratio) = self.min_initial_deposit_ratio.filter(|ratio| !ratio.is_zero()) {
+            parts.push(localization.format(
+                Message::MinInitialDeposit,
+                &[&localization.format_percentage(min_initial_deposit_ratio)],
+            ));
+        }
+        parts.push(localization.format(
+            Message::TallyThresholds,
+            &[
+                &localization.format_percentage(self.quorum),
+                &localization.format_percentage(self.threshold),
+                &localization.format_percentage(self.veto_threshold),
+            ],
+        ));
+        if let Some(expedited_threshold) = self.expedited_threshold {
+            parts.push(localization.format(
+                Message::ExpeditedThreshold,
+                &[&localization.format_percentage(expedited_threshold)],
+            ));
+        }
+        if let Some(expedited_voting_period) = &self.expedited_voting_period {
+            parts.push(localization.format(
+                Message::ExpeditedVotingPeriod,
+                &[&format_duration(expedited_voting_period)],
+            ));
+        }
+        if !self.expedited_min_deposit.is_empty() {
+            parts.push(localization.format(
+                Message::ExpeditedMinDeposit,
+                &[&format_coins(&self.expedited_min_deposit, localization)],
+            ));
+        }
+        parts
+            .iter()
+            .map(|part| format!("\n{}", part))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+impl fmt::Display for GovParamsExt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_string_localized(&Localization::default()))
+    }
+}
@@ -1,6 +1,17 @@
+pub mod content_details_ext;
+pub mod deposit_ext;
+pub mod gov_params_ext;
+pub mod params_change_ext;
 pub mod params_ext;
+pub mod phishing_detector;
+pub mod proposal_diff;
 pub mod proposal_ext;
+pub mod proposal_features;
+pub mod proto;
 pub mod tally_ext;
+pub mod tally_projection;
 pub mod tally_v1beta1_ext;
+pub mod validator_votes_ext;
+pub mod vote_ext;
 //pub mod proposal_v1beta1_ext;
 pub mod common;
\ No newline at end of file
@@ -0,0 +1,263 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::hash::Hash;
+
+use strum_macros;
+use strum_macros::EnumIter;
+
+// The SDK modules that can be reconfigured through a governance `MsgUpdateParams`.
+#[derive(
+Deserialize, Serialize, strum_macros::Display, Debug, Clone, Copy, Eq, PartialEq, EnumIter, Hash,
+)]
+pub enum ParamsModule {
+    Gov,
+    Staking,
+    Mint,
+    Distribution,
+    Bank,
+    Slashing,
+    Consensus,
+    Wasm,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParamChange {
+    pub field: String,
+    pub current: Option<String>,
+    pub proposed: String,
+}
+
+// Params that directly affect the security of staked funds or the outcome of governance votes.
+const SENSITIVE_PARAMS: [&str; 12] = [
+    "unbondingtime",
+    "maxvalidators",
+    "slashfractiondoublesign",
+    "slashfractiondowntime",
+    "signedblockswindow",
+    "minsignedperwindow",
+    "downtimejailduration",
+    "quorum",
+    "threshold",
+    "vetothreshold",
+    "tallyparams",
+    "votingperiod",
+];
+
+impl ParamChange {
+    pub fn is_unchanged(&self) -> bool {
+        self.current.as_ref() == Some(&self.proposed)
+    }
+
+    // Matches both the legacy `subspace/Key` and the `snake_case` field names.
+    pub fn is_sensitive(&self) -> bool {
+        let field = self.field.to_lowercase().replace('_', "");
+        let key = field.rsplit(|c| c == '/' || c == '.').next().unwrap_or(&field);
+        SENSITIVE_PARAMS.contains(&key)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParamsChangeExt {
+    pub module: String,
+    pub changes: Vec<ParamChange>,
+}
+
+impl ParamsChangeExt {
+    // Builds the changes of a legacy `ParameterChangeProposal`, the field is `subspace/key`.
+    // The current value is `None` if it could not be queried from the x/params module.
+    pub fn from_legacy_changes(changes: Vec<(String, String, Option<String>)>) -> Self {
+        Self {
+            module: "Legacy".to_string(),
+            changes: changes
+                .into_iter()
+                .map(|(field, proposed, current)| ParamChange {
+                    field,
+                    current: current.map(|value| normalize_legacy_value(&value)),
+                    proposed: normalize_legacy_value(&proposed),
+                })
+                .collect(),
+        }
+    }
+
+    pub fn has_sensitive_changes(&self) -> bool {
+        self.changes.iter().any(|change| change.is_sensitive())
+    }
+
+    // Compares the proposed params against the current on-chain params.
+    // Only fields whose value differs (or whose current value is unknown) are kept.
+    // Fields only present in `current` are not reported, `MsgUpdateParams` always carries the full params.
+    pub fn diff(
+        module: &str,
+        current: Option<&serde_json::Value>,
+        proposed: &serde_json::Value,
+    ) -> Self {
+        let mut current_fields = Vec::new();
+        if let Some(current) = current {
+            flatten_json(current, "", &mut current_fields);
+        }
+        let mut proposed_fields = Vec::new();
+        flatten_json(proposed, "", &mut proposed_fields);
+
+        let changes = proposed_fields
+            .into_iter()
+            .map(|(field, proposed)| ParamChange {
+                current: current_fields
+                    .iter()
+                    .find(|(key, _)| key == &field)
+                    .map(|(_, value)| value.to_owned()),
+                field,
+                proposed,
+            })
+            .filter(|change| !change.is_unchanged())
+            .collect();
+
+        Self {
+            module: module.to_string(),
+            changes,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl fmt::Display for ParamsChangeExt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.changes.is_empty() {
+            return write!(f, "{} params: no changes compared to the current on-chain params.", self.module);
+        }
+        let changes = self
+            .changes
+            .iter()
+            .map(|change| {
+                format!(
+                    "{}{}\n  before: {}\n  after:  {}",
+                    if change.is_sensitive() { "⚠ " } else { "- " },
+                    change.field,
+                    change.current.as_deref().unwrap_or("(unknown)"),
+                    change.proposed
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        write!(f, "{} params changes:\n{}", self.module, changes)?;
+        if self.has_sensitive_changes() {
+            write!(f, "\n\n⚠ This proposal changes security sensitive params.")?;
+        }
+        Ok(())
+    }
+}
+
+// Flattens a JSON object into `(path, value)` pairs, e.g. `{"a":{"b":1}}` becomes `[("a.b","1")]`.
+// Arrays of scalars (like `min_deposit`) are kept as a single comma separated value.
+pub fn flatten_json(value: &serde_json::Value, prefix: &str, out: &mut Vec<(String, String)>) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map {
+                let path = if prefix.is_empty() {
+                    key.to_owned()
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                flatten_json(value, &path, out);
+            }
+        }
+        _ => {
+            out.push((prefix.to_string(), json_value_to_string(value)));
+        }
+    }
+}
+
+pub fn json_value_to_string(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => "null".to_string(),
+        serde_json::Value::String(s) => s.to_owned(),
+        serde_json::Value::Array(list) => list
+            .iter()
+            .map(|item| match item {
+                serde_json::Value::Object(map)
+                    if map.contains_key("amount") && map.contains_key("denom") =>
+                {
+                    format!(
+                        "{} {}",
+                        json_value_to_string(&map["amount"]),
+                        json_value_to_string(&map["denom"])
+                    )
+                }
+                _ => json_value_to_string(item),
+            })
+            .collect::<Vec<String>>()
+            .join(", "),
+        _ => value.to_string(),
+    }
+}
+
+// Legacy param values are JSON encoded, e.g. `"\"1814400s\""`. Strings are unquoted, objects are flattened.
+pub fn normalize_legacy_value(value: &str) -> String {
+    match serde_json::from_str::<serde_json::Value>(value) {
+        Ok(serde_json::Value::Object(map)) => {
+            let mut fields = Vec::new();
+            flatten_json(&serde_json::Value::Object(map), "", &mut fields);
+            fields
+                .iter()
+                .map(|(field, value)| format!("{}={}", field, value))
+                .collect::<Vec<String>>()
+                .join(", ")
+        }
+        Ok(json) => json_value_to_string(&json),
+        Err(_) => value.trim().to_string(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    // cargo test api::custom::types::gov::params_change_ext::test::test_params_diff -- --exact --nocapture
+
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_params_diff() {
+        let mut fields = Vec::new();
+        flatten_json(
+            &json!({"voting_params": {"voting_period": "5s"}, "min_deposit": [{"denom": "uosmo", "amount": "10"}]}),
+            "",
+            &mut fields,
+        );
+        fields.sort();
+        assert_eq!(
+            fields,
+            vec![
+                ("min_deposit".to_string(), "10 uosmo".to_string()),
+                ("voting_params.voting_period".to_string(), "5s".to_string()),
+            ]
+        );
+
+        let current = json!({"tally_params": {"quorum": "0.4", "threshold": "0.5"}, "removed": "1"});
+        let proposed = json!({"tally_params": {"quorum": "0.2", "threshold": "0.5"}, "added": true});
+        let diff = ParamsChangeExt::diff("Gov", Some(&current), &proposed);
+        let change = |field: &str| diff.changes.iter().find(|change| change.field == field).cloned();
+
+        // Nested and changed.
+        assert_eq!(
+            change("tally_params.quorum"),
+            Some(ParamChange {
+                field: "tally_params.quorum".to_string(),
+                current: Some("0.4".to_string()),
+                proposed: "0.2".to_string(),
+            })
+        );
+        assert!(change("tally_params.quorum").unwrap().is_sensitive());
+        // Unchanged and removed fields are not reported, added ones have no current value.
+        assert_eq!(change("tally_params.threshold"), None);
+        assert_eq!(change("removed"), None);
+        assert_eq!(change("added").unwrap().current, None);
+        assert_eq!(diff.changes.len(), 2);
+
+        assert!(ParamsChangeExt::diff("Gov", Some(&current), &current).is_empty());
+        // Without the current params every field is reported.
+        assert_eq!(ParamsChangeExt::diff("Gov", None, &proposed).changes.len(), 3);
+    }
+}
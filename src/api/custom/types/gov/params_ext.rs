@@ -6,7 +6,9 @@ use std::hash::Hash;
 use crate::api::core::cosmos::channels::SupportedBlockchain;
 use crate::api::custom::types::ProtoMessageWrapper;
 use cosmos_sdk_proto::cosmos::gov::v1beta1::{QueryParamsResponse, TallyParams};
-use num_format::{Locale, ToFormattedString};
+use crate::api::custom::types::decimal_ext::{parse_sdk_dec, parse_u128};
+use crate::api::custom::i18n::{Localization, Message};
+use rust_decimal::Decimal;
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Hash)]
 pub struct ParamsExt {
@@ -29,15 +31,15 @@ impl ParamsExt {
     }
 }
 
-impl fmt::Display for ParamsExt {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl ParamsExt {
+    pub fn to_string_localized(&self, localization: &Localization) -> String {
         let mut parts = Vec::new();
         if let Some(voting_params) = &self.params.0.voting_params {
             if let Some(voting_period) = &voting_params.voting_period {
                 if voting_period.seconds != 0i64 || voting_period.nanos != 0i32 {
-                    parts.push(format!(
-                        "\nVoting period: {}",
-                        DurationExt(voting_period).get_formatted_duration()
+                    parts.push(localization.format(
+                        Message::VotingPeriod,
+                        &[&DurationExt(voting_period).get_formatted_duration()],
                     ));
                 }
             }
@@ -49,43 +51,56 @@ impl fmt::Display for ParamsExt {
                 .map(|coin_ext| {
                     format!(
                         "{} {}",
-                        coin_ext
-                            .amount
-                            .parse::<u128>()
-                            .unwrap_or(0u128)
-                            .to_formatted_string(&Locale::en),
+                        parse_u128(&coin_ext.amount)
+                            .map(|amount| localization.format_number(amount))
+                            .unwrap_or(coin_ext.amount.to_owned()),
                         coin_ext.denom
                     )
                 })
                 .collect::<Vec<_>>()
                 .join(", ");
             if !min_deposit_str.is_empty() {
-                parts.push(format!("\nMin deposit: {}", min_deposit_str));
+                parts.push(localization.format(Message::MinDeposit, &[&min_deposit_str]));
             }
             if let Some(max_deposit_period) = &deposit_params.max_deposit_period {
                 if max_deposit_period.seconds != 0i64 || max_deposit_period.nanos != 0i32 {
-                    parts.push(format!(
-                        "\nMax deposit period: {}",
-                        DurationExt(max_deposit_period).get_formatted_duration()
+                    parts.push(localization.format(
+                        Message::MaxDepositPeriod,
+                        &[&DurationExt(max_deposit_period).get_formatted_duration()],
                     ));
                 }
             }
         }
         if let Some(tally_params) = &self.params.0.tally_params {
             let tally_params_ext = TallyParamsExt(tally_params);
-            let quorum = tally_params_ext.get_quorum();
-            let threshold = tally_params_ext.get_threshold();
-            let veto_threshold = tally_params_ext.get_veto_threshold();
-            if quorum != 0f64 || threshold != 0f64 || veto_threshold != 0f64 {
-                parts.push(format!(
-                    "\nQuorum: {:.2}%,\nThreshold: {:.2}%,\nVeto threshold: {:.2}%",
-                    quorum * 100.0,
-                    threshold * 100.0,
-                    veto_threshold * 100.0
-                ));
+            if let (Ok(quorum), Ok(threshold), Ok(veto_threshold)) = (
+                tally_params_ext.get_quorum(),
+                tally_params_ext.get_threshold(),
+                tally_params_ext.get_veto_threshold(),
+            ) {
+                if !quorum.is_zero() || !threshold.is_zero() || !veto_threshold.is_zero() {
+                    parts.push(localization.format(
+                        Message::TallyThresholds,
+                        &[
+                            &localization.format_percentage(quorum),
+                            &localization.format_percentage(threshold),
+                            &localization.format_percentage(veto_threshold),
+                        ],
+                    ));
+                }
             }
         }
-        write!(f, "{}", parts.join(", "))
+        parts
+            .iter()
+            .map(|part| format!("\n{}", part))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+impl fmt::Display for ParamsExt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_string_localized(&Localization::default()))
     }
 }
 
@@ -158,25 +173,21 @@ impl<'a> DurationExt<'a> {
 pub struct TallyParamsExt<'a>(pub &'a TallyParams);
 
 impl<'a> TallyParamsExt<'a> {
-    fn decode_dec(&self, dec_encoded: &Vec<u8>) -> f64 {
-        let decimal_string = String::from_utf8_lossy(dec_encoded).to_string();
-        let decimal_int = decimal_string.parse::<u128>().unwrap();
-        let decimal = decimal_int as f64 / 10_u128.pow(18) as f64;
-        decimal
+    // v1beta1 stores the `sdk.Dec` as the utf8 string of its 18 decimal places integer representation.
+    fn decode_dec(&self, dec_encoded: &[u8]) -> anyhow::Result<Decimal> {
+        let decimal_string = std::str::from_utf8(dec_encoded)?;
+        parse_sdk_dec(decimal_string)
     }
 
-    pub fn get_quorum(&self) -> f64 {
-        let dec_encoded: &Vec<u8> = &self.0.quorum;
-        self.decode_dec(dec_encoded)
+    pub fn get_quorum(&self) -> anyhow::Result<Decimal> {
+        self.decode_dec(&self.0.quorum)
     }
 
-    pub fn get_threshold(&self) -> f64 {
-        let dec_encoded: &Vec<u8> = &self.0.threshold;
-        self.decode_dec(dec_encoded)
+    pub fn get_threshold(&self) -> anyhow::Result<Decimal> {
+        self.decode_dec(&self.0.threshold)
     }
 
-    pub fn get_veto_threshold(&self) -> f64 {
-        let dec_encoded: &Vec<u8> = &self.0.veto_threshold;
-        self.decode_dec(dec_encoded)
+    pub fn get_veto_threshold(&self) -> anyhow::Result<Decimal> {
+        self.decode_dec(&self.0.veto_threshold)
     }
 }
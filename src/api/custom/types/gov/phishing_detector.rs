@@ -0,0 +1,373 @@
+use serde::{Deserialize, Serialize};
+
+use crate::api::custom::types::gov::common::ProposalContent;
+use crate::api::custom::types::gov::proposal_ext::ProposalExt;
+use crate::api::custom::types::gov::proposal_features::{
+    domain_of, extract_urls, keyword_count, AIRDROP_KEYWORDS, CLAIM_KEYWORDS,
+};
+
+// Code and explorer hosts commonly linked by legitimate proposals, subdomains included.
+pub const DEFAULT_ALLOWLIST: &[&str] = &[
+    "github.com",
+    "gitlab.com",
+    "cosmos.network",
+    "cosmos.directory",
+    "mintscan.io",
+    "ping.pub",
+];
+
+// Hosts where anyone can publish, subdomains included. Links to them are common in legitimate proposals,
+// so they are not reported as unknown domains, but they are untrusted for the lure checks.
+pub const USER_CONTENT_HOSTS: &[&str] = &[
+    "commonwealth.im",
+    "ipfs.io",
+    "hackmd.io",
+    "medium.com",
+    "notion.site",
+    "docs.google.com",
+    "twitter.com",
+    "x.com",
+    "youtube.com",
+];
+
+// Why a proposal looks like phishing or spam.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum PhishingReason {
+    // Link in a text proposal to a domain that is neither allowlisted nor an official site.
+    UnknownDomain(String),
+    LookalikeDomain { domain: String, official: String },
+    AirdropLure(Vec<String>),
+    ClaimLure(Vec<String>),
+    ZeroDeposit,
+}
+
+impl PhishingReason {
+    pub fn score(&self) -> f64 {
+        match self {
+            PhishingReason::UnknownDomain(_) => 0.25,
+            PhishingReason::LookalikeDomain { .. } => 0.6,
+            PhishingReason::AirdropLure(_) => 0.25,
+            PhishingReason::ClaimLure(_) => 0.2,
+            PhishingReason::ZeroDeposit => 0.3,
+        }
+    }
+}
+
+impl std::fmt::Display for PhishingReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PhishingReason::UnknownDomain(domain) => write!(f, "Link to unknown domain {}", domain),
+            PhishingReason::LookalikeDomain { domain, official } => {
+                write!(f, "Lookalike domain {} (official: {})", domain, official)
+            }
+            PhishingReason::AirdropLure(keywords) => write!(f, "Airdrop lure: {}", keywords.join(", ")),
+            PhishingReason::ClaimLure(keywords) => write!(f, "Claim lure: {}", keywords.join(", ")),
+            PhishingReason::ZeroDeposit => write!(f, "Zero deposit"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct PhishingReport {
+    pub reasons: Vec<PhishingReason>,
+}
+
+impl PhishingReport {
+    pub fn is_empty(&self) -> bool {
+        self.reasons.is_empty()
+    }
+
+    /// Sum of the reason scores, capped at 1.0.
+    pub fn score(&self) -> f64 {
+        self.reasons.iter().map(|reason| reason.score()).sum::<f64>().min(1.0)
+    }
+}
+
+/// Rule-based detector for typical scam proposals.
+///
+/// The official sites of a chain are its `websites` and the domain of its `governance_proposals_link`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhishingDetector {
+    allowlist: Vec<String>,
+}
+
+impl Default for PhishingDetector {
+    fn default() -> Self {
+        Self::new(DEFAULT_ALLOWLIST.iter().map(|domain| domain.to_string()).collect())
+    }
+}
+
+impl PhishingDetector {
+    pub fn new(allowlist: Vec<String>) -> Self {
+        Self { allowlist }
+    }
+
+    pub fn with_allowed_domain(mut self, domain: &str) -> Self {
+        self.allowlist.push(domain.to_lowercase());
+        self
+    }
+
+    pub fn detect(&self, proposal: &ProposalExt) -> PhishingReport {
+        let official: Vec<String> = proposal
+            .blockchain
+            .websites
+            .iter()
+            .chain(std::iter::once(&proposal.blockchain.governance_proposals_link))
+            .filter_map(|url| domain_of(url))
+            .collect();
+        // Names a lookalike domain would imitate.
+        let mut official_labels: Vec<String> = proposal
+            .blockchain
+            .websites
+            .iter()
+            .filter_map(|url| domain_of(url))
+            .filter_map(|domain| registrable_label(&domain))
+            .collect();
+        official_labels.push(proposal.blockchain.name.to_lowercase());
+        official_labels.sort();
+        official_labels.dedup();
+
+        let text = format!("{}\n\n{}", proposal.get_title(), proposal.get_description());
+        let lower = text.to_lowercase();
+        let mut domains: Vec<String> = extract_urls(&text).iter().filter_map(|url| domain_of(url)).collect();
+        domains.sort();
+        domains.dedup();
+
+        let is_text_proposal = proposal
+            .messages_as_proposal_content()
+            .iter()
+            .all(|content| matches!(content, ProposalContent::TextProposal(_)));
+
+        let mut reasons = Vec::new();
+        let mut untrusted_link = false;
+        for domain in domains {
+            if self.is_allowed(&domain) || official.iter().any(|x| is_same_or_subdomain(&domain, x)) {
+                continue;
+            }
+            untrusted_link = true;
+            if USER_CONTENT_HOSTS.iter().any(|host| is_same_or_subdomain(&domain, host)) {
+                continue;
+            }
+            if let Some(imitated) = official_labels.iter().find(|label| is_lookalike(&domain, label)) {
+                let official = official
+                    .iter()
+                    .find(|x| registrable_label(x).as_ref() == Some(imitated))
+                    .cloned()
+                    .unwrap_or(imitated.to_owned());
+                reasons.push(PhishingReason::LookalikeDomain { domain, official });
+            } else if is_text_proposal {
+                reasons.push(PhishingReason::UnknownDomain(domain));
+            }
+        }
+
+        // Lure keywords are common in legitimate proposals, they only count together with an untrusted link.
+        let airdrop = matched_keywords(&lower, AIRDROP_KEYWORDS);
+        if !airdrop.is_empty() && untrusted_link {
+            reasons.push(PhishingReason::AirdropLure(airdrop));
+        }
+        let claim = matched_keywords(&lower, CLAIM_KEYWORDS);
+        if !claim.is_empty() && untrusted_link {
+            reasons.push(PhishingReason::ClaimLure(claim));
+        }
+
+        let zero_deposit = proposal.proposal.0.total_deposit.iter().all(|coin| {
+            coin.amount.parse::<u128>().map(|amount| amount == 0).unwrap_or(false)
+        });
+        if zero_deposit {
+            reasons.push(PhishingReason::ZeroDeposit);
+        }
+
+        PhishingReport { reasons }
+    }
+
+    fn is_allowed(&self, domain: &str) -> bool {
+        self.allowlist.iter().any(|allowed| is_same_or_subdomain(domain, allowed))
+    }
+}
+
+fn is_same_or_subdomain(domain: &str, parent: &str) -> bool {
+    domain == parent || domain.ends_with(&format!(".{}", parent))
+}
+
+// Second level label, e.g. `app.osmosis.zone` → `osmosis`.
+fn registrable_label(domain: &str) -> Option<String> {
+    let mut labels = domain.rsplit('.');
+    labels.next()?;
+    labels.next().map(|label| label.to_string())
+}
+
+// Digits commonly used to imitate letters.
+fn normalize(label: &str) -> String {
+    label
+        .chars()
+        .map(|c| match c {
+            '0' => 'o',
+            '1' => 'l',
+            '3' => 'e',
+            '4' => 'a',
+            '5' => 's',
+            c => c,
+        })
+        .collect()
+}
+
+// Only the registrable label is compared, so that `osmosis.evil.com` is left to the unknown domain check
+// and names that merely start with the official one (`cosmostation` for `cosmos`) are not flagged.
+fn is_lookalike(domain: &str, official_label: &str) -> bool {
+    let official = normalize(official_label);
+    let label = match registrable_label(domain) {
+        Some(label) => normalize(&label),
+        None => return false,
+    };
+    if official.chars().count() < 4 {
+        return false;
+    }
+    // Same name on another domain, also with digits for letters (`0smosis`).
+    if label == official {
+        return true;
+    }
+    // The official name as part of a compound name (`osmosis-airdrop`).
+    let is_separator = |c: char| c == '-' || c == '_';
+    if label.contains(is_separator) && label.split(is_separator).any(|part| part == official) {
+        return true;
+    }
+    // A single typo, only for longer names where it is unlikely to hit a common word (`kava`, `java`).
+    official.chars().count() >= 6 && levenshtein(&label, &official) == 1
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + if ca == *cb { 0 } else { 1 };
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+fn matched_keywords(lower: &str, keywords: &[&str]) -> Vec<String> {
+    keywords
+        .iter()
+        .filter(|keyword| keyword_count(lower, keyword) > 0)
+        .map(|keyword| keyword.to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+
+    // cargo test api::custom::types::gov::phishing_detector::test::test_detect -- --exact --nocapture
+
+    use super::*;
+    use crate::api::core::cosmos::channels::{test_blockchain, SupportedBlockchain};
+    use crate::api::custom::types::gov::common::ProposalStatus;
+    use cosmos_sdk_proto::traits::MessageExt;
+
+    fn text_proposal(blockchain: &SupportedBlockchain, description: &str, deposit: &str) -> ProposalExt {
+        let message = cosmos_sdk_proto::cosmos::gov::v1beta1::TextProposal {
+            title: "Community update".to_string(),
+            description: description.to_string(),
+        }
+        .to_any()
+        .unwrap();
+        ProposalExt::new(
+            blockchain,
+            cosmos_sdk_proto::cosmos::gov::v1::Proposal {
+                id: 1,
+                messages: vec![message],
+                status: ProposalStatus::StatusVotingPeriod.into(),
+                total_deposit: vec![cosmos_sdk_proto::cosmos::base::v1beta1::Coin {
+                    denom: "uosmo".to_string(),
+                    amount: deposit.to_string(),
+                }],
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn test_detect() {
+        let blockchain = SupportedBlockchain {
+            governance_proposals_link: "https://www.mintscan.io/osmosis/proposals/".to_string(),
+            websites: vec!["https://osmosis.zone/".to_string()],
+            ..test_blockchain()
+        };
+        let detector = PhishingDetector::default();
+
+        let legit = text_proposal(
+            &blockchain,
+            "Details at https://app.osmosis.zone and https://github.com/osmosis-labs, see the disclaimer.",
+            "500000000",
+        );
+        assert!(detector.detect(&legit).is_empty());
+        assert_eq!(legit.fraud_label(), None);
+
+        let scam = text_proposal(
+            &blockchain,
+            "Airdrop for stakers! Claim at https://0smosis.zone or https://osmosis-rewards.xyz, more at https://example.com",
+            "0",
+        );
+        let report = detector.detect(&scam);
+        assert_eq!(
+            report.reasons,
+            vec![
+                PhishingReason::LookalikeDomain {
+                    domain: "0smosis.zone".to_string(),
+                    official: "osmosis.zone".to_string()
+                },
+                PhishingReason::UnknownDomain("example.com".to_string()),
+                PhishingReason::LookalikeDomain {
+                    domain: "osmosis-rewards.xyz".to_string(),
+                    official: "osmosis.zone".to_string()
+                },
+                PhishingReason::AirdropLure(vec!["airdrop".to_string()]),
+                PhishingReason::ClaimLure(vec!["claim".to_string(), "reward".to_string()]),
+                PhishingReason::ZeroDeposit,
+            ]
+        );
+        assert_eq!(report.score(), 1.0);
+        let label = scam.fraud_label().unwrap();
+        assert!(label.starts_with("🚨"));
+        assert!(label.contains("Lookalike domain 0smosis.zone (official: osmosis.zone)"));
+
+        assert!(is_lookalike("cosmos-airdrop.io", "cosmos"));
+        assert!(is_lookalike("osmosls.zone", "osmosis"));
+        // Project names starting with the chain name, common words and other labels than the registrable one.
+        assert!(!is_lookalike("cosmostation.io", "cosmos"));
+        assert!(!is_lookalike("java.com", "kava"));
+        assert!(!is_lookalike("osmosis.example.com", "osmosis"));
+
+        let cosmoshub = SupportedBlockchain {
+            name: "cosmoshub".to_string(),
+            websites: vec!["https://cosmos.network".to_string()],
+            ..test_blockchain()
+        };
+        let wallet = text_proposal(&cosmoshub, "Vote with https://wallet.cosmostation.io", "1000000");
+        assert_eq!(
+            detector.detect(&wallet).reasons,
+            vec![PhishingReason::UnknownDomain("wallet.cosmostation.io".to_string())]
+        );
+        assert!(!wallet.fraud_label().unwrap().starts_with("🛡️"));
+
+        // User content hosts are not unknown domains, but lures linking to them are reported.
+        let notion = text_proposal(&blockchain, "Claim your reward at https://osmosis-claim.notion.site", "1000000");
+        assert_eq!(
+            detector.detect(&notion).reasons,
+            vec![PhishingReason::ClaimLure(vec!["claim".to_string(), "reward".to_string()])]
+        );
+        let forum = text_proposal(&blockchain, "Discussion at https://commonwealth.im/osmosis", "1000000");
+        assert!(detector.detect(&forum).is_empty());
+
+        // Lure keywords without an untrusted link are not reported.
+        let bonus = text_proposal(
+            &blockchain,
+            "Adds a staking bonus after the airdrop, code at https://github.com/osmosis-labs",
+            "1000000",
+        );
+        assert!(detector.detect(&bonus).is_empty());
+    }
+}
@@ -0,0 +1,248 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+
+use chrono::{TimeZone, Utc};
+use rust_decimal::Decimal;
+
+use crate::api::custom::types::decimal_ext::{ratio, to_percentage};
+use crate::api::custom::types::gov::common::{ProposalStatus, VoteOption};
+use crate::api::custom::types::gov::gov_params_ext::CoinAmount;
+use crate::api::custom::types::gov::proposal_ext::ProposalExt;
+
+// Tally movements below one percentage point of the total votes are not reported by `diff`.
+pub const DEFAULT_TALLY_THRESHOLD: Decimal = Decimal::from_parts(1, 0, 0, false, 2);
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum ProposalChange {
+    StatusChanged {
+        from: ProposalStatus,
+        to: ProposalStatus,
+    },
+    // Share of the total votes behind `option`.
+    TallyMoved {
+        option: VoteOption,
+        from: Decimal,
+        to: Decimal,
+    },
+    DepositChanged {
+        from: Vec<CoinAmount>,
+        to: Vec<CoinAmount>,
+    },
+    // Unix timestamps in seconds.
+    VotingEndTimeChanged {
+        from: Option<i64>,
+        to: Option<i64>,
+    },
+}
+
+fn format_timestamp(seconds: &Option<i64>) -> String {
+    seconds
+        .and_then(|seconds| Utc.timestamp_opt(seconds, 0).single())
+        .map(|time| time.to_rfc2822().replace("+0000", "UTC"))
+        .unwrap_or("-".to_string())
+}
+
+fn format_coins(coins: &[CoinAmount]) -> String {
+    if coins.is_empty() {
+        return "-".to_string();
+    }
+    coins
+        .iter()
+        .map(|coin| coin.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl fmt::Display for ProposalChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProposalChange::StatusChanged { from, to } => write!(
+                f,
+                "Status: {} {} → {} {}",
+                from.to_icon(),
+                from.to_name(),
+                to.to_icon(),
+                to.to_name()
+            ),
+            ProposalChange::TallyMoved { option, from, to } => write!(
+                f,
+                "{}: {:.2}% → {:.2}%",
+                option,
+                to_percentage(*from),
+                to_percentage(*to)
+            ),
+            ProposalChange::DepositChanged { from, to } => {
+                write!(f, "Deposit: {} → {}", format_coins(from), format_coins(to))
+            }
+            ProposalChange::VotingEndTimeChanged { from, to } => write!(
+                f,
+                "Voting end: {} → {}",
+                format_timestamp(from),
+                format_timestamp(to)
+            ),
+        }
+    }
+}
+
+// Vote shares of (yes, no, abstain, no_with_veto), `None` if nobody voted yet.
+fn tally_shares(proposal: &ProposalExt) -> Option<[(VoteOption, Decimal); 4]> {
+    let (yes, no, abstain, no_with_veto) = proposal.tally_counts()?;
+    let total = yes + no + abstain + no_with_veto;
+    Some([
+        (VoteOption::Yes, ratio(yes, total).ok()?),
+        (VoteOption::No, ratio(no, total).ok()?),
+        (VoteOption::Abstain, ratio(abstain, total).ok()?),
+        (VoteOption::NoWithVeto, ratio(no_with_veto, total).ok()?),
+    ])
+}
+
+fn total_deposit(proposal: &ProposalExt) -> Vec<CoinAmount> {
+    let mut deposit = CoinAmount::from_coins(&proposal.proposal.0.total_deposit).unwrap_or_default();
+    deposit.sort_by(|a, b| a.denom.cmp(&b.denom));
+    deposit
+}
+
+/// Changes between two snapshots of the same proposal, with the default tally threshold.
+pub fn diff(old: &ProposalExt, new: &ProposalExt) -> Vec<ProposalChange> {
+    diff_with_threshold(old, new, DEFAULT_TALLY_THRESHOLD)
+}
+
+/// Changes between two snapshots of the same proposal.
+///
+/// A tally movement is reported if the share of an option moved by at least `tally_threshold`
+/// (a fraction of the total votes, e.g. 0.01 for one percentage point).
+pub fn diff_with_threshold(old: &ProposalExt, new: &ProposalExt, tally_threshold: Decimal) -> Vec<ProposalChange> {
+    let mut changes = Vec::new();
+
+    let (old_status, new_status) = (old.get_proposal_status(), new.get_proposal_status());
+    if old_status != new_status {
+        changes.push(ProposalChange::StatusChanged {
+            from: old_status,
+            to: new_status,
+        });
+    }
+
+    let zero_shares = [
+        (VoteOption::Yes, Decimal::ZERO),
+        (VoteOption::No, Decimal::ZERO),
+        (VoteOption::Abstain, Decimal::ZERO),
+        (VoteOption::NoWithVeto, Decimal::ZERO),
+    ];
+    if let Some(new_shares) = tally_shares(new) {
+        let old_shares = tally_shares(old).unwrap_or(zero_shares);
+        for ((option, from), (_, to)) in old_shares.into_iter().zip(new_shares) {
+            if (to - from).abs() >= tally_threshold {
+                changes.push(ProposalChange::TallyMoved { option, from, to });
+            }
+        }
+    }
+
+    let (old_deposit, new_deposit) = (total_deposit(old), total_deposit(new));
+    if old_deposit != new_deposit {
+        changes.push(ProposalChange::DepositChanged {
+            from: old_deposit,
+            to: new_deposit,
+        });
+    }
+
+    let old_end = old.proposal.0.voting_end_time.as_ref().map(|time| time.seconds);
+    let new_end = new.proposal.0.voting_end_time.as_ref().map(|time| time.seconds);
+    if old_end != new_end {
+        changes.push(ProposalChange::VotingEndTimeChanged {
+            from: old_end,
+            to: new_end,
+        });
+    }
+    changes
+}
+
+// Changes across the proposals of all chains between two polls.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ProposalsDiff {
+    pub added: Vec<ProposalExt>,
+    pub removed: Vec<ProposalExt>,
+    pub changed: Vec<(ProposalExt, Vec<ProposalChange>)>,
+}
+
+impl ProposalsDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+fn proposal_key(proposal: &ProposalExt) -> (String, u64) {
+    (proposal.blockchain.get_name(), proposal.get_proposal_id())
+}
+
+/// Compares two collections of proposals (of any number of chains), matched by chain name and proposal id.
+pub fn diff_all(old: &[ProposalExt], new: &[ProposalExt]) -> ProposalsDiff {
+    let old_by_key: HashMap<(String, u64), &ProposalExt> =
+        old.iter().map(|proposal| (proposal_key(proposal), proposal)).collect();
+    let new_by_key: HashMap<(String, u64), &ProposalExt> =
+        new.iter().map(|proposal| (proposal_key(proposal), proposal)).collect();
+
+    let mut result = ProposalsDiff::default();
+    for proposal in new {
+        match old_by_key.get(&proposal_key(proposal)) {
+            None => result.added.push(proposal.clone()),
+            Some(old_proposal) => {
+                let changes = diff(old_proposal, proposal);
+                if !changes.is_empty() {
+                    result.changed.push((proposal.clone(), changes));
+                }
+            }
+        }
+    }
+    result.removed = old
+        .iter()
+        .filter(|proposal| !new_by_key.contains_key(&proposal_key(proposal)))
+        .cloned()
+        .collect();
+    result
+}
+
+#[cfg(test)]
+mod test {
+
+    // cargo test api::custom::types::gov::proposal_diff::test::test_diff -- --exact --nocapture
+
+    use super::*;
+    use crate::api::core::cosmos::channels::test_blockchain;
+    use cosmos_sdk_proto::cosmos::gov::v1::{Proposal, TallyResult};
+
+    fn proposal(id: u64, status: ProposalStatus, yes: &str, no: &str) -> ProposalExt {
+        let blockchain = test_blockchain();
+        ProposalExt::new(
+            &blockchain,
+            Proposal {
+                id,
+                status: status.into(),
+                final_tally_result: Some(TallyResult {
+                    yes_count: yes.to_string(),
+                    abstain_count: "0".to_string(),
+                    no_count: no.to_string(),
+                    no_with_veto_count: "0".to_string(),
+                }),
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn test_diff() {
+        let old = proposal(1, ProposalStatus::StatusVotingPeriod, "50", "50");
+        let new = proposal(1, ProposalStatus::StatusPassed, "995", "1005");
+        let changes = diff(&old, &new);
+        assert_eq!(changes.len(), 1);
+        assert!(matches!(changes[0], ProposalChange::StatusChanged { .. }));
+
+        let new = proposal(1, ProposalStatus::StatusVotingPeriod, "60", "40");
+        assert_eq!(diff(&old, &new).len(), 2);
+
+        let collection_diff = diff_all(&[old.clone()], &[new, proposal(2, ProposalStatus::StatusDepositPeriod, "0", "0")]);
+        assert_eq!(collection_diff.added.len(), 1);
+        assert_eq!(collection_diff.changed.len(), 1);
+        assert!(diff_all(&[old], &[]).removed.len() == 1);
+    }
+}
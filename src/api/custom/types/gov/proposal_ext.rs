@@ -1,8 +1,6 @@
 use crate::api::core::cosmos::channels::SupportedBlockchain;
 
 use prost_types::Timestamp;
-use std::cmp::Ordering;
-use std::collections::hash_map::DefaultHasher;
 
 use std::hash::{Hash, Hasher};
 
@@ -11,30 +9,37 @@ use std::string::ToString;
 use strum_macros;
 use strum_macros::EnumIter;
 
-use chrono::NaiveDateTime;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, TimeZone, Utc};
 use cosmos_sdk_proto::cosmos::gov::v1::TallyResult;
+use crypto::digest::Digest;
+use crypto::sha2::Sha256;
 
 
 use serde::{Deserialize, Serialize};
-use crate::api::custom::types::gov::common::{ProposalContent, ProposalStatus};
-use crate::api::custom::types::gov::params_ext::ParamsExt;
+use crate::api::custom::i18n::{Localization, Message};
+use crate::api::custom::types::gov::common::{ProposalContent, ProposalStatus, ProposalTime};
+use crate::api::custom::types::gov::content_details_ext::ContentDetailsExt;
+use crate::api::custom::types::gov::deposit_ext::DepositProgress;
+use crate::api::custom::types::gov::gov_params_ext::{CoinAmount, GovParamsExt};
+use crate::api::custom::types::gov::phishing_detector::{PhishingDetector, PhishingReport};
 
 
 use crate::api::custom::types::gov::tally_ext::TallyHelper;
-use crate::api::custom::types::gov::tally_v1beta1_ext::TallyResultV1Beta1Ext;
+use crate::api::custom::types::gov::tally_projection::TallyProjection;
+use crate::api::custom::types::gov::tally_v1beta1_ext::{TallyHelper as TallyV1Beta1Helper, TallyResultV1Beta1Ext};
 use crate::api::custom::types::ProtoMessageWrapper;
 use crate::api::custom::types::staking::pool_ext::PoolExt;
+use crate::api::custom::types::wasm::code_ext::WasmCodeMetadata;
 
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
 pub struct ProposalParams {
     pub fraud_classification: Option<f64>,
     pub tally_result: Option<TallyResultV1Beta1Ext>,
-    pub tallying_param: Option<ParamsExt>,
-    pub deposit_param: Option<ParamsExt>,
-    pub voting_param: Option<ParamsExt>,
+    pub gov_params: Option<GovParamsExt>,
     pub blockchain_pool: Option<PoolExt>,
+    #[serde(default)]
+    pub content_details: Vec<ContentDetailsExt>,
 }
 
 impl Hash for ProposalParams{
@@ -45,22 +50,22 @@ impl ProposalParams {
     pub fn new(
         fraud_classification: Option<f64>,
         tally_result: Option<TallyResultV1Beta1Ext>,
-        tallying_param: Option<ParamsExt>,
-        deposit_param: Option<ParamsExt>,
-        voting_param: Option<ParamsExt>,
+        gov_params: Option<GovParamsExt>,
         blockchain_pool: Option<PoolExt>,
     ) -> Self {
         Self {
             fraud_classification,
             tally_result,
-            tallying_param,
-            deposit_param,
-            voting_param,
+            gov_params,
             blockchain_pool,
+            content_details: Vec::new(),
         }
     }
 }
 
+// Bumped whenever the fields covered by `ProposalExt::content_hash` change.
+pub const CONTENT_HASH_VERSION: u8 = 2;
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Hash)]
 pub struct ProposalExt {
     pub blockchain: SupportedBlockchain,
@@ -111,10 +116,77 @@ impl ProposalExt {
         self.params = Some(params);
     }
 
+    pub fn add_content_details(&mut self, content_details: Vec<ContentDetailsExt>){
+        self.params.get_or_insert_with(ProposalParams::default).content_details = content_details;
+    }
+
+    pub fn get_content_details(&self, message_index: usize) -> Vec<&ContentDetailsExt> {
+        self.params
+            .as_ref()
+            .map(|params| {
+                params
+                    .content_details
+                    .iter()
+                    .filter(|details| details.message_index == message_index)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Stable digest of the proposal, suitable as a persistent key (e.g. to dedupe notifications).
+    ///
+    /// Hex encoded sha256 over the digest version, the chain name and the canonical proposal fields
+    /// (id, status, messages, submit/deposit end/voting times, total deposit), each field length prefixed,
+    /// formatted as `v{CONTENT_HASH_VERSION}:{hex}`.
+    /// Fields are listed explicitly rather than hashing the protobuf encoding, so new fields in
+    /// `cosmos-sdk-proto` do not change existing digests.
+    /// Endpoint data of the chain (rank, gRPC urls, links) and the attached `params` (live tally,
+    /// pool, gov params) are not part of the digest, so it only changes with the on-chain proposal.
+    pub fn content_hash(&self) -> String {
+        let proposal = &self.proposal.0;
+        let mut fields: Vec<Vec<u8>> = vec![
+            self.blockchain.get_name().into_bytes(),
+            proposal.id.to_be_bytes().to_vec(),
+            proposal.status.to_be_bytes().to_vec(),
+            (proposal.messages.len() as u64).to_be_bytes().to_vec(),
+        ];
+        for message in proposal.messages.iter() {
+            fields.push(message.type_url.as_bytes().to_vec());
+            fields.push(message.value.to_owned());
+        }
+        for time in [
+            &proposal.submit_time,
+            &proposal.deposit_end_time,
+            &proposal.voting_start_time,
+            &proposal.voting_end_time,
+        ] {
+            // Empty if not set.
+            fields.push(
+                time.as_ref()
+                    .map(|time| [time.seconds.to_be_bytes().as_slice(), time.nanos.to_be_bytes().as_slice()].concat())
+                    .unwrap_or_default(),
+            );
+        }
+        fields.push((proposal.total_deposit.len() as u64).to_be_bytes().to_vec());
+        for coin in proposal.total_deposit.iter() {
+            fields.push(coin.denom.as_bytes().to_vec());
+            fields.push(coin.amount.as_bytes().to_vec());
+        }
+
+        let mut sha = Sha256::new();
+        sha.input(&[CONTENT_HASH_VERSION]);
+        for field in fields {
+            sha.input(&(field.len() as u64).to_be_bytes());
+            sha.input(&field);
+        }
+        format!("v{}:{}", CONTENT_HASH_VERSION, sha.result_str())
+    }
+
+    /// The first 8 bytes of `content_hash`, stable across processes and toolchains.
     pub fn object_to_hash(&self) -> u64 {
-        let mut s = DefaultHasher::new();
-        &self.hash(&mut s);
-        s.finish()
+        let content_hash = self.content_hash();
+        let hex = content_hash.split(':').last().unwrap_or_default();
+        u64::from_str_radix(&hex[..16], 16).unwrap_or_default()
     }
     pub fn messages_as_proposal_content(&self) -> Vec<ProposalContent> {
         let proposal_content = self
@@ -125,6 +197,16 @@ impl ProposalExt {
         proposal_content
     }
     
+    // Checksum, entry points and interface version of the wasm code uploaded by this proposal.
+    // Messages whose byte code can not be inspected are skipped.
+    pub fn wasm_code_metadata(&self) -> Vec<WasmCodeMetadata> {
+        self.messages_as_proposal_content()
+            .iter()
+            .filter_map(|content| content.wasm_code_metadata())
+            .filter_map(|metadata| metadata.ok())
+            .collect()
+    }
+
     pub fn get_proposal_status(&self) -> ProposalStatus {
         ProposalStatus::from_i32(self.proposal.0.status).unwrap()
     }
@@ -162,7 +244,13 @@ impl ProposalExt {
     }
 
     pub fn get_description(&self) -> String {
-        self.messages_as_proposal_content().iter().map(|msg| msg.get_description().replace("\\n","\n")).collect::<Vec<String>>().join("\n")
+        self.messages_as_proposal_content().iter().enumerate().map(|(index, msg)| {
+            let mut description = msg.get_description().replace("\\n","\n");
+            for details in self.get_content_details(index) {
+                description.push_str(&format!("\n\n{}", details));
+            }
+            description
+        }).collect::<Vec<String>>().join("\n")
     }
     pub fn get_title(&self) -> String {
         self.messages_as_proposal_content().iter().map(|msg| msg.get_title()).collect::<Vec<String>>().join("\n")
@@ -185,23 +273,44 @@ impl ProposalExt {
             title,
         );
 
-        if let Some(prediction) =  self.params.as_ref().map(|x| x.fraud_classification).flatten() {
-            let label = if prediction >= 0.7 {
-                format!("🚨 {}", Self::map_prediction_to_string(prediction))
-            } else if prediction >= 0.35 {
-                format!("⚠ {}", Self::map_prediction_to_string(prediction))
-            } else if prediction >= 0.30 {
-                format!("❗ {}", Self::map_prediction_to_string(prediction))
-            } else if prediction > 0.25 {
-                format!("⁉️️ {}", Self::map_prediction_to_string(prediction))
-            } else {
-                format!("🛡️ {}", Self::map_prediction_to_string(prediction))
-            };
+        if let Some(label) = self.fraud_label() {
             display = format!("{}\n\n{}", display, label);
         }
         display
     }
 
+    /// Rule-based phishing and spam check with the default allowlist.
+    pub fn phishing_report(&self) -> PhishingReport {
+        PhishingDetector::default().detect(self)
+    }
+
+    // Warning label of the higher of the attached fraud classification and the phishing score, with the reasons found.
+    pub fn fraud_label(&self) -> Option<String> {
+        let report = self.phishing_report();
+        let classification = self.params.as_ref().and_then(|x| x.fraud_classification);
+        let prediction = match classification {
+            Some(classification) => classification.max(report.score()),
+            None if !report.is_empty() => report.score(),
+            None => return None,
+        };
+        let mut label = if prediction >= 0.7 {
+            format!("🚨 {}", Self::map_prediction_to_string(prediction))
+        } else if prediction >= 0.35 {
+            format!("⚠ {}", Self::map_prediction_to_string(prediction))
+        } else if prediction >= 0.30 {
+            format!("❗ {}", Self::map_prediction_to_string(prediction))
+        } else if prediction > 0.25 || !report.is_empty() {
+            // Any reason found is worth a look, even if its score alone is low.
+            format!("⁉️️ {}", Self::map_prediction_to_string(prediction))
+        } else {
+            format!("🛡️ {}", Self::map_prediction_to_string(prediction))
+        };
+        for reason in report.reasons {
+            label.push_str(&format!("\n• {}", reason));
+        }
+        Some(label)
+    }
+
     fn map_prediction_to_string(number: f64) -> String {
         let mut result = String::new();
 
@@ -224,35 +333,136 @@ impl ProposalExt {
         result
     }
 
-    fn timestamp_to_string(item: Option<Timestamp>) -> String {
-        match item.as_ref() {
-            Some(time) => {
-                if time.seconds > 0 {
-                    DateTime::<Utc>::from_utc(
-                        NaiveDateTime::from_timestamp_opt(time.seconds, time.nanos as u32).unwrap(),
-                        Utc,
-                    )
-                    .to_rfc2822()
-                    .replace("+0000", "UTC")
-                } else {
-                    "".to_string()
-                }
-            }
-            None => "".to_string(),
-        }
+    // Unset timestamps are zero in the proto, out of range timestamps are treated as unset.
+    fn to_datetime(time: &Option<Timestamp>) -> Option<DateTime<Utc>> {
+        let time = time.as_ref().filter(|time| time.seconds > 0)?;
+        Utc.timestamp_opt(time.seconds, u32::try_from(time.nanos).ok()?).single()
     }
 
-    fn get_voting_start_and_end(&self) -> (String, String) {
+    pub fn get_time(&self, time: &ProposalTime) -> Option<DateTime<Utc>> {
         let proposal = &self.proposal.0;
+        match time {
+            ProposalTime::SubmitTime => Self::to_datetime(&proposal.submit_time),
+            ProposalTime::DepositEndTime => Self::to_datetime(&proposal.deposit_end_time),
+            ProposalTime::VotingStartTime => Self::to_datetime(&proposal.voting_start_time),
+            ProposalTime::VotingEndTime => Self::to_datetime(&proposal.voting_end_time),
+            ProposalTime::LatestTime => [
+                &proposal.submit_time,
+                &proposal.deposit_end_time,
+                &proposal.voting_start_time,
+                &proposal.voting_end_time,
+            ]
+            .into_iter()
+            .filter_map(Self::to_datetime)
+            .max(),
+        }
+    }
+
+    pub fn submit_time(&self) -> Option<DateTime<Utc>> {
+        self.get_time(&ProposalTime::SubmitTime)
+    }
+
+    pub fn deposit_end_time(&self) -> Option<DateTime<Utc>> {
+        self.get_time(&ProposalTime::DepositEndTime)
+    }
+
+    pub fn voting_start_time(&self) -> Option<DateTime<Utc>> {
+        self.get_time(&ProposalTime::VotingStartTime)
+    }
+
+    pub fn voting_end_time(&self) -> Option<DateTime<Utc>> {
+        self.get_time(&ProposalTime::VotingEndTime)
+    }
+
+    pub fn latest_time(&self) -> Option<DateTime<Utc>> {
+        self.get_time(&ProposalTime::LatestTime)
+    }
+
+    /// End of the current period: the deposit end in deposit period, the voting end in voting period.
+    pub fn period_end_time(&self) -> Option<DateTime<Utc>> {
+        match self.get_proposal_status() {
+            ProposalStatus::StatusDepositPeriod => self.deposit_end_time(),
+            ProposalStatus::StatusVotingPeriod => self.voting_end_time(),
+            _ => None,
+        }
+    }
+
+    /// Time left in the current deposit or voting period, zero once the end passed.
+    /// `None` for proposals in a final state or with unknown end time.
+    pub fn time_remaining(&self) -> Option<chrono::Duration> {
+        self.time_remaining_at(Utc::now())
+    }
+
+    pub fn time_remaining_at(&self, now: DateTime<Utc>) -> Option<chrono::Duration> {
+        let end = self.period_end_time()?;
+        Some((end - now).max(chrono::Duration::zero()))
+    }
+
+    /// Whether the current period ends within `duration`, e.g. for "ends in 24h" notifications.
+    pub fn ends_within(&self, duration: chrono::Duration) -> bool {
+        self.ends_within_at(duration, Utc::now())
+    }
+
+    pub fn ends_within_at(&self, duration: chrono::Duration, now: DateTime<Utc>) -> bool {
+        self.time_remaining_at(now)
+            .map(|remaining| remaining <= duration)
+            .unwrap_or(false)
+    }
+
+    /// Elapsed share of the voting period in [0, 1], `None` if the voting times are unknown.
+    pub fn voting_progress(&self) -> Option<f64> {
+        self.voting_progress_at(Utc::now())
+    }
+
+    pub fn voting_progress_at(&self, now: DateTime<Utc>) -> Option<f64> {
+        let start = self.voting_start_time()?;
+        let end = self.voting_end_time()?;
+        let total = (end - start).num_milliseconds();
+        if total <= 0 {
+            return Some(if now >= end { 1.0 } else { 0.0 });
+        }
+        let elapsed = (now - start).num_milliseconds().clamp(0, total);
+        Some(elapsed as f64 / total as f64)
+    }
+
+    /// Formats a proposal time as RFC 2822 in the given timezone.
+    pub fn format_time_in<Tz: TimeZone>(&self, time: &ProposalTime, timezone: &Tz) -> Option<String>
+    where
+        Tz::Offset: std::fmt::Display,
+    {
+        self.get_time(time)
+            .map(|time| time.with_timezone(timezone).to_rfc2822())
+    }
+
+    fn timestamp_to_string(&self, time: &ProposalTime, localization: &Localization) -> String {
+        self.get_time(time)
+            .map(|time| localization.format_datetime(&time))
+            .unwrap_or_default()
+    }
+
+    fn get_voting_start_and_end(&self, localization: &Localization) -> (String, String) {
         (
-            format!(
-                "{}",
-                Self::timestamp_to_string(proposal.voting_start_time.clone())
-            ),
-            format!(
-                "{}",
-                Self::timestamp_to_string(proposal.voting_end_time.clone())
-            ),
+            self.timestamp_to_string(&ProposalTime::VotingStartTime, localization),
+            self.timestamp_to_string(&ProposalTime::VotingEndTime, localization),
+        )
+    }
+
+    /// Projects the outcome of a proposal in voting period from the live tally, the bonded tokens and the tally params.
+    pub fn tally_projection(&self, expedited: bool) -> Option<anyhow::Result<TallyProjection>> {
+        let params = self.params.as_ref()?;
+        let thresholds = params.gov_params.as_ref()?.tally_thresholds();
+        let tally_result = params.tally_result.as_ref()?;
+        let pool = params.blockchain_pool.as_ref()?;
+        Some(tally_result.projection(pool, thresholds, expedited))
+    }
+
+    /// How close the total deposit is to the `min_deposit` of the attached gov params.
+    pub fn deposit_progress(&self, expedited: bool) -> Option<anyhow::Result<DepositProgress>> {
+        let gov_params = self.params.as_ref()?.gov_params.as_ref()?;
+        Some(
+            CoinAmount::from_coins(&self.proposal.0.total_deposit).map(|deposited| {
+                DepositProgress::new(&deposited, DepositProgress::required(gov_params, expedited))
+            }),
         )
     }
 
@@ -276,7 +486,7 @@ impl ProposalExt {
             result
         }
     }
-    pub fn total_votes(&self) -> Option<f64> {
+    pub fn total_votes(&self) -> Option<u128> {
         let proposal = &self.proposal.0;
         if let Some(tally) = &proposal.final_tally_result {
             return TallyHelper(tally).total_votes();
@@ -284,25 +494,43 @@ impl ProposalExt {
         None
     }
 
+    // (yes, no, abstain, no_with_veto) of the live tally if attached, otherwise of the final tally result.
+    pub fn tally_counts(&self) -> Option<(u128, u128, u128, u128)> {
+        let live_tally = self
+            .params
+            .as_ref()
+            .and_then(|params| params.tally_result.as_ref())
+            .and_then(|tally_result| tally_result.tally_result.0.tally.as_ref());
+        match live_tally {
+            Some(tally) => TallyV1Beta1Helper(tally).tally_counts().ok(),
+            None => self
+                .proposal
+                .0
+                .final_tally_result
+                .as_ref()
+                .and_then(|tally| TallyHelper(tally).tally_counts().ok()),
+        }
+    }
+
     pub fn final_tally_with_no_with_veto_majority(&self) -> bool {
         let proposal = &self.proposal.0;
         proposal
             .final_tally_result
-            .clone()
-            .map(|y| {
-                let no_with_veto = y.no_with_veto_count.parse::<f64>().unwrap_or(0f64);
-                let yes = y.yes_count.parse::<f64>().unwrap_or(0f64);
-                let no = y.no_count.parse::<f64>().unwrap_or(0f64);
-                no_with_veto > yes && no_with_veto > no
-            })
+            .as_ref()
+            .and_then(|tally| TallyHelper(tally).tally_counts().ok())
+            .map(|(yes, no, _, no_with_veto)| no_with_veto > yes && no_with_veto > no)
             .unwrap_or(false)
     }
 
     pub fn get_final_tally_result(&self) -> String {
+        self.get_final_tally_result_localized(&Localization::default())
+    }
+
+    pub fn get_final_tally_result_localized(&self, localization: &Localization) -> String {
         let proposal = &self.proposal.0;
         let mut output = String::new();
         if let Some(tally) = &proposal.final_tally_result {
-            output.push_str(&TallyHelper(tally).final_tally_to_string());
+            output.push_str(&TallyHelper(tally).final_tally_to_string_localized(localization));
         }
         output
     }
@@ -321,66 +549,53 @@ impl ProposalExt {
     }
 
     pub fn tally_details(&self) -> Option<String> {
+        self.tally_details_localized(&Localization::default())
+    }
+
+    pub fn tally_details_localized(&self, localization: &Localization) -> Option<String> {
         let proposal = &self.proposal.0;
         if let Some(tally) = &proposal.final_tally_result {
-            Some(TallyHelper(tally).tally_details())
+            TallyHelper(tally).tally_details_localized(localization).ok()
         } else {
             None
         }
     }
 
     pub fn proposal_submitted(&self) -> String {
-        let proposal = &self.proposal.0;
-        Self::timestamp_to_string(proposal.submit_time.clone())
+        self.proposal_submitted_localized(&Localization::default())
+    }
+
+    pub fn proposal_submitted_localized(&self, localization: &Localization) -> String {
+        self.timestamp_to_string(&ProposalTime::SubmitTime, localization)
     }
 
     pub fn proposal_state(&self) -> String {
-        let proposal = &self.proposal.0;
-        let (voting_start_text, voting_end_text) = self.get_voting_start_and_end();
-        let tally_result = self.get_final_tally_result();
+        self.proposal_state_localized(&Localization::default())
+    }
+
+    pub fn proposal_state_localized(&self, localization: &Localization) -> String {
+        let (voting_start_text, voting_end_text) = self.get_voting_start_and_end(localization);
+        let tally_result = self.get_final_tally_result_localized(localization);
 
         let mut voting_state = "".to_string();
         if &self.get_proposal_status() == &ProposalStatus::StatusVotingPeriod {
-            let mut voting_start = false;
-            if let Some(time) = &proposal.voting_start_time {
-                match DateTime::<Utc>::from_utc(
-                    NaiveDateTime::from_timestamp_opt(time.seconds, time.nanos as u32).unwrap(),
-                    Utc,
-                )
-                .cmp(&Utc::now())
-                {
-                    Ordering::Less | Ordering::Equal => {
-                        voting_start = true;
-                    }
-                    Ordering::Greater => {
-                        voting_start = false;
-                    }
-                }
-            }
-            let mut voting_end = false;
-            if let Some(time) = &proposal.voting_end_time {
-                match DateTime::<Utc>::from_utc(
-                    NaiveDateTime::from_timestamp_opt(time.seconds, time.nanos as u32).unwrap(),
-                    Utc,
-                )
-                .cmp(&Utc::now())
-                {
-                    Ordering::Less | Ordering::Equal => {
-                        voting_end = true;
-                    }
-                    Ordering::Greater => {
-                        voting_end = false;
-                    }
-                }
-            }
+            let now = Utc::now();
+            let voting_start = self.voting_start_time().map(|time| time <= now).unwrap_or(false);
+            let voting_end = self.voting_end_time().map(|time| time <= now).unwrap_or(false);
             voting_state = match (voting_start, voting_end) {
-                (true, true) => format!("Voting finished\n\n"),
-                (true, false) => format!("Voting ends at {}\n\n", voting_end_text),
-                (false, false) => format!("Voting starts at {}\n\n", voting_start_text),
-                (false, true) => format!("Voting ended before it started!\n\n"),
+                (true, true) => format!("{}\n\n", localization.text(Message::VotingFinished)),
+                (true, false) => format!("{}\n\n", localization.format(Message::VotingEndsAt, &[&voting_end_text])),
+                (false, false) => format!("{}\n\n", localization.format(Message::VotingStartsAt, &[&voting_start_text])),
+                (false, true) => format!("{}\n\n", localization.text(Message::VotingEndedBeforeStart)),
             };
         } else if &self.get_proposal_status() == &ProposalStatus::StatusDepositPeriod {
-            voting_state = format!("You can help the proposal move forward by depositing now. \nThe deposit period is open until {}\n\n",Self::timestamp_to_string(proposal.deposit_end_time.clone()))
+            voting_state = format!(
+                "{}\n\n",
+                localization.format(
+                    Message::DepositPeriodOpen,
+                    &[&self.timestamp_to_string(&ProposalTime::DepositEndTime, localization)]
+                )
+            )
         }
 
         format!(
@@ -393,3 +608,77 @@ impl ProposalExt {
         .to_string()
     }
 }
+
+#[cfg(test)]
+mod test {
+
+    // cargo test api::custom::types::gov::proposal_ext::test::test_content_hash -- --exact --nocapture
+
+    use super::*;
+    use crate::api::core::cosmos::channels::test_blockchain;
+
+    #[test]
+    fn test_content_hash() {
+        let mut blockchain = test_blockchain();
+        blockchain.grpc_service.grpc_urls = vec!["https://osmosis-grpc.lavenderfive.com:443".to_string()];
+        let proposal = cosmos_sdk_proto::cosmos::gov::v1::Proposal {
+            id: 1,
+            messages: vec![cosmos_sdk_proto::Any {
+                type_url: "/cosmos.gov.v1beta1.TextProposal".to_string(),
+                value: vec![1, 2, 3],
+            }],
+            status: ProposalStatus::StatusVotingPeriod.into(),
+            submit_time: Some(Timestamp { seconds: 1700000000, nanos: 0 }),
+            total_deposit: vec![cosmos_sdk_proto::cosmos::base::v1beta1::Coin {
+                denom: "uosmo".to_string(),
+                amount: "100".to_string(),
+            }],
+            ..Default::default()
+        };
+        let hash = ProposalExt::new(&blockchain, proposal.clone()).content_hash();
+        // Pinned, a change of this digest must come with a bump of `CONTENT_HASH_VERSION`.
+        assert_eq!(hash, "v2:c5100d3d4cb56bff39e37c5c842cd1fb112068809b6b7c72ff441303382df059");
+
+        blockchain.rank = 2;
+        blockchain.grpc_service.grpc_urls = vec![];
+        let mut proposal_ext = ProposalExt::new(&blockchain, proposal);
+        proposal_ext.add_params(ProposalParams::default());
+        assert_eq!(proposal_ext.content_hash(), hash);
+
+        proposal_ext.proposal.0.status = ProposalStatus::StatusPassed.into();
+        assert_ne!(proposal_ext.content_hash(), hash);
+    }
+
+    // cargo test api::custom::types::gov::proposal_ext::test::test_time_remaining -- --exact --nocapture
+
+    #[test]
+    fn test_time_remaining() {
+        let blockchain = test_blockchain();
+        let mut proposal = ProposalExt::new(
+            &blockchain,
+            cosmos_sdk_proto::cosmos::gov::v1::Proposal {
+                id: 1,
+                status: ProposalStatus::StatusVotingPeriod.into(),
+                voting_start_time: Some(Timestamp { seconds: 1000, nanos: 0 }),
+                voting_end_time: Some(Timestamp { seconds: 1000 + 4 * 3600, nanos: 0 }),
+                // Out of range, must not panic.
+                deposit_end_time: Some(Timestamp { seconds: i64::MAX, nanos: 0 }),
+                ..Default::default()
+            },
+        );
+        let now = Utc.timestamp_opt(1000 + 3600, 0).unwrap();
+        assert_eq!(proposal.deposit_end_time(), None);
+        assert_eq!(proposal.time_remaining_at(now), Some(chrono::Duration::hours(3)));
+        assert_eq!(proposal.voting_progress_at(now), Some(0.25));
+        assert!(proposal.ends_within_at(chrono::Duration::hours(24), now));
+        assert!(!proposal.ends_within_at(chrono::Duration::hours(1), now));
+        assert_eq!(
+            proposal.format_time_in(&ProposalTime::VotingStartTime, &chrono::FixedOffset::east_opt(3600).unwrap()),
+            Some("Thu, 01 Jan 1970 01:16:40 +0100".to_string())
+        );
+
+        proposal.proposal.0.status = ProposalStatus::StatusPassed.into();
+        assert_eq!(proposal.time_remaining_at(now), None);
+        assert!(!proposal.proposal_state().is_empty());
+    }
+}
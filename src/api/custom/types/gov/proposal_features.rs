@@ -0,0 +1,270 @@
+use serde::{Deserialize, Serialize};
+
+use rust_decimal::prelude::ToPrimitive;
+
+use crate::api::custom::types::gov::common::ProposalContent;
+use crate::api::custom::types::gov::gov_params_ext::CoinAmount;
+use crate::api::custom::types::gov::proposal_ext::ProposalExt;
+
+// Lower case keywords typical for scam proposals, matched as substrings of title and description.
+pub const CLAIM_KEYWORDS: &[&str] = &["claim", "reward", "eligible", "redeem", "unlock"];
+pub const AIRDROP_KEYWORDS: &[&str] = &["airdrop", "air drop", "free tokens", "giveaway", "bonus"];
+
+/// Urls in a text, `http(s)://` links and bare `www.` hosts, without trailing punctuation.
+pub fn extract_urls(text: &str) -> Vec<String> {
+    text.split(|c: char| c.is_whitespace() || "<>\"'`()[]{}".contains(c))
+        .filter_map(|token| {
+            let lower = token.to_ascii_lowercase();
+            let start = ["https://", "http://", "www."]
+                .iter()
+                .filter_map(|prefix| lower.find(prefix))
+                .min()?;
+            let url = token[start..].trim_end_matches(|c: char| ".,;:!?*_~".contains(c));
+            if url.len() > "www.".len() {
+                Some(url.to_string())
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Lower case host of a url without `www.` and port, e.g. `https://www.Osmosis.zone:443/x` → `osmosis.zone`.
+pub fn domain_of(url: &str) -> Option<String> {
+    let lower = url.to_lowercase();
+    let without_scheme = lower.split_once("://").map(|(_, rest)| rest).unwrap_or(&lower);
+    let host = without_scheme
+        .split(|c| c == '/' || c == '?' || c == '#')
+        .next()?
+        .rsplit('@')
+        .next()?
+        .split(':')
+        .next()?
+        .trim_start_matches("www.");
+    if host.contains('.') {
+        Some(host.to_string())
+    } else {
+        None
+    }
+}
+
+/// On-chain data of the proposer account (see `get_proposer_info`), the proposal itself does not contain it.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct ProposerInfo {
+    pub address: String,
+    // Account numbers are assigned in order of creation, a high number relative to the chain is a young account.
+    pub account_number: Option<u64>,
+    // Number of transactions signed by the account.
+    pub sequence: Option<u64>,
+    pub balance: Vec<CoinAmount>,
+}
+
+/// Feature vector of a proposal for spam and fraud classifiers.
+///
+/// `to_vector` returns the numeric features in the order of `FEATURE_NAMES`, unknown values are `NaN`.
+/// `text` and `message_types` are meant for text models.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct ProposalFeatures {
+    // Total deposit / min deposit, requires the gov params.
+    pub deposit_ratio: Option<f64>,
+    pub url_count: usize,
+    pub domains: Vec<String>,
+    pub claim_keyword_count: usize,
+    pub airdrop_keyword_count: usize,
+    pub message_types: Vec<String>,
+    pub is_text_proposal: bool,
+    pub title_length: usize,
+    pub description_length: usize,
+    pub proposer_account_number: Option<u64>,
+    pub proposer_sequence: Option<u64>,
+    // Balance in the denom of the min deposit (or the first denom held), in base units.
+    pub proposer_balance: Option<f64>,
+    // Vote shares of the live tally if attached, otherwise of the final tally.
+    pub yes_share: Option<f64>,
+    pub no_share: Option<f64>,
+    pub abstain_share: Option<f64>,
+    pub no_with_veto_share: Option<f64>,
+    pub total_votes: Option<f64>,
+    pub spam_likelihood: Option<f64>,
+    pub text: String,
+}
+
+pub const FEATURE_NAMES: &[&str] = &[
+    "deposit_ratio",
+    "url_count",
+    "domain_count",
+    "claim_keyword_count",
+    "airdrop_keyword_count",
+    "message_count",
+    "is_text_proposal",
+    "title_length",
+    "description_length",
+    "proposer_account_number",
+    "proposer_sequence",
+    "proposer_balance",
+    "yes_share",
+    "no_share",
+    "abstain_share",
+    "no_with_veto_share",
+    "total_votes",
+    "spam_likelihood",
+];
+
+/// Occurrences of a lower case `keyword` at the start of a word, so that `claim` does not match `disclaimer`.
+pub fn keyword_count(lower: &str, keyword: &str) -> usize {
+    lower
+        .match_indices(keyword)
+        .filter(|(index, _)| {
+            !lower[..*index].chars().next_back().map(|c| c.is_alphanumeric()).unwrap_or(false)
+        })
+        .count()
+}
+
+fn count_keywords(lower: &str, keywords: &[&str]) -> usize {
+    keywords.iter().map(|keyword| keyword_count(lower, keyword)).sum()
+}
+
+impl ProposalFeatures {
+    pub fn extract(proposal: &ProposalExt, proposer: Option<&ProposerInfo>) -> Self {
+        let title = proposal.get_title();
+        let description = proposal.get_description();
+        let text = format!("{}\n\n{}", title, description);
+        let lower = text.to_lowercase();
+
+        let urls = extract_urls(&text);
+        let mut domains: Vec<String> = urls.iter().filter_map(|url| domain_of(url)).collect();
+        domains.sort();
+        domains.dedup();
+
+        let contents = proposal.messages_as_proposal_content();
+        let is_text_proposal = contents
+            .iter()
+            .all(|content| matches!(content, ProposalContent::TextProposal(_)));
+
+        let min_deposit_denom = proposal
+            .params
+            .as_ref()
+            .and_then(|params| params.gov_params.as_ref())
+            .and_then(|gov_params| gov_params.min_deposit.first())
+            .map(|coin| coin.denom.to_owned());
+        let proposer_balance = proposer.map(|proposer| {
+            let coin = match &min_deposit_denom {
+                Some(denom) => proposer.balance.iter().find(|coin| &coin.denom == denom),
+                None => proposer.balance.first(),
+            };
+            coin.map(|coin| coin.amount as f64).unwrap_or(0.0)
+        });
+
+        let tally = proposal.tally_counts();
+        let total = tally.map(|(yes, no, abstain, no_with_veto)| yes + no + abstain + no_with_veto);
+        let share = |votes: u128| -> Option<f64> {
+            match total {
+                Some(total) if total > 0 => Some(votes as f64 / total as f64),
+                _ => None,
+            }
+        };
+
+        Self {
+            deposit_ratio: proposal
+                .deposit_progress(false)
+                .and_then(|progress| progress.ok())
+                .and_then(|progress| progress.progress().to_f64()),
+            url_count: urls.len(),
+            domains,
+            claim_keyword_count: count_keywords(&lower, CLAIM_KEYWORDS),
+            airdrop_keyword_count: count_keywords(&lower, AIRDROP_KEYWORDS),
+            message_types: proposal
+                .proposal
+                .0
+                .messages
+                .iter()
+                .map(|message| message.type_url.to_owned())
+                .collect(),
+            is_text_proposal,
+            title_length: title.chars().count(),
+            description_length: description.chars().count(),
+            proposer_account_number: proposer.and_then(|proposer| proposer.account_number),
+            proposer_sequence: proposer.and_then(|proposer| proposer.sequence),
+            proposer_balance,
+            yes_share: tally.and_then(|(yes, _, _, _)| share(yes)),
+            no_share: tally.and_then(|(_, no, _, _)| share(no)),
+            abstain_share: tally.and_then(|(_, _, abstain, _)| share(abstain)),
+            no_with_veto_share: tally.and_then(|(_, _, _, no_with_veto)| share(no_with_veto)),
+            total_votes: total.map(|total| total as f64),
+            spam_likelihood: proposal.spam_likelihood(),
+            text,
+        }
+    }
+
+    pub fn to_vector(&self) -> Vec<f64> {
+        let optional = |value: Option<f64>| value.unwrap_or(f64::NAN);
+        vec![
+            optional(self.deposit_ratio),
+            self.url_count as f64,
+            self.domains.len() as f64,
+            self.claim_keyword_count as f64,
+            self.airdrop_keyword_count as f64,
+            self.message_types.len() as f64,
+            if self.is_text_proposal { 1.0 } else { 0.0 },
+            self.title_length as f64,
+            self.description_length as f64,
+            optional(self.proposer_account_number.map(|n| n as f64)),
+            optional(self.proposer_sequence.map(|n| n as f64)),
+            optional(self.proposer_balance),
+            optional(self.yes_share),
+            optional(self.no_share),
+            optional(self.abstain_share),
+            optional(self.no_with_veto_share),
+            optional(self.total_votes),
+            optional(self.spam_likelihood),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    // cargo test api::custom::types::gov::proposal_features::test::test_extract_features -- --exact --nocapture
+
+    use super::*;
+    use crate::api::core::cosmos::channels::test_blockchain;
+    use crate::api::custom::types::gov::common::ProposalStatus;
+    use cosmos_sdk_proto::traits::MessageExt;
+
+    #[test]
+    fn test_extract_features() {
+        assert_eq!(
+            extract_urls("Claim at https://osmosis-airdrop.xyz/claim. or www.example.com!"),
+            vec!["https://osmosis-airdrop.xyz/claim", "www.example.com"]
+        );
+        assert_eq!(domain_of("https://www.Osmosis.zone:443/x"), Some("osmosis.zone".to_string()));
+        assert_eq!(keyword_count("claim now, see the disclaimer. claims:", "claim"), 2);
+
+        let blockchain = test_blockchain();
+        let message = cosmos_sdk_proto::cosmos::gov::v1beta1::TextProposal {
+            title: "Airdrop for OSMO stakers".to_string(),
+            description: "Claim your airdrop at https://osmosis-airdrop.xyz".to_string(),
+        }
+        .to_any()
+        .unwrap();
+        let proposal = ProposalExt::new(
+            &blockchain,
+            cosmos_sdk_proto::cosmos::gov::v1::Proposal {
+                id: 1,
+                messages: vec![message],
+                status: ProposalStatus::StatusVotingPeriod.into(),
+                ..Default::default()
+            },
+        );
+        let features = ProposalFeatures::extract(&proposal, None);
+        assert!(features.is_text_proposal);
+        assert_eq!(features.url_count, 1);
+        assert_eq!(features.domains, vec!["osmosis-airdrop.xyz".to_string()]);
+        assert_eq!(features.airdrop_keyword_count, 3);
+        assert!(features.claim_keyword_count >= 1);
+
+        let vector = features.to_vector();
+        assert_eq!(vector.len(), FEATURE_NAMES.len());
+        assert!(vector[0].is_nan());
+    }
+}
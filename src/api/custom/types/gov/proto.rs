@@ -0,0 +1,108 @@
+// x/gov messages that are newer than the cosmos-sdk-proto version in use.
+// Unknown fields are skipped when decoding, so these also decode responses of older chains.
+
+use cosmos_sdk_proto::cosmos::base::v1beta1::Coin;
+use cosmos_sdk_proto::cosmos::gov::v1::{DepositParams, TallyParams, VotingParams};
+
+/// `/cosmos.gov.v1.Params` (cosmos-sdk v0.47+, expedited and cancel fields since v0.50)
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct GovParamsV1 {
+    #[prost(message, repeated, tag = "1")]
+    pub min_deposit: Vec<Coin>,
+    #[prost(message, optional, tag = "2")]
+    pub max_deposit_period: Option<prost_types::Duration>,
+    #[prost(message, optional, tag = "3")]
+    pub voting_period: Option<prost_types::Duration>,
+    #[prost(string, tag = "4")]
+    pub quorum: String,
+    #[prost(string, tag = "5")]
+    pub threshold: String,
+    #[prost(string, tag = "6")]
+    pub veto_threshold: String,
+    #[prost(string, tag = "7")]
+    pub min_initial_deposit_ratio: String,
+    #[prost(string, tag = "8")]
+    pub proposal_cancel_ratio: String,
+    #[prost(string, tag = "9")]
+    pub proposal_cancel_dest: String,
+    #[prost(message, optional, tag = "10")]
+    pub expedited_voting_period: Option<prost_types::Duration>,
+    #[prost(string, tag = "11")]
+    pub expedited_threshold: String,
+    #[prost(message, repeated, tag = "12")]
+    pub expedited_min_deposit: Vec<Coin>,
+    #[prost(bool, tag = "13")]
+    pub burn_vote_quorum: bool,
+    #[prost(bool, tag = "14")]
+    pub burn_proposal_deposit_prevote: bool,
+    #[prost(bool, tag = "15")]
+    pub burn_vote_veto: bool,
+    #[prost(string, tag = "16")]
+    pub min_deposit_ratio: String,
+}
+
+/// `/cosmos.gov.v1.QueryParamsResponse` including the unified `params` (cosmos-sdk v0.47+)
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryParamsResponseV1 {
+    #[prost(message, optional, tag = "1")]
+    pub voting_params: Option<VotingParams>,
+    #[prost(message, optional, tag = "2")]
+    pub deposit_params: Option<DepositParams>,
+    #[prost(message, optional, tag = "3")]
+    pub tally_params: Option<TallyParams>,
+    #[prost(message, optional, tag = "4")]
+    pub params: Option<GovParamsV1>,
+}
+
+/// `/cosmos.gov.v1.MsgSubmitProposal` (title and summary since cosmos-sdk v0.47, expedited since v0.50)
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgSubmitProposalV1 {
+    #[prost(message, repeated, tag = "1")]
+    pub messages: Vec<cosmos_sdk_proto::Any>,
+    #[prost(message, repeated, tag = "2")]
+    pub initial_deposit: Vec<Coin>,
+    #[prost(string, tag = "3")]
+    pub proposer: String,
+    #[prost(string, tag = "4")]
+    pub metadata: String,
+    #[prost(string, tag = "5")]
+    pub title: String,
+    #[prost(string, tag = "6")]
+    pub summary: String,
+    #[prost(bool, tag = "7")]
+    pub expedited: bool,
+}
+
+/// `/cosmos.gov.v1.MsgExecLegacyContent`, executes a v1beta1 proposal content on gov v1 chains.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgExecLegacyContent {
+    #[prost(message, optional, tag = "1")]
+    pub content: Option<cosmos_sdk_proto::Any>,
+    #[prost(string, tag = "2")]
+    pub authority: String,
+}
+
+/// A message reduced to the string field holding its signer (e.g. `authority`).
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgSignerTag1 {
+    #[prost(string, tag = "1")]
+    pub signer: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgSignerTag2 {
+    #[prost(string, tag = "2")]
+    pub signer: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgSignerTag3 {
+    #[prost(string, tag = "3")]
+    pub signer: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgSignerTag6 {
+    #[prost(string, tag = "6")]
+    pub signer: String,
+}
@@ -7,7 +7,13 @@ use crate::api::custom::types::ProtoMessageWrapper;
 use cosmos_sdk_proto::cosmos::gov::v1::{QueryTallyResultResponse, TallyResult};
 use cosmos_sdk_proto::cosmos::gov::v1beta1::{QueryTallyResultResponse as QueryTallyResultResponseV1Beta1};
 
-use num_format::{Locale, ToFormattedString};
+use crate::api::custom::types::gov::tally_projection::{TallyProjection, TallyThresholds};
+use crate::api::custom::types::staking::pool_ext::PoolExt;
+use crate::api::custom::types::decimal_ext::{parse_u128, ratio, to_percentage};
+
+use crate::api::custom::i18n::{Localization, Message};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Hash)]
 pub struct TallyResultExt {
@@ -54,27 +60,52 @@ impl TallyResultExt {
             None
         }
     }
-    pub fn total_votes(&self) -> Option<f64> {
+    pub fn total_votes(&self) -> Option<u128> {
         if let Some(tally) = &self.tally_result.0.tally {
             TallyHelper(tally).total_votes()
         } else {
             None
         }
     }
-    pub fn tally_details(&self) -> String {
+    pub fn tally_details(&self) -> anyhow::Result<String> {
+        self.tally_details_localized(&Localization::default())
+    }
+    pub fn tally_details_localized(&self, localization: &Localization) -> anyhow::Result<String> {
         let mut output = String::new();
         if let Some(tally) = &self.tally_result.0.tally {
-            output.push_str(&TallyHelper(tally).tally_details());
+            output.push_str(&TallyHelper(tally).tally_details_localized(localization)?);
         }
-        output
+        Ok(output)
     }
     pub fn current_tally(&self) -> String {
+        self.current_tally_localized(&Localization::default())
+    }
+    pub fn current_tally_localized(&self, localization: &Localization) -> String {
         let mut output = String::new();
         if let Some(tally) = &self.tally_result.0.tally {
-            output.push_str(&TallyHelper(tally).current_tally_to_string());
+            output.push_str(&TallyHelper(tally).current_tally_to_string_localized(localization));
         }
         output
     }
+    pub fn projection(
+        &self,
+        pool: &PoolExt,
+        thresholds: TallyThresholds,
+        expedited: bool,
+    ) -> anyhow::Result<TallyProjection> {
+        let tally = self
+            .tally_result
+            .0
+            .tally
+            .as_ref()
+            .ok_or(anyhow::anyhow!("Error: Tally result is empty."))?;
+        Ok(TallyProjection::new(
+            TallyHelper(tally).tally_counts()?,
+            pool.bonded_tokens()?,
+            thresholds,
+            expedited,
+        ))
+    }
 }
 
 impl fmt::Display for TallyResultExt {
@@ -91,87 +122,93 @@ pub struct TallyHelper<'a>(pub &'a TallyResult);
 
 impl<'a> TallyHelper<'a> {
     pub fn final_tally_to_string(&self) -> String {
-        if let Some(tally) = self.tally_to_string() {
-            format!("🗳 Final tally result:\n{}", tally)
+        self.final_tally_to_string_localized(&Localization::default())
+    }
+    pub fn final_tally_to_string_localized(&self, localization: &Localization) -> String {
+        if let Some(tally) = self.tally_to_string(localization) {
+            format!("🗳 {}:\n{}", localization.text(Message::FinalTallyResult), tally)
         } else {
             "".to_string()
         }
     }
     pub fn current_tally_to_string(&self) -> String {
-        if let Some(tally) = self.tally_to_string() {
-            format!("🗳 Current voting results:\n{}", tally)
+        self.current_tally_to_string_localized(&Localization::default())
+    }
+    pub fn current_tally_to_string_localized(&self, localization: &Localization) -> String {
+        if let Some(tally) = self.tally_to_string(localization) {
+            format!("🗳 {}:\n{}", localization.text(Message::CurrentVotingResults), tally)
         } else {
             "".to_string()
         }
     }
-    pub fn tally_details(&self) -> String {
+    // (yes, no, abstain, no_with_veto)
+    pub fn tally_counts(&self) -> anyhow::Result<(u128, u128, u128, u128)> {
         let tally = self.0;
-        let abstain_num = tally.abstain_count.parse::<u128>().unwrap();
-        let yes_num = tally.yes_count.parse::<u128>().unwrap();
-        let no_num = tally.no_count.parse::<u128>().unwrap();
-        let no_with_veto_num = tally.no_with_veto_count.parse::<u128>().unwrap();
+        Ok((
+            parse_u128(&tally.yes_count)?,
+            parse_u128(&tally.no_count)?,
+            parse_u128(&tally.abstain_count)?,
+            parse_u128(&tally.no_with_veto_count)?,
+        ))
+    }
+    pub fn tally_details(&self) -> anyhow::Result<String> {
+        self.tally_details_localized(&Localization::default())
+    }
+    pub fn tally_details_localized(&self, localization: &Localization) -> anyhow::Result<String> {
+        let (yes_num, no_num, abstain_num, no_with_veto_num) = self.tally_counts()?;
         let total = abstain_num + yes_num + no_num + no_with_veto_num;
-        format!("\nYes Votes: \n{}\n\nNo Votes: \n{}\n\nAbstain Votes: \n{}\n\nNoWithVeto Votes: \n{}\n\n\nTotal Votes: \n{}\n",
-            yes_num.to_formatted_string(&Locale::en),
-            no_num.to_formatted_string(&Locale::en),
-            abstain_num.to_formatted_string(&Locale::en),
-            no_with_veto_num.to_formatted_string(&Locale::en),
-            total.to_formatted_string(&Locale::en),
-        )
-    }
-    fn tally_to_string(&self) -> Option<String> {
-        let mut output = None;
-        let tally = self.0;
-        if tally.yes_count != "0" || tally.abstain_count != "0" || tally.no_count != "0" || tally.no_with_veto_count != "0"
-        {
-            let abstain_num = tally.abstain_count.parse::<f64>().unwrap();
-            let yes_num = tally.yes_count.parse::<f64>().unwrap();
-            let no_num = tally.no_count.parse::<f64>().unwrap();
-            let no_with_veto_num = tally.no_with_veto_count.parse::<f64>().unwrap();
-            let total = (abstain_num + yes_num + no_num + no_with_veto_num) as f64;
-            let abstain_num = f64::trunc(abstain_num / total * 100.0 * 100.0) / 100.0;
-            let yes_num = f64::trunc(yes_num / total * 100.0 * 100.0) / 100.0;
-            let no_num = f64::trunc(no_num / total * 100.0 * 100.0) / 100.0;
-            let no_with_veto_num = f64::trunc(no_with_veto_num / total * 100.0 * 100.0) / 100.0;
-            output = Some(format!(
-                r#"👍 {}%, 👎 {}%, 🕊️ {}%, ❌ {}% "#,
-                yes_num, no_num, abstain_num, no_with_veto_num
-            ));
+        Ok(format!("\n{}: \n{}\n\n{}: \n{}\n\n{}: \n{}\n\n{}: \n{}\n\n\n{}: \n{}\n",
+            localization.text(Message::YesVotes),
+            localization.format_number(yes_num),
+            localization.text(Message::NoVotes),
+            localization.format_number(no_num),
+            localization.text(Message::AbstainVotes),
+            localization.format_number(abstain_num),
+            localization.text(Message::NoWithVetoVotes),
+            localization.format_number(no_with_veto_num),
+            localization.text(Message::TotalVotes),
+            localization.format_number(total),
+        ))
+    }
+    // Vote shares of (yes, no, abstain, no_with_veto), `None` if nobody voted.
+    fn tally_shares(&self) -> Option<(Decimal, Decimal, Decimal, Decimal)> {
+        let (yes_num, no_num, abstain_num, no_with_veto_num) = self.tally_counts().ok()?;
+        let total = abstain_num + yes_num + no_num + no_with_veto_num;
+        if total == 0 {
+            return None;
         }
-        output
+        Some((
+            ratio(yes_num, total).ok()?,
+            ratio(no_num, total).ok()?,
+            ratio(abstain_num, total).ok()?,
+            ratio(no_with_veto_num, total).ok()?,
+        ))
+    }
+    fn tally_to_string(&self, localization: &Localization) -> Option<String> {
+        let (yes_num, no_num, abstain_num, no_with_veto_num) = self.tally_shares()?;
+        Some(format!(
+            r#"👍 {}%, 👎 {}%, 🕊️ {}%, ❌ {}% "#,
+            localization.format_decimal(to_percentage(yes_num).normalize(), None),
+            localization.format_decimal(to_percentage(no_num).normalize(), None),
+            localization.format_decimal(to_percentage(abstain_num).normalize(), None),
+            localization.format_decimal(to_percentage(no_with_veto_num).normalize(), None)
+        ))
     }
 
     pub fn spam_likelihood(&self) -> Option<f64> {
-        let tally = self.0;
-        if tally.yes_count != "0" || tally.abstain_count != "0" || tally.no_count != "0" || tally.no_with_veto_count != "0"
-        {
-            let abstain_num = tally.abstain_count.parse::<f64>().unwrap();
-            let yes_num = tally.yes_count.parse::<f64>().unwrap();
-            let no_num = tally.no_count.parse::<f64>().unwrap();
-            let no_with_veto_num = tally.no_with_veto_count.parse::<f64>().unwrap();
-            let total = abstain_num + yes_num + no_num + no_with_veto_num;
-            let abstain_num = abstain_num / total;
-            let yes_num = yes_num / total;
-            let no_num = no_num / total;
-            let no_with_veto_num = no_with_veto_num / total;
-            Some((1.0 + ((2.0 * no_with_veto_num) + no_num - yes_num - (0.5 * abstain_num))) / 3.0)
-        } else {
-            None
-        }
+        let (yes_num, no_num, abstain_num, no_with_veto_num) = self.tally_shares()?;
+        let score = (Decimal::ONE + ((Decimal::TWO * no_with_veto_num) + no_num - yes_num - (abstain_num / Decimal::TWO)))
+            / Decimal::new(3, 0);
+        score.to_f64()
     }
 
-    pub fn total_votes(&self) -> Option<f64> {
-        let tally = self.0;
-        if tally.yes_count != "0" || tally.abstain_count != "0" || tally.no_count != "0" || tally.no_with_veto_count != "0"
-        {
-            let abstain_num = tally.abstain_count.parse::<f64>().unwrap();
-            let yes_num = tally.yes_count.parse::<f64>().unwrap();
-            let no_num = tally.no_count.parse::<f64>().unwrap();
-            let no_with_veto_num = tally.no_with_veto_count.parse::<f64>().unwrap();
-            let total = abstain_num + yes_num + no_num + no_with_veto_num;
-            Some(total)
-        } else {
+    pub fn total_votes(&self) -> Option<u128> {
+        let (yes_num, no_num, abstain_num, no_with_veto_num) = self.tally_counts().ok()?;
+        let total = abstain_num + yes_num + no_num + no_with_veto_num;
+        if total == 0 {
             None
+        } else {
+            Some(total)
         }
     }
 }
@@ -0,0 +1,258 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use rust_decimal::Decimal;
+
+use crate::api::custom::i18n::{Localization, Message};
+use crate::api::custom::types::decimal_ext::{parse_sdk_dec, ratio};
+use crate::api::custom::types::gov::common::VoteOption;
+use crate::api::custom::types::gov::params_ext::TallyParamsExt;
+
+// The x/gov tally parameters as fractions (0.334 = 33.4%).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TallyThresholds {
+    pub quorum: Decimal,
+    pub threshold: Decimal,
+    pub veto_threshold: Decimal,
+    // Only set for gov v1 chains that support expedited proposals.
+    pub expedited_threshold: Option<Decimal>,
+}
+
+impl TallyThresholds {
+    pub fn from_v1beta1(tally_params: &cosmos_sdk_proto::cosmos::gov::v1beta1::TallyParams) -> anyhow::Result<Self> {
+        let tally_params_ext = TallyParamsExt(tally_params);
+        Ok(Self {
+            quorum: tally_params_ext.get_quorum()?,
+            threshold: tally_params_ext.get_threshold()?,
+            veto_threshold: tally_params_ext.get_veto_threshold()?,
+            expedited_threshold: None,
+        })
+    }
+
+    // gov v1 encodes the decimals as strings, e.g. "0.334000000000000000".
+    pub fn from_v1(tally_params: &cosmos_sdk_proto::cosmos::gov::v1::TallyParams) -> anyhow::Result<Self> {
+        Ok(Self {
+            quorum: parse_sdk_dec(&tally_params.quorum)?,
+            threshold: parse_sdk_dec(&tally_params.threshold)?,
+            veto_threshold: parse_sdk_dec(&tally_params.veto_threshold)?,
+            expedited_threshold: None,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, strum_macros::Display, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TallyVerdict {
+    NoQuorum,
+    Pass,
+    Reject,
+    Veto,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct FlipRequirement {
+    pub option: VoteOption,
+    // Additional tokens voting for `option` needed to change the verdict,
+    // `None` if the not yet voted bonded tokens are not enough.
+    pub tokens: Option<u128>,
+    pub outcome: Option<TallyVerdict>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TallyProjection {
+    pub yes: u128,
+    pub no: u128,
+    pub abstain: u128,
+    pub no_with_veto: u128,
+    pub bonded_tokens: u128,
+    pub thresholds: TallyThresholds,
+    pub expedited: bool,
+    pub quorum_reached: bool,
+    pub verdict: TallyVerdict,
+    pub flip_requirements: Vec<FlipRequirement>,
+}
+
+impl TallyProjection {
+    /// Projects the outcome of a proposal if voting ended right now, following the x/gov `Tally` logic.
+    pub fn new(
+        (yes, no, abstain, no_with_veto): (u128, u128, u128, u128),
+        bonded_tokens: u128,
+        thresholds: TallyThresholds,
+        expedited: bool,
+    ) -> Self {
+        let mut projection = Self {
+            yes,
+            no,
+            abstain,
+            no_with_veto,
+            bonded_tokens,
+            thresholds,
+            expedited,
+            quorum_reached: false,
+            verdict: TallyVerdict::NoQuorum,
+            flip_requirements: Vec::new(),
+        };
+        projection.verdict = projection.verdict_for(yes, no, abstain, no_with_veto);
+        projection.quorum_reached = projection.verdict != TallyVerdict::NoQuorum;
+        projection.flip_requirements = [VoteOption::Yes, VoteOption::No, VoteOption::Abstain, VoteOption::NoWithVeto]
+            .iter()
+            .map(|option| projection.flip_requirement(*option))
+            .collect();
+        projection
+    }
+
+    fn threshold(&self) -> Decimal {
+        if self.expedited {
+            self.thresholds.expedited_threshold.unwrap_or(self.thresholds.threshold)
+        } else {
+            self.thresholds.threshold
+        }
+    }
+
+    fn verdict_for(&self, yes: u128, no: u128, abstain: u128, no_with_veto: u128) -> TallyVerdict {
+        let total = yes + no + abstain + no_with_veto;
+        if self.bonded_tokens == 0 || total == 0 {
+            return TallyVerdict::NoQuorum;
+        }
+        match ratio(total, self.bonded_tokens) {
+            Ok(turnout) if turnout >= self.thresholds.quorum => {}
+            _ => return TallyVerdict::NoQuorum,
+        }
+        let non_abstain = total - abstain;
+        if non_abstain == 0 {
+            return TallyVerdict::Reject;
+        }
+        if matches!(ratio(no_with_veto, total), Ok(veto) if veto > self.thresholds.veto_threshold) {
+            return TallyVerdict::Veto;
+        }
+        if matches!(ratio(yes, non_abstain), Ok(yes) if yes > self.threshold()) {
+            return TallyVerdict::Pass;
+        }
+        TallyVerdict::Reject
+    }
+
+    fn verdict_with_additional(&self, option: VoteOption, tokens: u128) -> TallyVerdict {
+        let (mut yes, mut no, mut abstain, mut no_with_veto) = (self.yes, self.no, self.abstain, self.no_with_veto);
+        match option {
+            VoteOption::Yes => yes += tokens,
+            VoteOption::No => no += tokens,
+            VoteOption::Abstain => abstain += tokens,
+            VoteOption::NoWithVeto => no_with_veto += tokens,
+        }
+        self.verdict_for(yes, no, abstain, no_with_veto)
+    }
+
+    // Adding votes to one option changes the verdict at most once in the same direction,
+    // so the smallest amount can be found with a binary search over the not yet voted tokens.
+    fn flip_requirement(&self, option: VoteOption) -> FlipRequirement {
+        let total = self.yes + self.no + self.abstain + self.no_with_veto;
+        let not_voted = self.bonded_tokens.saturating_sub(total);
+        if self.verdict_with_additional(option, not_voted) == self.verdict {
+            return FlipRequirement { option, tokens: None, outcome: None };
+        }
+        let (mut low, mut high) = (0u128, not_voted);
+        while low + 1 < high {
+            let mid = low + (high - low) / 2;
+            if self.verdict_with_additional(option, mid) == self.verdict {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        FlipRequirement {
+            option,
+            tokens: Some(high),
+            outcome: Some(self.verdict_with_additional(option, high)),
+        }
+    }
+
+    pub fn turnout(&self) -> Option<Decimal> {
+        ratio(self.yes + self.no + self.abstain + self.no_with_veto, self.bonded_tokens).ok()
+    }
+}
+
+impl TallyProjection {
+    pub fn to_string_localized(&self, localization: &Localization) -> String {
+        let mut output = localization.format(Message::ProjectedOutcome, &[&self.verdict.to_string()]);
+        output.push('\n');
+        output.push_str(&localization.format(
+            Message::QuorumProgress,
+            &[
+                &localization.text(if self.quorum_reached {
+                    Message::QuorumReached
+                } else {
+                    Message::QuorumNotReached
+                }),
+                &localization.format_percentage(self.turnout().unwrap_or(Decimal::ZERO)),
+                &localization.format_percentage(self.thresholds.quorum),
+            ],
+        ));
+        for requirement in self.flip_requirements.iter() {
+            if let (Some(tokens), Some(outcome)) = (requirement.tokens, requirement.outcome) {
+                output.push('\n');
+                output.push_str(&localization.format(
+                    Message::FlipRequirement,
+                    &[
+                        &requirement.option.to_string(),
+                        &localization.format_number(tokens),
+                        &outcome.to_string(),
+                    ],
+                ));
+            }
+        }
+        output
+    }
+}
+
+impl fmt::Display for TallyProjection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_string_localized(&Localization::default()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    // cargo test api::custom::types::gov::tally_projection::test::test_tally_projection -- --exact --nocapture
+
+    use super::*;
+    use crate::api::custom::i18n::Language;
+
+    fn thresholds() -> TallyThresholds {
+        TallyThresholds {
+            quorum: Decimal::new(4, 1),
+            threshold: Decimal::new(5, 1),
+            veto_threshold: Decimal::new(334, 3),
+            expedited_threshold: Some(Decimal::new(667, 3)),
+        }
+    }
+
+    #[test]
+    fn test_tally_projection() {
+        let no_quorum = TallyProjection::new((30, 5, 0, 0), 100, thresholds(), false);
+        println!("{}", no_quorum);
+        assert_eq!(no_quorum.verdict, TallyVerdict::NoQuorum);
+        assert_eq!(no_quorum.flip_requirements[0].tokens, Some(5));
+        assert_eq!(no_quorum.flip_requirements[0].outcome, Some(TallyVerdict::Pass));
+
+        let pass = TallyProjection::new((30, 20, 0, 0), 100, thresholds(), false);
+        assert_eq!(pass.verdict, TallyVerdict::Pass);
+        // 30 / (50 + 10) is no longer above 50%
+        assert_eq!(pass.flip_requirements[1].tokens, Some(10));
+        assert_eq!(pass.flip_requirements[1].outcome, Some(TallyVerdict::Reject));
+        assert_eq!(pass.flip_requirements[2].tokens, None);
+
+        let expedited = TallyProjection::new((30, 20, 0, 0), 100, thresholds(), true);
+        assert_eq!(expedited.verdict, TallyVerdict::Reject);
+
+        let veto = TallyProjection::new((20, 10, 0, 20), 100, thresholds(), false);
+        assert_eq!(veto.verdict, TallyVerdict::Veto);
+        assert!(veto.quorum_reached);
+
+        let large = TallyProjection::new((30_000, 20_000, 0, 0), 100_000, thresholds(), false);
+        assert!(large.to_string().contains("needs 10,000 more tokens"));
+        assert!(large.to_string().contains("Quorum: reached (50.00% of 40.00%)"));
+        let german = large.to_string_localized(&Localization::new(Language::German));
+        assert!(german.contains("benötigt 10.000 weitere Token"));
+        assert!(german.contains("Quorum: erreicht (50,00% von 40,00%)"));
+    }
+}
@@ -0,0 +1,198 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use rust_decimal::Decimal;
+
+use crate::api::core::cosmos::channels::SupportedBlockchain;
+use crate::api::custom::i18n::{Localization, Message};
+use crate::api::custom::types::gov::common::VoteOption;
+use crate::api::custom::types::gov::vote_ext::VoteExt;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ValidatorVote {
+    pub moniker: String,
+    pub operator_address: String,
+    // The validator's self-delegation account, which casts the validator's vote.
+    pub account_address: String,
+    pub tokens: u128,
+    // Share of the bonded tokens of all active validators.
+    pub voting_power_share: Decimal,
+    // `Err` if the vote could not be queried, such validators are neither voters nor non-voters.
+    pub vote: Result<Option<VoteExt>, String>,
+}
+
+impl ValidatorVote {
+    pub fn has_voted(&self) -> bool {
+        matches!(self.vote, Ok(Some(_)))
+    }
+
+    pub fn has_not_voted(&self) -> bool {
+        matches!(self.vote, Ok(None))
+    }
+
+    pub fn is_unknown(&self) -> bool {
+        self.vote.is_err()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ValidatorVotesReport {
+    pub blockchain: SupportedBlockchain,
+    pub proposal_id: u64,
+    // Active validators sorted by voting power, largest first.
+    pub validators: Vec<ValidatorVote>,
+}
+
+impl ValidatorVotesReport {
+    pub fn new(blockchain: SupportedBlockchain, proposal_id: u64, mut validators: Vec<ValidatorVote>) -> Self {
+        validators.sort_by(|a, b| b.tokens.cmp(&a.tokens));
+        Self {
+            blockchain,
+            proposal_id,
+            validators,
+        }
+    }
+
+    pub fn voters(&self) -> Vec<&ValidatorVote> {
+        self.validators.iter().filter(|validator| validator.has_voted()).collect()
+    }
+
+    pub fn non_voters(&self) -> Vec<&ValidatorVote> {
+        self.validators.iter().filter(|validator| validator.has_not_voted()).collect()
+    }
+
+    // Validators whose vote could not be queried.
+    pub fn unknown(&self) -> Vec<&ValidatorVote> {
+        self.validators.iter().filter(|validator| validator.is_unknown()).collect()
+    }
+
+    // Voting power share of the validators that voted.
+    pub fn voted_share(&self) -> Decimal {
+        self.voters().iter().map(|validator| validator.voting_power_share).sum()
+    }
+
+    // Voting power share behind `option`, weighted votes are split by their weights.
+    pub fn option_share(&self, option: VoteOption) -> Decimal {
+        self.validators
+            .iter()
+            .filter_map(|validator| {
+                validator
+                    .vote
+                    .as_ref()
+                    .ok()?
+                    .as_ref()
+                    .map(|vote| validator.voting_power_share * vote.weight_of(option))
+            })
+            .sum()
+    }
+}
+
+impl ValidatorVotesReport {
+    pub fn to_string_localized(&self, localization: &Localization) -> String {
+        let mut output = localization.format(
+            Message::ValidatorsVoted,
+            &[
+                &self.voters().len().to_string(),
+                &self.validators.len().to_string(),
+                &localization.format_percentage(self.voted_share()),
+            ],
+        );
+        for option in [VoteOption::Yes, VoteOption::No, VoteOption::Abstain, VoteOption::NoWithVeto] {
+            output.push_str(&format!(
+                "\n{}: {}%",
+                option,
+                localization.format_percentage(self.option_share(option))
+            ));
+        }
+        for validator in self.voters() {
+            output.push_str(&format!(
+                "\n{} ({}%): {}",
+                validator.moniker,
+                localization.format_percentage(validator.voting_power_share),
+                validator
+                    .vote
+                    .as_ref()
+                    .ok()
+                    .and_then(|vote| vote.as_ref())
+                    .map(|vote| vote.options_to_string())
+                    .unwrap_or_default()
+            ));
+        }
+        let non_voters = self.non_voters();
+        if !non_voters.is_empty() {
+            output.push_str(&format!("\n\n{}", localization.text(Message::NotVotedYet)));
+            for validator in non_voters {
+                output.push('\n');
+                output.push_str(&localization.format(
+                    Message::ValidatorTokens,
+                    &[
+                        &validator.moniker,
+                        &localization.format_percentage(validator.voting_power_share),
+                        &localization.format_number(validator.tokens),
+                    ],
+                ));
+            }
+        }
+        let unknown = self.unknown();
+        if !unknown.is_empty() {
+            output.push_str(&format!("\n\n{}", localization.text(Message::VoteUnknown)));
+            for validator in unknown {
+                output.push_str(&format!(
+                    "\n{} ({}%)",
+                    validator.moniker,
+                    localization.format_percentage(validator.voting_power_share)
+                ));
+            }
+        }
+        output
+    }
+}
+
+impl fmt::Display for ValidatorVotesReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_string_localized(&Localization::default()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    // cargo test api::custom::types::gov::validator_votes_ext::test::test_unknown_votes -- --exact --nocapture
+
+    use super::*;
+    use crate::api::core::cosmos::channels::test_blockchain;
+    use crate::api::custom::i18n::Language;
+
+    fn validator(moniker: &str, tokens: u128, vote: Result<Option<VoteExt>, String>) -> ValidatorVote {
+        ValidatorVote {
+            moniker: moniker.to_string(),
+            operator_address: format!("osmovaloper1{}", moniker),
+            account_address: format!("osmo1{}", moniker),
+            tokens,
+            voting_power_share: Decimal::new(tokens as i64, 2),
+            vote,
+        }
+    }
+
+    #[test]
+    fn test_unknown_votes() {
+        let report = ValidatorVotesReport::new(
+            test_blockchain(),
+            1,
+            vec![
+                validator("silent", 40, Ok(None)),
+                validator("unreachable", 60, Err("status: Unavailable".to_string())),
+            ],
+        );
+        println!("{}", report);
+        assert!(report.voters().is_empty());
+        assert_eq!(report.non_voters().len(), 1);
+        assert_eq!(report.non_voters()[0].moniker, "silent");
+        assert_eq!(report.unknown().len(), 1);
+        assert_eq!(report.unknown()[0].moniker, "unreachable");
+        assert!(report.to_string().contains("Vote unknown (query failed):\nunreachable (60.00%)"));
+        let german = report.to_string_localized(&Localization::new(Language::German));
+        assert!(german.contains("Noch nicht abgestimmt:\nsilent (40,00%, 40 Token)"));
+        assert!(german.contains("Stimme unbekannt (Abfrage fehlgeschlagen):\nunreachable (60,00%)"));
+    }
+}
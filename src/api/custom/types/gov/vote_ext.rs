@@ -0,0 +1,152 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use rust_decimal::Decimal;
+
+use crate::api::core::cosmos::channels::SupportedBlockchain;
+use crate::api::custom::types::decimal_ext::{parse_sdk_dec, to_percentage};
+use crate::api::custom::types::gov::common::VoteOption;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WeightedVoteOptionExt {
+    pub option: VoteOption,
+    // Share of the voting power, all options of a vote add up to 1.
+    pub weight: Decimal,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct VoteExt {
+    pub blockchain: SupportedBlockchain,
+    pub proposal_id: u64,
+    pub voter: String,
+    pub options: Vec<WeightedVoteOptionExt>,
+}
+
+impl VoteExt {
+    /// Merges duplicate options, drops unspecified ones and rescales the weights so they sum up to 1.
+    fn normalize(raw_options: Vec<(i32, &str)>) -> anyhow::Result<Vec<WeightedVoteOptionExt>> {
+        let mut options: Vec<WeightedVoteOptionExt> = Vec::new();
+        for (option, weight) in raw_options {
+            let Some(option) = VoteOption::from_i32(option) else {
+                continue;
+            };
+            let weight = parse_sdk_dec(weight)?;
+            match options.iter_mut().find(|existing| existing.option == option) {
+                Some(existing) => existing.weight += weight,
+                None => options.push(WeightedVoteOptionExt { option, weight }),
+            }
+        }
+        let total: Decimal = options.iter().map(|option| option.weight).sum();
+        if total.is_zero() {
+            return Err(anyhow::anyhow!("Error: Vote has no weighted options."));
+        }
+        for option in options.iter_mut() {
+            option.weight /= total;
+        }
+        options.sort_by(|a, b| b.weight.cmp(&a.weight));
+        Ok(options)
+    }
+
+    pub fn new(
+        blockchain: &SupportedBlockchain,
+        vote: cosmos_sdk_proto::cosmos::gov::v1::Vote,
+    ) -> anyhow::Result<Self> {
+        Ok(Self {
+            blockchain: blockchain.clone(),
+            proposal_id: vote.proposal_id,
+            options: Self::normalize(
+                vote.options
+                    .iter()
+                    .map(|option| (option.option, option.weight.as_str()))
+                    .collect(),
+            )?,
+            voter: vote.voter,
+        })
+    }
+
+    pub fn from_v1beta1(
+        blockchain: &SupportedBlockchain,
+        vote: cosmos_sdk_proto::cosmos::gov::v1beta1::Vote,
+    ) -> anyhow::Result<Self> {
+        // Votes cast before weighted voting only set the deprecated `option` field.
+        #[allow(deprecated)]
+        let raw_options = if vote.options.is_empty() {
+            vec![(vote.option, "1.0")]
+        } else {
+            vote.options
+                .iter()
+                .map(|option| (option.option, option.weight.as_str()))
+                .collect()
+        };
+        Ok(Self {
+            blockchain: blockchain.clone(),
+            proposal_id: vote.proposal_id,
+            options: Self::normalize(raw_options)?,
+            voter: vote.voter,
+        })
+    }
+
+    pub fn is_weighted(&self) -> bool {
+        self.options.len() > 1
+    }
+
+    // The option with the largest weight.
+    pub fn primary_option(&self) -> Option<VoteOption> {
+        self.options.first().map(|option| option.option)
+    }
+
+    // e.g. "Yes" or "Yes 70%, No 30%" for weighted votes.
+    pub fn options_to_string(&self) -> String {
+        if self.is_weighted() {
+            self.options
+                .iter()
+                .map(|weighted| format!("{} {}%", weighted.option, to_percentage(weighted.weight).normalize()))
+                .collect::<Vec<_>>()
+                .join(", ")
+        } else {
+            self.primary_option().map(|option| option.to_string()).unwrap_or_default()
+        }
+    }
+
+    pub fn weight_of(&self, option: VoteOption) -> Decimal {
+        self.options
+            .iter()
+            .find(|weighted| weighted.option == option)
+            .map(|weighted| weighted.weight)
+            .unwrap_or(Decimal::ZERO)
+    }
+}
+
+impl fmt::Display for VoteExt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} voted {}", self.voter, self.options_to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    // cargo test api::custom::types::gov::vote_ext::test::test_normalize -- --exact --nocapture
+
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        // Duplicates are merged, unspecified options dropped and weights rescaled to sum up to 1.
+        let options = VoteExt::normalize(vec![(1, "0.3"), (3, "0.3"), (1, "0.2"), (0, "1.0")]).unwrap();
+        assert_eq!(
+            options,
+            vec![
+                WeightedVoteOptionExt { option: VoteOption::Yes, weight: Decimal::new(625, 3) },
+                WeightedVoteOptionExt { option: VoteOption::No, weight: Decimal::new(375, 3) },
+            ]
+        );
+
+        // sdk.Dec atomics without a decimal point.
+        let options = VoteExt::normalize(vec![(2, "500000000000000000"), (4, "500000000000000000")]).unwrap();
+        assert_eq!(options.iter().map(|option| option.weight).sum::<Decimal>(), Decimal::ONE);
+
+        assert!(VoteExt::normalize(vec![(1, "0")]).is_err());
+        assert!(VoteExt::normalize(vec![(1, "x")]).is_err());
+    }
+}
@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::hash::Hash;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IbcClientInfo {
+    pub role: String,
+    pub client_id: String,
+    pub chain_id: Option<String>,
+    pub status: Option<String>,
+}
+
+impl IbcClientInfo {
+    pub fn new(role: &str, client_id: &str) -> Self {
+        Self {
+            role: role.to_string(),
+            client_id: client_id.to_string(),
+            chain_id: None,
+            status: None,
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.status.as_deref() == Some("Active")
+    }
+}
+
+impl fmt::Display for IbcClientInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} client: {}\n- Chain ID: {}\n- Status: {}",
+            self.role,
+            self.client_id,
+            self.chain_id.as_deref().unwrap_or("unknown"),
+            self.status.as_deref().unwrap_or("unknown")
+        )
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IbcClientsExt {
+    pub clients: Vec<IbcClientInfo>,
+}
+
+impl IbcClientsExt {
+    // A client recovery only makes sense if both clients track the same chain.
+    pub fn chain_ids_match(&self) -> Option<bool> {
+        let chain_ids = self
+            .clients
+            .iter()
+            .map(|client| client.chain_id.as_ref())
+            .collect::<Option<Vec<&String>>>()?;
+        Some(chain_ids.windows(2).all(|pair| pair[0] == pair[1]))
+    }
+}
+
+impl fmt::Display for IbcClientsExt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let clients = self
+            .clients
+            .iter()
+            .map(|client| client.to_string())
+            .collect::<Vec<String>>()
+            .join("\n");
+        write!(f, "{}", clients)?;
+        if self.clients.len() > 1 && self.chain_ids_match() == Some(false) {
+            write!(f, "\n\n⚠ The clients track different chains.")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,2 @@
+pub mod client_ext;
+pub mod proto;
@@ -0,0 +1,33 @@
+// ibc-go messages that are not (yet) part of cosmos-sdk-proto.
+// Only the fields needed to describe a proposal are declared, unknown fields are skipped when decoding.
+
+use cosmos_sdk_proto::cosmos::upgrade::v1beta1::Plan;
+
+/// `/ibc.core.client.v1.MsgRecoverClient` (ibc-go v8)
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgRecoverClient {
+    #[prost(string, tag = "1")]
+    pub subject_client_id: String,
+    #[prost(string, tag = "2")]
+    pub substitute_client_id: String,
+    #[prost(string, tag = "3")]
+    pub signer: String,
+}
+
+/// `/ibc.core.client.v1.MsgIBCSoftwareUpgrade` (ibc-go v8)
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgIbcSoftwareUpgrade {
+    #[prost(message, optional, tag = "1")]
+    pub plan: Option<Plan>,
+    #[prost(message, optional, tag = "2")]
+    pub upgraded_client_state: Option<cosmos_sdk_proto::Any>,
+    #[prost(string, tag = "3")]
+    pub signer: String,
+}
+
+/// `/ibc.lightclients.tendermint.v1.ClientState`, reduced to the chain id.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct TendermintClientState {
+    #[prost(string, tag = "1")]
+    pub chain_id: String,
+}
@@ -1,5 +1,12 @@
+pub mod decimal_ext;
 pub mod gov;
+pub mod ibc;
+pub mod proto_json;
+pub mod rpc_event_ext;
 pub mod staking;
+pub mod upgrade;
+pub mod versioned_ext;
+pub mod wasm;
 
 use cosmos_sdk_proto::prost::Message;
 use serde::{Deserialize, Serialize};
@@ -8,6 +15,7 @@ use std::hash::{Hash, Hasher};
 
 use crate::api::custom::types::gov::params_ext::ParamsExt;
 use crate::api::custom::types::gov::proposal_ext::ProposalExt;
+use crate::api::custom::types::proto_json::ProtoJson;
 use crate::api::custom::types::gov::tally_v1beta1_ext::TallyResultV1Beta1Ext;
 use crate::api::custom::types::staking::pool_ext::PoolExt;
 use crate::api::custom::types::staking::validators_ext::ValidatorsExt;
@@ -37,6 +45,21 @@ where
     }
 }
 
+// The proto3-JSON form is opt-in per field with `#[serde(with = "proto_json")]`, see `proto_json`.
+
+impl<T> ProtoMessageWrapper<T>
+where
+    T: ProtoJson,
+{
+    pub fn to_proto_json(&self) -> serde_json::Value {
+        self.0.to_proto_json()
+    }
+
+    pub fn from_proto_json(value: &serde_json::Value) -> anyhow::Result<Self> {
+        T::from_proto_json(value).map(ProtoMessageWrapper)
+    }
+}
+
 impl<T> Serialize for ProtoMessageWrapper<T>
 where
     T: Message + Default,
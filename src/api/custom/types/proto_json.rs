@@ -0,0 +1,948 @@
+// proto3-JSON mapping for the protos wrapped in `ProtoMessageWrapper`.
+//
+// Field names are printed as in the .proto files (snake_case, like the cosmos-sdk REST gateway)
+// and parsed in both snake_case and lowerCamelCase. 64 bit integers are strings, enums their names,
+// bytes base64, `Timestamp` RFC 3339 and `Duration` seconds with an "s" suffix.
+// `Any` is expanded to `{"@type": <type url>, ...fields}` for the type urls in `ANY_JSON_CODECS`,
+// other types are kept as `{"@type": <type url>, "value": <base64>}` so they still round-trip.
+// Messages decoded with osmosis-std / injective-std types (`MsgUpdateParams`, CosmWasm) use the
+// serde mapping of those crates, which follows the same conventions.
+//
+// `ProtoMessageWrapper` itself always serializes to protobuf bytes. Fields that should use
+// proto3-JSON instead opt in with `#[serde(with = "crate::api::custom::types::proto_json")]`.
+
+use std::collections::HashMap;
+
+use base64::Engine;
+use chrono::{DateTime, SecondsFormat, TimeZone, Utc};
+use prost::Message;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+use cosmos_sdk_proto::cosmos::base::v1beta1::Coin;
+use cosmos_sdk_proto::Any;
+
+use crate::api::custom::types::ProtoMessageWrapper;
+
+pub trait ProtoJson: Sized {
+    fn to_proto_json(&self) -> Value;
+    fn from_proto_json(value: &Value) -> anyhow::Result<Self>;
+}
+
+pub fn serialize<T, S>(wrapper: &ProtoMessageWrapper<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: ProtoJson,
+    S: serde::Serializer,
+{
+    serde::Serialize::serialize(&wrapper.0.to_proto_json(), serializer)
+}
+
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<ProtoMessageWrapper<T>, D::Error>
+where
+    T: ProtoJson,
+    D: serde::Deserializer<'de>,
+{
+    let value: Value = serde::Deserialize::deserialize(deserializer)?;
+    T::from_proto_json(&value)
+        .map(ProtoMessageWrapper)
+        .map_err(|e| serde::de::Error::custom(format!("Error decoding message: {}", e)))
+}
+
+fn to_camel_case(name: &str) -> String {
+    let mut camel = String::new();
+    let mut upper = false;
+    for c in name.chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            camel.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            camel.push(c);
+        }
+    }
+    camel
+}
+
+// A field by its proto name or lowerCamelCase json name, `None` if missing or null.
+fn field<'a>(value: &'a Value, name: &str) -> Option<&'a Value> {
+    value
+        .get(name)
+        .or_else(|| value.get(to_camel_case(name)))
+        .filter(|field| !field.is_null())
+}
+
+fn type_error(name: &str, expected: &str, value: &Value) -> anyhow::Error {
+    anyhow::anyhow!("Error: Field '{}' must be {}, got {}.", name, expected, value)
+}
+
+pub fn get_string(value: &Value, name: &str) -> anyhow::Result<String> {
+    match field(value, name) {
+        None => Ok(String::new()),
+        Some(Value::String(s)) => Ok(s.to_owned()),
+        Some(other) => Err(type_error(name, "a string", other)),
+    }
+}
+
+pub fn get_bool(value: &Value, name: &str) -> anyhow::Result<bool> {
+    match field(value, name) {
+        None => Ok(false),
+        Some(Value::Bool(b)) => Ok(*b),
+        Some(other) => Err(type_error(name, "a boolean", other)),
+    }
+}
+
+pub fn get_u64(value: &Value, name: &str) -> anyhow::Result<u64> {
+    match field(value, name) {
+        None => Ok(0),
+        Some(Value::String(s)) => Ok(s.parse()?),
+        Some(Value::Number(n)) => n.as_u64().ok_or(type_error(name, "an unsigned integer", &Value::Number(n.clone()))),
+        Some(other) => Err(type_error(name, "an unsigned integer", other)),
+    }
+}
+
+pub fn get_i64(value: &Value, name: &str) -> anyhow::Result<i64> {
+    match field(value, name) {
+        None => Ok(0),
+        Some(Value::String(s)) => Ok(s.parse()?),
+        Some(Value::Number(n)) => n.as_i64().ok_or(type_error(name, "an integer", &Value::Number(n.clone()))),
+        Some(other) => Err(type_error(name, "an integer", other)),
+    }
+}
+
+pub fn get_bytes(value: &Value, name: &str) -> anyhow::Result<Vec<u8>> {
+    match field(value, name) {
+        None => Ok(Vec::new()),
+        Some(Value::String(s)) => Ok(base64::engine::general_purpose::STANDARD.decode(s)?),
+        Some(other) => Err(type_error(name, "a base64 string", other)),
+    }
+}
+
+// Enums are printed by name, parsing also accepts the number.
+pub fn get_enum(value: &Value, name: &str, from_str_name: fn(&str) -> Option<i32>) -> anyhow::Result<i32> {
+    match field(value, name) {
+        None => Ok(0),
+        Some(Value::String(s)) => from_str_name(s).ok_or(anyhow::anyhow!("Error: Unknown value '{}' of enum field '{}'.", s, name)),
+        Some(Value::Number(n)) => n
+            .as_i64()
+            .and_then(|n| i32::try_from(n).ok())
+            .ok_or(type_error(name, "an enum value", &Value::Number(n.clone()))),
+        Some(other) => Err(type_error(name, "an enum value", other)),
+    }
+}
+
+pub fn get_message<T: ProtoJson>(value: &Value, name: &str) -> anyhow::Result<Option<T>> {
+    field(value, name).map(T::from_proto_json).transpose()
+}
+
+pub fn get_repeated<T: ProtoJson>(value: &Value, name: &str) -> anyhow::Result<Vec<T>> {
+    match field(value, name) {
+        None => Ok(Vec::new()),
+        Some(Value::Array(items)) => items.iter().map(T::from_proto_json).collect(),
+        Some(other) => Err(type_error(name, "an array", other)),
+    }
+}
+
+pub fn bytes_to_json(bytes: &[u8]) -> Value {
+    Value::String(base64::engine::general_purpose::STANDARD.encode(bytes))
+}
+
+pub fn optional_to_json<T: ProtoJson>(message: &Option<T>) -> Value {
+    message.as_ref().map(|message| message.to_proto_json()).unwrap_or(Value::Null)
+}
+
+pub fn repeated_to_json<T: ProtoJson>(messages: &[T]) -> Value {
+    Value::Array(messages.iter().map(|message| message.to_proto_json()).collect())
+}
+
+impl ProtoJson for prost_types::Timestamp {
+    fn to_proto_json(&self) -> Value {
+        match Utc.timestamp_opt(self.seconds, self.nanos as u32).single() {
+            Some(time) => Value::String(time.to_rfc3339_opts(SecondsFormat::AutoSi, true)),
+            None => Value::Null,
+        }
+    }
+
+    fn from_proto_json(value: &Value) -> anyhow::Result<Self> {
+        let text = value.as_str().ok_or(type_error("timestamp", "an RFC 3339 string", value))?;
+        let time = DateTime::parse_from_rfc3339(text)?;
+        Ok(prost_types::Timestamp {
+            seconds: time.timestamp(),
+            nanos: time.timestamp_subsec_nanos() as i32,
+        })
+    }
+}
+
+impl ProtoJson for prost_types::Duration {
+    fn to_proto_json(&self) -> Value {
+        if self.nanos == 0 {
+            Value::String(format!("{}s", self.seconds))
+        } else {
+            let nanos = format!("{:09}", self.nanos.abs());
+            let sign = if self.seconds == 0 && self.nanos < 0 { "-" } else { "" };
+            Value::String(format!("{}{}.{}s", sign, self.seconds, nanos.trim_end_matches('0')))
+        }
+    }
+
+    fn from_proto_json(value: &Value) -> anyhow::Result<Self> {
+        let text = value
+            .as_str()
+            .and_then(|text| text.strip_suffix('s'))
+            .ok_or(type_error("duration", "a string like \"1.5s\"", value))?;
+        let negative = text.starts_with('-');
+        let (seconds, fraction) = text.split_once('.').unwrap_or((text, ""));
+        let seconds: i64 = seconds.parse()?;
+        let nanos: i32 = if fraction.is_empty() {
+            0
+        } else {
+            format!("{:0<9}", fraction)[..9].parse()?
+        };
+        Ok(prost_types::Duration {
+            seconds,
+            nanos: if negative { -nanos } else { nanos },
+        })
+    }
+}
+
+impl ProtoJson for Coin {
+    fn to_proto_json(&self) -> Value {
+        json!({ "denom": self.denom, "amount": self.amount })
+    }
+
+    fn from_proto_json(value: &Value) -> anyhow::Result<Self> {
+        Ok(Coin {
+            denom: get_string(value, "denom")?,
+            amount: get_string(value, "amount")?,
+        })
+    }
+}
+
+struct AnyJsonCodec {
+    to_json: fn(&[u8]) -> anyhow::Result<Value>,
+    from_json: fn(&Value) -> anyhow::Result<Vec<u8>>,
+}
+
+fn decode_to_json<T: ProtoJson + Message + Default>(bytes: &[u8]) -> anyhow::Result<Value> {
+    Ok(T::decode(bytes)?.to_proto_json())
+}
+
+fn encode_from_json<T: ProtoJson + Message + Default>(value: &Value) -> anyhow::Result<Vec<u8>> {
+    Ok(T::from_proto_json(value)?.encode_to_vec())
+}
+
+fn codec<T: ProtoJson + Message + Default>() -> AnyJsonCodec {
+    AnyJsonCodec {
+        to_json: decode_to_json::<T>,
+        from_json: encode_from_json::<T>,
+    }
+}
+
+fn std_decode_to_json<T: osmosis_prost::Message + Default + Serialize>(bytes: &[u8]) -> anyhow::Result<Value> {
+    Ok(serde_json::to_value(T::decode(bytes)?)?)
+}
+
+fn std_encode_from_json<T: osmosis_prost::Message + DeserializeOwned>(value: &Value) -> anyhow::Result<Vec<u8>> {
+    let mut value = value.clone();
+    if let Some(object) = value.as_object_mut() {
+        object.remove("@type");
+    }
+    Ok(osmosis_prost::Message::encode_to_vec(&serde_json::from_value::<T>(value)?))
+}
+
+// For the osmosis-std / injective-std types, which are prost 0.11 messages with serde support.
+fn std_codec<T: osmosis_prost::Message + Default + Serialize + DeserializeOwned>() -> AnyJsonCodec {
+    AnyJsonCodec {
+        to_json: std_decode_to_json::<T>,
+        from_json: std_encode_from_json::<T>,
+    }
+}
+
+lazy_static::lazy_static! {
+    static ref ANY_JSON_CODECS: HashMap<&'static str, AnyJsonCodec> = {
+        use cosmos_sdk_proto::cosmos::{distribution, gov, params, upgrade, crypto};
+        use crate::api::custom::types::ibc;
+        let mut codecs = HashMap::new();
+        codecs.insert("/cosmos.gov.v1beta1.TextProposal", codec::<gov::v1beta1::TextProposal>());
+        codecs.insert("/cosmos.gov.v1.MsgExecLegacyContent", codec::<gov::v1::MsgExecLegacyContent>());
+        codecs.insert("/cosmos.distribution.v1beta1.CommunityPoolSpendProposal", codec::<distribution::v1beta1::CommunityPoolSpendProposal>());
+        codecs.insert("/cosmos.params.v1beta1.ParameterChangeProposal", codec::<params::v1beta1::ParameterChangeProposal>());
+        codecs.insert("/cosmos.upgrade.v1beta1.SoftwareUpgradeProposal", codec::<upgrade::v1beta1::SoftwareUpgradeProposal>());
+        codecs.insert("/cosmos.upgrade.v1beta1.CancelSoftwareUpgradeProposal", codec::<upgrade::v1beta1::CancelSoftwareUpgradeProposal>());
+        codecs.insert("/cosmos.upgrade.v1beta1.MsgSoftwareUpgrade", codec::<upgrade::v1beta1::MsgSoftwareUpgrade>());
+        codecs.insert("/cosmos.upgrade.v1beta1.MsgCancelUpgrade", codec::<upgrade::v1beta1::MsgCancelUpgrade>());
+        codecs.insert("/cosmos.crypto.ed25519.PubKey", codec::<crypto::ed25519::PubKey>());
+        codecs.insert("/cosmos.crypto.secp256k1.PubKey", codec::<crypto::secp256k1::PubKey>());
+        codecs.insert("/ibc.core.client.v1.MsgRecoverClient", codec::<ibc::proto::MsgRecoverClient>());
+        codecs.insert("/ibc.core.client.v1.MsgIBCSoftwareUpgrade", codec::<ibc::proto::MsgIbcSoftwareUpgrade>());
+
+        use injective_std::types::cosmos as injective;
+        codecs.insert("/cosmos.gov.v1.MsgUpdateParams", std_codec::<injective::gov::v1::MsgUpdateParams>());
+        codecs.insert("/cosmos.staking.v1beta1.MsgUpdateParams", std_codec::<injective::staking::v1beta1::MsgUpdateParams>());
+        codecs.insert("/cosmos.mint.v1beta1.MsgUpdateParams", std_codec::<injective::mint::v1beta1::MsgUpdateParams>());
+        codecs.insert("/cosmos.distribution.v1beta1.MsgUpdateParams", std_codec::<injective::distribution::v1beta1::MsgUpdateParams>());
+        codecs.insert("/cosmos.bank.v1beta1.MsgUpdateParams", std_codec::<injective::bank::v1beta1::MsgUpdateParams>());
+        codecs.insert("/cosmos.slashing.v1beta1.MsgUpdateParams", std_codec::<injective::slashing::v1beta1::MsgUpdateParams>());
+        codecs.insert("/cosmos.consensus.v1.MsgUpdateParams", std_codec::<injective::consensus::v1::MsgUpdateParams>());
+
+        use osmosis_std::types::cosmwasm::wasm::v1 as wasm;
+        codecs.insert("/cosmwasm.wasm.v1.MsgUpdateParams", std_codec::<wasm::MsgUpdateParams>());
+        codecs.insert("/cosmwasm.wasm.v1.MsgStoreCode", std_codec::<wasm::MsgStoreCode>());
+        codecs.insert("/cosmwasm.wasm.v1.MsgInstantiateContract", std_codec::<wasm::MsgInstantiateContract>());
+        codecs.insert("/cosmwasm.wasm.v1.MsgExecuteContract", std_codec::<wasm::MsgExecuteContract>());
+        codecs.insert("/cosmwasm.wasm.v1.MsgMigrateContract", std_codec::<wasm::MsgMigrateContract>());
+        codecs.insert("/cosmwasm.wasm.v1.MsgSudoContract", std_codec::<wasm::MsgSudoContract>());
+        codecs.insert("/cosmwasm.wasm.v1.MsgUpdateAdmin", std_codec::<wasm::MsgUpdateAdmin>());
+        codecs.insert("/cosmwasm.wasm.v1.MsgClearAdmin", std_codec::<wasm::MsgClearAdmin>());
+        codecs.insert("/cosmwasm.wasm.v1.MsgUpdateInstantiateConfig", std_codec::<wasm::MsgUpdateInstantiateConfig>());
+        codecs
+    };
+}
+
+impl ProtoJson for Any {
+    fn to_proto_json(&self) -> Value {
+        let mut object = Map::new();
+        object.insert("@type".to_string(), Value::String(self.type_url.to_owned()));
+        let expanded = ANY_JSON_CODECS
+            .get(self.type_url.as_str())
+            .and_then(|codec| (codec.to_json)(&self.value).ok());
+        match expanded {
+            Some(Value::Object(fields)) => object.extend(fields),
+            _ => {
+                object.insert("value".to_string(), bytes_to_json(&self.value));
+            }
+        }
+        Value::Object(object)
+    }
+
+    fn from_proto_json(value: &Value) -> anyhow::Result<Self> {
+        let type_url = value
+            .get("@type")
+            .and_then(|t| t.as_str())
+            .ok_or(anyhow::anyhow!("Error: Any is missing '@type'."))?
+            .to_string();
+        let bytes = match ANY_JSON_CODECS.get(type_url.as_str()) {
+            Some(codec) => (codec.from_json)(value)?,
+            None => get_bytes(value, "value")?,
+        };
+        Ok(Any { type_url, value: bytes })
+    }
+}
+
+mod gov_json {
+    use super::*;
+    use cosmos_sdk_proto::cosmos::gov::{v1, v1beta1};
+
+    impl ProtoJson for v1::TallyResult {
+        fn to_proto_json(&self) -> Value {
+            json!({
+                "yes_count": self.yes_count,
+                "abstain_count": self.abstain_count,
+                "no_count": self.no_count,
+                "no_with_veto_count": self.no_with_veto_count,
+            })
+        }
+
+        fn from_proto_json(value: &Value) -> anyhow::Result<Self> {
+            Ok(Self {
+                yes_count: get_string(value, "yes_count")?,
+                abstain_count: get_string(value, "abstain_count")?,
+                no_count: get_string(value, "no_count")?,
+                no_with_veto_count: get_string(value, "no_with_veto_count")?,
+            })
+        }
+    }
+
+    impl ProtoJson for v1::QueryTallyResultResponse {
+        fn to_proto_json(&self) -> Value {
+            json!({ "tally": optional_to_json(&self.tally) })
+        }
+
+        fn from_proto_json(value: &Value) -> anyhow::Result<Self> {
+            Ok(Self {
+                tally: get_message(value, "tally")?,
+            })
+        }
+    }
+
+    impl ProtoJson for v1::Proposal {
+        fn to_proto_json(&self) -> Value {
+            json!({
+                "id": self.id.to_string(),
+                "messages": repeated_to_json(&self.messages),
+                "status": v1::ProposalStatus::try_from(self.status)
+                    .map(|status| Value::String(status.as_str_name().to_string()))
+                    .unwrap_or(Value::from(self.status)),
+                "final_tally_result": optional_to_json(&self.final_tally_result),
+                "submit_time": optional_to_json(&self.submit_time),
+                "deposit_end_time": optional_to_json(&self.deposit_end_time),
+                "total_deposit": repeated_to_json(&self.total_deposit),
+                "voting_start_time": optional_to_json(&self.voting_start_time),
+                "voting_end_time": optional_to_json(&self.voting_end_time),
+                "metadata": self.metadata,
+            })
+        }
+
+        fn from_proto_json(value: &Value) -> anyhow::Result<Self> {
+            Ok(Self {
+                id: get_u64(value, "id")?,
+                messages: get_repeated(value, "messages")?,
+                status: get_enum(value, "status", |name| {
+                    v1::ProposalStatus::from_str_name(name).map(|status| status as i32)
+                })?,
+                final_tally_result: get_message(value, "final_tally_result")?,
+                submit_time: get_message(value, "submit_time")?,
+                deposit_end_time: get_message(value, "deposit_end_time")?,
+                total_deposit: get_repeated(value, "total_deposit")?,
+                voting_start_time: get_message(value, "voting_start_time")?,
+                voting_end_time: get_message(value, "voting_end_time")?,
+                metadata: get_string(value, "metadata")?,
+            })
+        }
+    }
+
+    impl ProtoJson for v1::MsgExecLegacyContent {
+        fn to_proto_json(&self) -> Value {
+            json!({ "content": optional_to_json(&self.content), "authority": self.authority })
+        }
+
+        fn from_proto_json(value: &Value) -> anyhow::Result<Self> {
+            Ok(Self {
+                content: get_message(value, "content")?,
+                authority: get_string(value, "authority")?,
+            })
+        }
+    }
+
+    impl ProtoJson for v1beta1::TallyResult {
+        fn to_proto_json(&self) -> Value {
+            json!({
+                "yes": self.yes,
+                "abstain": self.abstain,
+                "no": self.no,
+                "no_with_veto": self.no_with_veto,
+            })
+        }
+
+        fn from_proto_json(value: &Value) -> anyhow::Result<Self> {
+            Ok(Self {
+                yes: get_string(value, "yes")?,
+                abstain: get_string(value, "abstain")?,
+                no: get_string(value, "no")?,
+                no_with_veto: get_string(value, "no_with_veto")?,
+            })
+        }
+    }
+
+    impl ProtoJson for v1beta1::QueryTallyResultResponse {
+        fn to_proto_json(&self) -> Value {
+            json!({ "tally": optional_to_json(&self.tally) })
+        }
+
+        fn from_proto_json(value: &Value) -> anyhow::Result<Self> {
+            Ok(Self {
+                tally: get_message(value, "tally")?,
+            })
+        }
+    }
+
+    impl ProtoJson for v1beta1::VotingParams {
+        fn to_proto_json(&self) -> Value {
+            json!({ "voting_period": optional_to_json(&self.voting_period) })
+        }
+
+        fn from_proto_json(value: &Value) -> anyhow::Result<Self> {
+            Ok(Self {
+                voting_period: get_message(value, "voting_period")?,
+            })
+        }
+    }
+
+    impl ProtoJson for v1beta1::DepositParams {
+        fn to_proto_json(&self) -> Value {
+            json!({
+                "min_deposit": repeated_to_json(&self.min_deposit),
+                "max_deposit_period": optional_to_json(&self.max_deposit_period),
+            })
+        }
+
+        fn from_proto_json(value: &Value) -> anyhow::Result<Self> {
+            Ok(Self {
+                min_deposit: get_repeated(value, "min_deposit")?,
+                max_deposit_period: get_message(value, "max_deposit_period")?,
+            })
+        }
+    }
+
+    impl ProtoJson for v1beta1::TallyParams {
+        fn to_proto_json(&self) -> Value {
+            json!({
+                "quorum": bytes_to_json(&self.quorum),
+                "threshold": bytes_to_json(&self.threshold),
+                "veto_threshold": bytes_to_json(&self.veto_threshold),
+            })
+        }
+
+        fn from_proto_json(value: &Value) -> anyhow::Result<Self> {
+            Ok(Self {
+                quorum: get_bytes(value, "quorum")?,
+                threshold: get_bytes(value, "threshold")?,
+                veto_threshold: get_bytes(value, "veto_threshold")?,
+            })
+        }
+    }
+
+    impl ProtoJson for v1beta1::QueryParamsResponse {
+        fn to_proto_json(&self) -> Value {
+            json!({
+                "voting_params": optional_to_json(&self.voting_params),
+                "deposit_params": optional_to_json(&self.deposit_params),
+                "tally_params": optional_to_json(&self.tally_params),
+            })
+        }
+
+        fn from_proto_json(value: &Value) -> anyhow::Result<Self> {
+            Ok(Self {
+                voting_params: get_message(value, "voting_params")?,
+                deposit_params: get_message(value, "deposit_params")?,
+                tally_params: get_message(value, "tally_params")?,
+            })
+        }
+    }
+
+    impl ProtoJson for v1beta1::TextProposal {
+        fn to_proto_json(&self) -> Value {
+            json!({ "title": self.title, "description": self.description })
+        }
+
+        fn from_proto_json(value: &Value) -> anyhow::Result<Self> {
+            Ok(Self {
+                title: get_string(value, "title")?,
+                description: get_string(value, "description")?,
+            })
+        }
+    }
+}
+
+mod content_json {
+    use super::*;
+    use cosmos_sdk_proto::cosmos::crypto::{ed25519, secp256k1};
+    use cosmos_sdk_proto::cosmos::distribution::v1beta1::CommunityPoolSpendProposal;
+    use cosmos_sdk_proto::cosmos::params::v1beta1::{ParamChange, ParameterChangeProposal};
+    use cosmos_sdk_proto::cosmos::upgrade::v1beta1::{
+        CancelSoftwareUpgradeProposal, MsgCancelUpgrade, MsgSoftwareUpgrade, Plan, SoftwareUpgradeProposal,
+    };
+
+    impl ProtoJson for CommunityPoolSpendProposal {
+        fn to_proto_json(&self) -> Value {
+            json!({
+                "title": self.title,
+                "description": self.description,
+                "recipient": self.recipient,
+                "amount": repeated_to_json(&self.amount),
+            })
+        }
+
+        fn from_proto_json(value: &Value) -> anyhow::Result<Self> {
+            Ok(Self {
+                title: get_string(value, "title")?,
+                description: get_string(value, "description")?,
+                recipient: get_string(value, "recipient")?,
+                amount: get_repeated(value, "amount")?,
+            })
+        }
+    }
+
+    impl ProtoJson for ParamChange {
+        fn to_proto_json(&self) -> Value {
+            json!({ "subspace": self.subspace, "key": self.key, "value": self.value })
+        }
+
+        fn from_proto_json(value: &Value) -> anyhow::Result<Self> {
+            Ok(Self {
+                subspace: get_string(value, "subspace")?,
+                key: get_string(value, "key")?,
+                value: get_string(value, "value")?,
+            })
+        }
+    }
+
+    impl ProtoJson for ParameterChangeProposal {
+        fn to_proto_json(&self) -> Value {
+            json!({
+                "title": self.title,
+                "description": self.description,
+                "changes": repeated_to_json(&self.changes),
+            })
+        }
+
+        fn from_proto_json(value: &Value) -> anyhow::Result<Self> {
+            Ok(Self {
+                title: get_string(value, "title")?,
+                description: get_string(value, "description")?,
+                changes: get_repeated(value, "changes")?,
+            })
+        }
+    }
+
+    impl ProtoJson for Plan {
+        fn to_proto_json(&self) -> Value {
+            json!({
+                "name": self.name,
+                "time": optional_to_json(&self.time),
+                "height": self.height.to_string(),
+                "info": self.info,
+                "upgraded_client_state": optional_to_json(&self.upgraded_client_state),
+            })
+        }
+
+        fn from_proto_json(value: &Value) -> anyhow::Result<Self> {
+            Ok(Self {
+                name: get_string(value, "name")?,
+                time: get_message(value, "time")?,
+                height: get_i64(value, "height")?,
+                info: get_string(value, "info")?,
+                upgraded_client_state: get_message(value, "upgraded_client_state")?,
+            })
+        }
+    }
+
+    impl ProtoJson for SoftwareUpgradeProposal {
+        fn to_proto_json(&self) -> Value {
+            json!({
+                "title": self.title,
+                "description": self.description,
+                "plan": optional_to_json(&self.plan),
+            })
+        }
+
+        fn from_proto_json(value: &Value) -> anyhow::Result<Self> {
+            Ok(Self {
+                title: get_string(value, "title")?,
+                description: get_string(value, "description")?,
+                plan: get_message(value, "plan")?,
+            })
+        }
+    }
+
+    impl ProtoJson for CancelSoftwareUpgradeProposal {
+        fn to_proto_json(&self) -> Value {
+            json!({ "title": self.title, "description": self.description })
+        }
+
+        fn from_proto_json(value: &Value) -> anyhow::Result<Self> {
+            Ok(Self {
+                title: get_string(value, "title")?,
+                description: get_string(value, "description")?,
+            })
+        }
+    }
+
+    impl ProtoJson for MsgSoftwareUpgrade {
+        fn to_proto_json(&self) -> Value {
+            json!({ "authority": self.authority, "plan": optional_to_json(&self.plan) })
+        }
+
+        fn from_proto_json(value: &Value) -> anyhow::Result<Self> {
+            Ok(Self {
+                authority: get_string(value, "authority")?,
+                plan: get_message(value, "plan")?,
+            })
+        }
+    }
+
+    impl ProtoJson for MsgCancelUpgrade {
+        fn to_proto_json(&self) -> Value {
+            json!({ "authority": self.authority })
+        }
+
+        fn from_proto_json(value: &Value) -> anyhow::Result<Self> {
+            Ok(Self {
+                authority: get_string(value, "authority")?,
+            })
+        }
+    }
+
+    impl ProtoJson for ed25519::PubKey {
+        fn to_proto_json(&self) -> Value {
+            json!({ "key": bytes_to_json(&self.key) })
+        }
+
+        fn from_proto_json(value: &Value) -> anyhow::Result<Self> {
+            Ok(Self {
+                key: get_bytes(value, "key")?,
+            })
+        }
+    }
+
+    impl ProtoJson for secp256k1::PubKey {
+        fn to_proto_json(&self) -> Value {
+            json!({ "key": bytes_to_json(&self.key) })
+        }
+
+        fn from_proto_json(value: &Value) -> anyhow::Result<Self> {
+            Ok(Self {
+                key: get_bytes(value, "key")?,
+            })
+        }
+    }
+}
+
+mod ibc_json {
+    use super::*;
+    use crate::api::custom::types::ibc::proto::{MsgIbcSoftwareUpgrade, MsgRecoverClient};
+
+    impl ProtoJson for MsgRecoverClient {
+        fn to_proto_json(&self) -> Value {
+            json!({
+                "subject_client_id": self.subject_client_id,
+                "substitute_client_id": self.substitute_client_id,
+                "signer": self.signer,
+            })
+        }
+
+        fn from_proto_json(value: &Value) -> anyhow::Result<Self> {
+            Ok(Self {
+                subject_client_id: get_string(value, "subject_client_id")?,
+                substitute_client_id: get_string(value, "substitute_client_id")?,
+                signer: get_string(value, "signer")?,
+            })
+        }
+    }
+
+    impl ProtoJson for MsgIbcSoftwareUpgrade {
+        fn to_proto_json(&self) -> Value {
+            json!({
+                "plan": optional_to_json(&self.plan),
+                "upgraded_client_state": optional_to_json(&self.upgraded_client_state),
+                "signer": self.signer,
+            })
+        }
+
+        fn from_proto_json(value: &Value) -> anyhow::Result<Self> {
+            Ok(Self {
+                plan: get_message(value, "plan")?,
+                upgraded_client_state: get_message(value, "upgraded_client_state")?,
+                signer: get_string(value, "signer")?,
+            })
+        }
+    }
+}
+
+mod staking_json {
+    use super::*;
+    use cosmos_sdk_proto::cosmos::staking::v1beta1::{
+        BondStatus, Commission, CommissionRates, Description, Pool, QueryPoolResponse, Validator,
+    };
+
+    impl ProtoJson for Pool {
+        fn to_proto_json(&self) -> Value {
+            json!({ "not_bonded_tokens": self.not_bonded_tokens, "bonded_tokens": self.bonded_tokens })
+        }
+
+        fn from_proto_json(value: &Value) -> anyhow::Result<Self> {
+            Ok(Self {
+                not_bonded_tokens: get_string(value, "not_bonded_tokens")?,
+                bonded_tokens: get_string(value, "bonded_tokens")?,
+            })
+        }
+    }
+
+    impl ProtoJson for QueryPoolResponse {
+        fn to_proto_json(&self) -> Value {
+            json!({ "pool": optional_to_json(&self.pool) })
+        }
+
+        fn from_proto_json(value: &Value) -> anyhow::Result<Self> {
+            Ok(Self {
+                pool: get_message(value, "pool")?,
+            })
+        }
+    }
+
+    impl ProtoJson for Description {
+        fn to_proto_json(&self) -> Value {
+            json!({
+                "moniker": self.moniker,
+                "identity": self.identity,
+                "website": self.website,
+                "security_contact": self.security_contact,
+                "details": self.details,
+            })
+        }
+
+        fn from_proto_json(value: &Value) -> anyhow::Result<Self> {
+            Ok(Self {
+                moniker: get_string(value, "moniker")?,
+                identity: get_string(value, "identity")?,
+                website: get_string(value, "website")?,
+                security_contact: get_string(value, "security_contact")?,
+                details: get_string(value, "details")?,
+            })
+        }
+    }
+
+    impl ProtoJson for CommissionRates {
+        fn to_proto_json(&self) -> Value {
+            json!({
+                "rate": self.rate,
+                "max_rate": self.max_rate,
+                "max_change_rate": self.max_change_rate,
+            })
+        }
+
+        fn from_proto_json(value: &Value) -> anyhow::Result<Self> {
+            Ok(Self {
+                rate: get_string(value, "rate")?,
+                max_rate: get_string(value, "max_rate")?,
+                max_change_rate: get_string(value, "max_change_rate")?,
+            })
+        }
+    }
+
+    impl ProtoJson for Commission {
+        fn to_proto_json(&self) -> Value {
+            json!({
+                "commission_rates": optional_to_json(&self.commission_rates),
+                "update_time": optional_to_json(&self.update_time),
+            })
+        }
+
+        fn from_proto_json(value: &Value) -> anyhow::Result<Self> {
+            Ok(Self {
+                commission_rates: get_message(value, "commission_rates")?,
+                update_time: get_message(value, "update_time")?,
+            })
+        }
+    }
+
+    impl ProtoJson for Validator {
+        fn to_proto_json(&self) -> Value {
+            json!({
+                "operator_address": self.operator_address,
+                "consensus_pubkey": optional_to_json(&self.consensus_pubkey),
+                "jailed": self.jailed,
+                "status": BondStatus::try_from(self.status)
+                    .map(|status| Value::String(status.as_str_name().to_string()))
+                    .unwrap_or(Value::from(self.status)),
+                "tokens": self.tokens,
+                "delegator_shares": self.delegator_shares,
+                "description": optional_to_json(&self.description),
+                "unbonding_height": self.unbonding_height.to_string(),
+                "unbonding_time": optional_to_json(&self.unbonding_time),
+                "commission": optional_to_json(&self.commission),
+                "min_self_delegation": self.min_self_delegation,
+            })
+        }
+
+        fn from_proto_json(value: &Value) -> anyhow::Result<Self> {
+            Ok(Self {
+                operator_address: get_string(value, "operator_address")?,
+                consensus_pubkey: get_message(value, "consensus_pubkey")?,
+                jailed: get_bool(value, "jailed")?,
+                status: get_enum(value, "status", |name| BondStatus::from_str_name(name).map(|status| status as i32))?,
+                tokens: get_string(value, "tokens")?,
+                delegator_shares: get_string(value, "delegator_shares")?,
+                description: get_message(value, "description")?,
+                unbonding_height: get_i64(value, "unbonding_height")?,
+                unbonding_time: get_message(value, "unbonding_time")?,
+                commission: get_message(value, "commission")?,
+                min_self_delegation: get_string(value, "min_self_delegation")?,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    // cargo test api::custom::types::proto_json::test::test_proposal_proto_json -- --exact --nocapture
+
+    use super::*;
+    use cosmos_sdk_proto::cosmos::gov::v1::{Proposal, ProposalStatus};
+    use cosmos_sdk_proto::traits::MessageExt;
+
+    #[test]
+    fn test_proposal_proto_json() {
+        let proposal = Proposal {
+            id: 42,
+            messages: vec![
+                cosmos_sdk_proto::cosmos::gov::v1beta1::TextProposal {
+                    title: "Title".to_string(),
+                    description: "Description".to_string(),
+                }
+                .to_any()
+                .unwrap(),
+                Any {
+                    type_url: "/unknown.v1.Msg".to_string(),
+                    value: vec![1, 2, 3],
+                },
+            ],
+            status: ProposalStatus::VotingPeriod as i32,
+            submit_time: Some(prost_types::Timestamp { seconds: 1700000000, nanos: 500_000_000 }),
+            total_deposit: vec![Coin { denom: "uosmo".to_string(), amount: "100".to_string() }],
+            ..Default::default()
+        };
+        let json = proposal.to_proto_json();
+        assert_eq!(json["id"], "42");
+        assert_eq!(json["status"], "PROPOSAL_STATUS_VOTING_PERIOD");
+        assert_eq!(json["submit_time"], "2023-11-14T22:13:20.500Z");
+        assert_eq!(json["messages"][0]["@type"], "/cosmos.gov.v1beta1.TextProposal");
+        assert_eq!(json["messages"][0]["title"], "Title");
+        assert_eq!(json["messages"][1]["value"], "AQID");
+        assert_eq!(Proposal::from_proto_json(&json).unwrap(), proposal);
+
+        // The wrapper keeps the binary form, proto3-JSON is opt-in per field.
+        #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+        struct Record {
+            binary: ProtoMessageWrapper<Proposal>,
+            #[serde(with = "crate::api::custom::types::proto_json")]
+            json: ProtoMessageWrapper<Proposal>,
+        }
+        let record = Record {
+            binary: ProtoMessageWrapper(proposal.clone()),
+            json: ProtoMessageWrapper(proposal.clone()),
+        };
+        let serialized = serde_json::to_value(&record).unwrap();
+        assert!(serialized["binary"].is_array());
+        assert_eq!(serialized["json"], json);
+        let decoded: Record = serde_json::from_value(serialized).unwrap();
+        assert_eq!(decoded, record);
+
+        let duration = prost_types::Duration { seconds: 1, nanos: 500_000_000 };
+        assert_eq!(duration.to_proto_json(), "1.5s");
+        assert_eq!(prost_types::Duration::from_proto_json(&duration.to_proto_json()).unwrap(), duration);
+    }
+
+    // cargo test api::custom::types::proto_json::test::test_message_codecs -- --exact --nocapture
+
+    #[test]
+    fn test_message_codecs() {
+        let recover_client = crate::api::custom::types::ibc::proto::MsgRecoverClient {
+            subject_client_id: "07-tendermint-1".to_string(),
+            substitute_client_id: "07-tendermint-2".to_string(),
+            signer: "osmo10d07y265gmmuvt4z0w9aw880jnsr700jjeq4qp".to_string(),
+        };
+        let clear_admin = osmosis_std::types::cosmwasm::wasm::v1::MsgClearAdmin {
+            sender: "osmo10d07y265gmmuvt4z0w9aw880jnsr700jjeq4qp".to_string(),
+            contract: "osmo1contract".to_string(),
+        };
+        let messages = vec![
+            Any {
+                type_url: "/ibc.core.client.v1.MsgRecoverClient".to_string(),
+                value: recover_client.encode_to_vec(),
+            },
+            Any {
+                type_url: "/cosmwasm.wasm.v1.MsgClearAdmin".to_string(),
+                value: osmosis_prost::Message::encode_to_vec(&clear_admin),
+            },
+        ];
+        for (message, field) in messages.iter().zip(["subject_client_id", "contract"]) {
+            let json = message.to_proto_json();
+            println!("{}", json);
+            assert!(json.get(field).is_some());
+            assert!(json.get("value").is_none());
+            assert_eq!(&Any::from_proto_json(&json).unwrap(), message);
+        }
+    }
+}
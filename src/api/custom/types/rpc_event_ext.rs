@@ -0,0 +1,171 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+
+use serde_json::Value;
+
+use crate::api::custom::types::decimal_ext::parse_u128;
+
+// End block event of x/gov, emitted when a proposal leaves the voting period (`active_proposal`)
+// or is dropped at the end of the deposit period (`inactive_proposal`).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ProposalEndEvent {
+    pub proposal_id: u64,
+    // e.g. "proposal_passed", "proposal_rejected", "proposal_failed", "proposal_dropped"
+    pub proposal_result: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum RpcEvent {
+    NewBlock {
+        height: u64,
+        active_proposals: Vec<ProposalEndEvent>,
+        inactive_proposals: Vec<ProposalEndEvent>,
+    },
+    Tx {
+        height: u64,
+        hash: String,
+        senders: Vec<String>,
+        submitted_proposals: Vec<u64>,
+    },
+    Other {
+        event_type: String,
+    },
+}
+
+// A decoded CometBFT websocket event notification.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RpcEventExt {
+    pub query: String,
+    pub event: RpcEvent,
+    // Flattened `<event type>.<attribute key>` → values, as sent by the node.
+    pub attributes: HashMap<String, Vec<String>>,
+}
+
+fn parse_height(value: &Value) -> anyhow::Result<u64> {
+    let height = value
+        .as_str()
+        .ok_or(anyhow::anyhow!("Error: Missing block height."))?;
+    Ok(u64::try_from(parse_u128(height)?)?)
+}
+
+impl RpcEventExt {
+    /// Decodes the `result` of an event notification.
+    pub fn from_rpc_result(result: &Value) -> anyhow::Result<Self> {
+        let attributes: HashMap<String, Vec<String>> = match result.get("events") {
+            Some(events) if !events.is_null() => serde_json::from_value(events.clone())?,
+            _ => HashMap::new(),
+        };
+        let data = result
+            .get("data")
+            .ok_or(anyhow::anyhow!("Error: Missing event data."))?;
+        let event_type = data.get("type").and_then(|t| t.as_str()).unwrap_or_default();
+        let value = &data["value"];
+
+        let mut ext = Self {
+            query: result["query"].as_str().unwrap_or_default().to_string(),
+            event: RpcEvent::Other {
+                event_type: event_type.to_string(),
+            },
+            attributes,
+        };
+        let event = match event_type {
+            "tendermint/event/NewBlock" => RpcEvent::NewBlock {
+                height: parse_height(&value["block"]["header"]["height"])?,
+                active_proposals: ext.proposal_end_events("active_proposal")?,
+                inactive_proposals: ext.proposal_end_events("inactive_proposal")?,
+            },
+            "tendermint/event/Tx" => RpcEvent::Tx {
+                height: parse_height(&value["TxResult"]["height"])?,
+                hash: ext.values("tx.hash").first().cloned().unwrap_or_default(),
+                senders: ext.values("message.sender").to_vec(),
+                submitted_proposals: ext
+                    .values("submit_proposal.proposal_id")
+                    .iter()
+                    .map(|id| Ok(u64::try_from(parse_u128(id)?)?))
+                    .collect::<anyhow::Result<Vec<u64>>>()?,
+            },
+            _ => return Ok(ext),
+        };
+        ext.event = event;
+        Ok(ext)
+    }
+
+    pub fn values(&self, key: &str) -> &[String] {
+        self.attributes.get(key).map(|values| values.as_slice()).unwrap_or(&[])
+    }
+
+    // The proposal ids and results are separate attribute lists of the same length.
+    fn proposal_end_events(&self, event_type: &str) -> anyhow::Result<Vec<ProposalEndEvent>> {
+        let ids = self.values(&format!("{}.proposal_id", event_type));
+        let results = self.values(&format!("{}.proposal_result", event_type));
+        ids.iter()
+            .enumerate()
+            .map(|(index, id)| {
+                Ok(ProposalEndEvent {
+                    proposal_id: u64::try_from(parse_u128(id)?)?,
+                    proposal_result: results.get(index).cloned().unwrap_or_default(),
+                })
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for RpcEventExt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.event {
+            RpcEvent::NewBlock {
+                height,
+                active_proposals,
+                inactive_proposals,
+            } => {
+                write!(f, "New block {}", height)?;
+                for proposal in active_proposals.iter().chain(inactive_proposals) {
+                    write!(f, "\nProposal #{}: {}", proposal.proposal_id, proposal.proposal_result)?;
+                }
+                Ok(())
+            }
+            RpcEvent::Tx { height, hash, .. } => write!(f, "Tx {} at height {}", hash, height),
+            RpcEvent::Other { event_type } => write!(f, "{} ({})", event_type, self.query),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    // cargo test api::custom::types::rpc_event_ext::test::test_from_rpc_result -- --exact --nocapture
+
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_from_rpc_result() {
+        let result = json!({
+            "query": "tm.event='NewBlock'",
+            "data": {"type": "tendermint/event/NewBlock", "value": {"block": {"header": {"height": "100"}}}},
+            "events": {
+                "tm.event": ["NewBlock"],
+                "active_proposal.proposal_id": ["7", "8"],
+                "active_proposal.proposal_result": ["proposal_passed", "proposal_rejected"]
+            }
+        });
+        let event = RpcEventExt::from_rpc_result(&result).unwrap();
+        match event.event {
+            RpcEvent::NewBlock { height, active_proposals, inactive_proposals } => {
+                assert_eq!(height, 100);
+                assert_eq!(active_proposals[1], ProposalEndEvent { proposal_id: 8, proposal_result: "proposal_rejected".to_string() });
+                assert!(inactive_proposals.is_empty());
+            }
+            _ => panic!("expected a NewBlock event"),
+        }
+
+        let result = json!({
+            "query": "submit_proposal.proposal_id EXISTS",
+            "data": {"type": "tendermint/event/Tx", "value": {"TxResult": {"height": "101"}}},
+            "events": {"tx.hash": ["ABC"], "message.sender": ["cosmos1sender"], "submit_proposal.proposal_id": ["9"]}
+        });
+        let event = RpcEventExt::from_rpc_result(&result).unwrap();
+        assert!(matches!(event.event, RpcEvent::Tx { height: 101, ref submitted_proposals, .. } if submitted_proposals == &vec![9]));
+    }
+}
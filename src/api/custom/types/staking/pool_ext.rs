@@ -4,7 +4,8 @@ use crate::api::core::cosmos::channels::SupportedBlockchain;
 use crate::api::custom::types::ProtoMessageWrapper;
 use cosmos_sdk_proto::cosmos::staking::v1beta1::QueryPoolResponse;
 
-use num_format::{Locale, ToFormattedString};
+use crate::api::custom::types::decimal_ext::{parse_u128, ratio, to_percentage};
+use crate::api::custom::i18n::{Localization, Message};
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Hash)]
 pub struct PoolExt {
@@ -19,58 +20,61 @@ impl PoolExt {
             pool: ProtoMessageWrapper(query_pool_response),
         }
     }
-    pub fn get_voter_turnout(&self, proposal_total_votes: Option<f64>) -> Option<String> {
-        let bonded_tokens = self
+    pub fn bonded_tokens(&self) -> anyhow::Result<u128> {
+        let pool = self
             .pool
             .0
             .pool
             .as_ref()
-            .map(|x| x.bonded_tokens.parse::<f64>().ok())
-            .flatten();
-        if let Some(bonded) = bonded_tokens {
-            if let Some(total_votes) = proposal_total_votes {
-                if total_votes == 0f64 || bonded == 0f64 {
-                    None
-                } else {
-                    Some(format!(
-                        "Voter turnout: {:.2}%",
-                        (total_votes / bonded) * 100.0
-                    ))
-                }
-            } else {
-                None
-            }
-        } else {
-            None
-        }
+            .ok_or(anyhow::anyhow!("Error: Staking pool is empty."))?;
+        parse_u128(&pool.bonded_tokens)
     }
-    pub fn get_pool_details(&self) -> Option<String> {
-        let mut output = String::new();
-        let bonded_tokens = self
+    pub fn not_bonded_tokens(&self) -> anyhow::Result<u128> {
+        let pool = self
             .pool
             .0
             .pool
             .as_ref()
-            .map(|x| x.bonded_tokens.parse::<u128>().ok())
-            .flatten();
-        let not_bonded_tokens = self
-            .pool
-            .0
-            .pool
-            .as_ref()
-            .map(|x| x.not_bonded_tokens.parse::<u128>().ok())
-            .flatten();
+            .ok_or(anyhow::anyhow!("Error: Staking pool is empty."))?;
+        parse_u128(&pool.not_bonded_tokens)
+    }
+    pub fn get_voter_turnout(&self, proposal_total_votes: Option<u128>) -> Option<String> {
+        self.get_voter_turnout_localized(proposal_total_votes, &Localization::default())
+    }
+    pub fn get_voter_turnout_localized(
+        &self,
+        proposal_total_votes: Option<u128>,
+        localization: &Localization,
+    ) -> Option<String> {
+        let bonded = self.bonded_tokens().ok()?;
+        match proposal_total_votes {
+            Some(total_votes) if total_votes != 0 && bonded != 0 => Some(localization.format(
+                Message::VoterTurnout,
+                &[&localization.format_decimal(to_percentage(ratio(total_votes, bonded).ok()?), Some(2))],
+            )),
+            _ => None,
+        }
+    }
+    pub fn get_pool_details(&self) -> Option<String> {
+        self.get_pool_details_localized(&Localization::default())
+    }
+    pub fn get_pool_details_localized(&self, localization: &Localization) -> Option<String> {
+        let mut output = String::new();
+        let bonded_tokens = self.bonded_tokens().ok();
+        let not_bonded_tokens = self.not_bonded_tokens().ok();
 
         if let Some(bonded) = bonded_tokens {
             output.push_str(&format!(
-                "\nBonded tokens: \n{}\n",
-                bonded.to_formatted_string(&Locale::en)
+                "\n{}: \n{}\n",
+                localization.text(Message::BondedTokens),
+                localization.format_number(bonded)
             ));
         }
         if let Some(not_bonded) = not_bonded_tokens {
             output.push_str(&format!(
-                "\nNot bonded tokens: \n{}\n",
-                not_bonded.to_formatted_string(&Locale::en)
+                "\n{}: \n{}\n",
+                localization.text(Message::NotBondedTokens),
+                localization.format_number(not_bonded)
             ));
         }
         if output.is_empty() {
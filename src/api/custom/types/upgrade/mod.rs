@@ -0,0 +1 @@
+pub mod plan_ext;
@@ -0,0 +1,152 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::hash::Hash;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+
+use crate::api::custom::i18n::{Localization, Message};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PlanInfo {
+    pub name: String,
+    pub height: i64,
+    pub info: String,
+}
+
+impl PlanInfo {
+    pub fn from_plan(plan: &cosmos_sdk_proto::cosmos::upgrade::v1beta1::Plan) -> Self {
+        Self {
+            name: plan.name.to_owned(),
+            height: plan.height,
+            info: plan.info.to_owned(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UpgradePlanExt {
+    // The plan of the proposal, `None` for a `MsgCancelUpgrade`.
+    pub proposed: Option<PlanInfo>,
+    // The plan currently scheduled in the x/upgrade module.
+    pub scheduled: Option<PlanInfo>,
+    // Set if the scheduled plan could not be queried, `scheduled` is then unknown instead of empty.
+    #[serde(default)]
+    pub scheduled_error: Option<String>,
+    pub latest_height: Option<i64>,
+    // Unix timestamp (seconds) of the latest block.
+    pub latest_time: Option<i64>,
+    pub average_block_time_ms: Option<u64>,
+}
+
+impl UpgradePlanExt {
+    // Extrapolates the time at which the given height is reached, based on the average block time.
+    pub fn estimate_time(&self, height: i64) -> Option<DateTime<Utc>> {
+        let latest_height = self.latest_height?;
+        let latest_time = self.latest_time?;
+        let average_block_time_ms = self.average_block_time_ms? as i64;
+        let remaining_ms = (height - latest_height).checked_mul(average_block_time_ms)?;
+        DateTime::<Utc>::from_timestamp(latest_time + remaining_ms / 1000, 0)
+    }
+
+    fn plan_to_string(&self, plan: &PlanInfo, localization: &Localization) -> String {
+        let mut output = localization.format(Message::UpgradePlan, &[&plan.name, &plan.height.to_string()]);
+        if let Some(latest_height) = self.latest_height {
+            if plan.height <= latest_height {
+                output.push('\n');
+                output.push_str(&localization.format(Message::UpgradeHeightReached, &[&latest_height.to_string()]));
+            } else if let Some(estimate) = self.estimate_time(plan.height) {
+                output.push('\n');
+                output.push_str(&localization.format(
+                    Message::UpgradeEstimatedTime,
+                    &[
+                        &localization.format_datetime(&estimate),
+                        &(plan.height - latest_height).to_string(),
+                    ],
+                ));
+            }
+        }
+        output
+    }
+
+    pub fn to_string_localized(&self, localization: &Localization) -> String {
+        let mut parts = Vec::new();
+        if let Some(proposed) = &self.proposed {
+            parts.push(format!(
+                "{}\n{}",
+                localization.text(Message::ProposedUpgrade),
+                self.plan_to_string(proposed, localization)
+            ));
+        }
+        match &self.scheduled {
+            _ if self.scheduled_error.is_some() => {
+                parts.push(localization.text(Message::ScheduledUpgradeUnknown));
+            }
+            Some(scheduled) => {
+                if self.proposed.as_ref() == Some(scheduled) {
+                    parts.push(localization.text(Message::UpgradeScheduled));
+                } else {
+                    parts.push(format!(
+                        "{}\n{}",
+                        localization.text(Message::ScheduledUpgrade),
+                        self.plan_to_string(scheduled, localization)
+                    ));
+                }
+            }
+            None => parts.push(localization.text(Message::NoUpgradeScheduled)),
+        }
+        if let Some(average_block_time_ms) = self.average_block_time_ms {
+            parts.push(localization.format(
+                Message::AverageBlockTime,
+                &[&localization.format_decimal(Decimal::new(average_block_time_ms as i64, 3), Some(2))],
+            ));
+        }
+        parts.join("\n\n")
+    }
+}
+
+impl fmt::Display for UpgradePlanExt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_string_localized(&Localization::default()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    // cargo test api::custom::types::upgrade::plan_ext::test::test_scheduled_plan -- --exact --nocapture
+
+    use super::*;
+    use crate::api::custom::i18n::Language;
+
+    #[test]
+    fn test_scheduled_plan() {
+        let plan = PlanInfo {
+            name: "v25".to_string(),
+            height: 1_000,
+            info: "".to_string(),
+        };
+        let mut upgrade_plan = UpgradePlanExt {
+            proposed: None,
+            scheduled: None,
+            scheduled_error: None,
+            latest_height: Some(900),
+            latest_time: Some(0),
+            average_block_time_ms: Some(6_000),
+        };
+        assert!(upgrade_plan.to_string().contains("No upgrade is currently scheduled."));
+        assert_eq!(upgrade_plan.estimate_time(1_000).map(|time| time.timestamp()), Some(600));
+
+        upgrade_plan.scheduled_error = Some("status: Unavailable".to_string());
+        let output = upgrade_plan.to_string();
+        assert!(output.contains("Currently scheduled upgrade: unknown"));
+        assert!(!output.contains("No upgrade is currently scheduled."));
+
+        upgrade_plan.scheduled_error = None;
+        upgrade_plan.scheduled = Some(plan);
+        assert!(upgrade_plan.to_string().contains("- Name: v25\n- Height: 1000\n- Estimated time:"));
+        let german = upgrade_plan.to_string_localized(&Localization::new(Language::German));
+        assert!(german.contains("Derzeit geplantes Upgrade:\n- Name: v25\n- Höhe: 1000"));
+        assert!(german.contains("Durchschnittliche Blockzeit: 6,00s"));
+    }
+}
@@ -0,0 +1,322 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::api::custom::types::gov::gov_params_ext::GovParamsExt;
+use crate::api::custom::types::gov::params_ext::ParamsExt;
+use crate::api::custom::types::gov::proposal_ext::ProposalExt;
+use crate::api::custom::types::gov::tally_ext::TallyResultExt;
+use crate::api::custom::types::gov::tally_v1beta1_ext::TallyResultV1Beta1Ext;
+use crate::api::custom::types::staking::pool_ext::PoolExt;
+use crate::api::custom::types::staking::validators_ext::ValidatorsExt;
+
+pub const SCHEMA_VERSION_FIELD: &str = "schema_version";
+pub const DATA_FIELD: &str = "data";
+
+// Snapshots written before the schema version was introduced (plain serde) are version 0.
+pub const UNVERSIONED: u32 = 0;
+
+/// Serialization of a type inside an envelope `{"schema_version": N, "data": ...}`.
+///
+/// On load, `migrate` is applied step by step from the stored version to `SCHEMA_VERSION`,
+/// so that snapshots written by previous crate versions still deserialize.
+/// Bump `SCHEMA_VERSION` and add a migration step whenever a serialized field changes.
+pub trait Versioned: Serialize + DeserializeOwned {
+    const SCHEMA_VERSION: u32;
+
+    /// Migrates the serialized `data` from `from_version` to `from_version + 1`.
+    fn migrate(from_version: u32, data: &mut Value) -> anyhow::Result<()>;
+
+    fn to_versioned_json(&self) -> anyhow::Result<Value> {
+        Ok(json!({
+            SCHEMA_VERSION_FIELD: Self::SCHEMA_VERSION,
+            DATA_FIELD: serde_json::to_value(self)?,
+        }))
+    }
+
+    fn to_versioned_vec(&self) -> anyhow::Result<Vec<u8>> {
+        Ok(serde_json::to_vec(&self.to_versioned_json()?)?)
+    }
+
+    fn from_versioned_json(value: Value) -> anyhow::Result<Self> {
+        let (mut version, mut data) = split_envelope(value)?;
+        if version > Self::SCHEMA_VERSION {
+            return Err(anyhow::anyhow!(
+                "Error: Schema version {} is newer than the supported version {}.",
+                version,
+                Self::SCHEMA_VERSION
+            ));
+        }
+        while version < Self::SCHEMA_VERSION {
+            Self::migrate(version, &mut data)?;
+            version += 1;
+        }
+        Ok(serde_json::from_value(data)?)
+    }
+
+    fn from_versioned_slice(bytes: &[u8]) -> anyhow::Result<Self> {
+        Self::from_versioned_json(serde_json::from_slice(bytes)?)
+    }
+}
+
+fn split_envelope(value: Value) -> anyhow::Result<(u32, Value)> {
+    match value {
+        Value::Object(mut object)
+            if object.len() == 2 && object.contains_key(SCHEMA_VERSION_FIELD) && object.contains_key(DATA_FIELD) =>
+        {
+            let version = object[SCHEMA_VERSION_FIELD]
+                .as_u64()
+                .and_then(|version| u32::try_from(version).ok())
+                .ok_or(anyhow::anyhow!("Error: Invalid schema version."))?;
+            Ok((version, object.remove(DATA_FIELD).unwrap_or(Value::Null)))
+        }
+        value => Ok((UNVERSIONED, value)),
+    }
+}
+
+// `SupportedBlockchain` before version 1: `grpc_service.error: Option<String>` instead of `errors`,
+// and no `rpc_urls`.
+fn migrate_blockchain_v0(blockchain: &mut Value) {
+    if let Some(grpc_service) = blockchain.get_mut("grpc_service").and_then(|g| g.as_object_mut()) {
+        if let Some(error) = grpc_service.remove("error") {
+            grpc_service
+                .entry("errors")
+                .or_insert(Value::Array(error.as_str().map(|e| vec![json!(e)]).unwrap_or_default()));
+        }
+    }
+    if let Some(blockchain) = blockchain.as_object_mut() {
+        blockchain.entry("rpc_urls").or_insert(json!([]));
+    }
+}
+
+// `SupportedBlockchain` before version 2: no `websites`.
+fn migrate_blockchain_v1(blockchain: &mut Value) {
+    if let Some(blockchain) = blockchain.as_object_mut() {
+        blockchain.entry("websites").or_insert(json!([]));
+    }
+}
+
+// Applies `migrate_blockchain` to the `blockchain` field of `data` and of the nested Ext objects at `paths`.
+fn migrate_blockchains(data: &mut Value, paths: &[&str], migrate_blockchain: fn(&mut Value)) {
+    if let Some(blockchain) = data.get_mut("blockchain") {
+        migrate_blockchain(blockchain);
+    }
+    for path in paths {
+        if let Some(blockchain) = data.pointer_mut(&format!("{}/blockchain", path)) {
+            migrate_blockchain(blockchain);
+        }
+    }
+}
+
+// `ProposalExt` before version 1 stored the gov params as three `ParamsExt` (`params.voting_param`,
+// `params.deposit_param` and `params.tallying_param`), they are folded into `params.gov_params`.
+// If one of them is missing or can not be read, `gov_params` is left empty and the params are dropped.
+fn migrate_proposal_gov_params_v0(data: &mut Value) {
+    let params = match data.get_mut("params").and_then(|params| params.as_object_mut()) {
+        Some(params) => params,
+        None => return,
+    };
+    let voting = params.remove("voting_param");
+    let deposit = params.remove("deposit_param");
+    let tallying = params.remove("tallying_param");
+    if params.get("gov_params").map(|gov_params| !gov_params.is_null()).unwrap_or(false) {
+        return;
+    }
+    let parse = |value: Option<Value>| -> Option<ParamsExt> {
+        let mut value = value.filter(|value| !value.is_null())?;
+        migrate_blockchains(&mut value, &[], migrate_blockchain_v0);
+        serde_json::from_value(value).ok()
+    };
+    let gov_params = match (parse(voting), parse(deposit), parse(tallying)) {
+        (Some(voting), Some(deposit), Some(tallying)) => {
+            GovParamsExt::from_params_v1beta1(&voting, &deposit, &tallying)
+                .ok()
+                .and_then(|gov_params| serde_json::to_value(gov_params).ok())
+        }
+        _ => None,
+    };
+    params.insert("gov_params".to_string(), gov_params.unwrap_or(Value::Null));
+}
+
+// Ext objects nested in the `ProposalExt` params, each with its own `blockchain`.
+const PROPOSAL_PARAMS_PATHS: [&str; 3] = ["/params/tally_result", "/params/gov_params", "/params/blockchain_pool"];
+
+// `ProposalExt` before version 3: upgrade plan details without `scheduled_error`.
+fn migrate_proposal_content_details_v2(data: &mut Value) {
+    let content_details = data
+        .pointer_mut("/params/content_details")
+        .and_then(|content_details| content_details.as_array_mut());
+    for content_detail in content_details.into_iter().flatten() {
+        if let Some(upgrade_plan) = content_detail
+            .pointer_mut("/detail/UpgradePlan")
+            .and_then(|upgrade_plan| upgrade_plan.as_object_mut())
+        {
+            upgrade_plan.entry("scheduled_error").or_insert(Value::Null);
+        }
+    }
+}
+
+impl Versioned for ProposalExt {
+    const SCHEMA_VERSION: u32 = 3;
+
+    fn migrate(from_version: u32, data: &mut Value) -> anyhow::Result<()> {
+        match from_version {
+            0 => {
+                migrate_proposal_gov_params_v0(data);
+                migrate_blockchains(data, &PROPOSAL_PARAMS_PATHS, migrate_blockchain_v0);
+                Ok(())
+            }
+            1 => {
+                migrate_blockchains(data, &PROPOSAL_PARAMS_PATHS, migrate_blockchain_v1);
+                Ok(())
+            }
+            2 => {
+                migrate_proposal_content_details_v2(data);
+                Ok(())
+            }
+            _ => Err(anyhow::anyhow!("Error: No migration from schema version {}.", from_version)),
+        }
+    }
+}
+
+// The Ext types whose only `SupportedBlockchain` is the top level `blockchain` share the same migrations.
+macro_rules! impl_versioned {
+    ($($ext:ty),*) => {
+        $(
+            impl Versioned for $ext {
+                const SCHEMA_VERSION: u32 = 2;
+
+                fn migrate(from_version: u32, data: &mut Value) -> anyhow::Result<()> {
+                    match from_version {
+                        0 => {
+                            migrate_blockchains(data, &[], migrate_blockchain_v0);
+                            Ok(())
+                        }
+                        1 => {
+                            migrate_blockchains(data, &[], migrate_blockchain_v1);
+                            Ok(())
+                        }
+                        _ => Err(anyhow::anyhow!("Error: No migration from schema version {}.", from_version)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_versioned!(TallyResultExt, TallyResultV1Beta1Ext, ParamsExt, PoolExt, ValidatorsExt, GovParamsExt);
+
+#[cfg(test)]
+mod test {
+
+    // cargo test api::custom::types::versioned_ext::test::test_versioned_migration -- --exact --nocapture
+    // cargo test api::custom::types::versioned_ext::test::test_proposal_migration -- --exact --nocapture
+
+    use super::*;
+    use crate::api::core::cosmos::channels::test_blockchain;
+    use crate::api::custom::types::gov::gov_params_ext::GovParamsVersion;
+    use cosmos_sdk_proto::cosmos::base::v1beta1::Coin;
+    use cosmos_sdk_proto::cosmos::gov::v1beta1::{DepositParams, QueryParamsResponse, TallyParams, VotingParams};
+    use cosmos_sdk_proto::cosmos::staking::v1beta1::{Pool, QueryPoolResponse};
+    use rust_decimal::Decimal;
+
+    // `SupportedBlockchain` as written by a previous crate version.
+    fn legacy_blockchain() -> Value {
+        json!({
+            "rank": 1,
+            "display": "Osmosis",
+            "name": "osmosis",
+            "prefix": "osmo",
+            "grpc_service": {"grpc_urls": [], "error": "no url"},
+            "governance_proposals_link": ""
+        })
+    }
+
+    fn legacy_params(params_type: &str, params: QueryParamsResponse) -> Value {
+        let mut value = serde_json::to_value(ParamsExt::new(test_blockchain(), params_type, params)).unwrap();
+        value["blockchain"] = legacy_blockchain();
+        value
+    }
+
+    #[test]
+    fn test_versioned_migration() {
+        let pool = QueryPoolResponse {
+            pool: Some(Pool {
+                not_bonded_tokens: "10".to_string(),
+                bonded_tokens: "90".to_string(),
+            }),
+        };
+        // Snapshot written by a previous crate version, without envelope and with `grpc_service.error`.
+        let legacy = json!({
+            "blockchain": legacy_blockchain(),
+            "pool": serde_json::to_value(crate::api::custom::types::ProtoMessageWrapper(pool.clone())).unwrap()
+        });
+        let migrated = PoolExt::from_versioned_json(legacy).unwrap();
+        assert_eq!(migrated.blockchain.grpc_service.errors, vec!["no url".to_string()]);
+        assert!(migrated.blockchain.websites.is_empty());
+        assert_eq!(migrated.pool.0, pool);
+
+        let versioned = migrated.to_versioned_json().unwrap();
+        assert_eq!(versioned[SCHEMA_VERSION_FIELD], 2);
+        assert_eq!(versioned[DATA_FIELD]["blockchain"]["websites"], json!([]));
+        assert_eq!(PoolExt::from_versioned_json(versioned).unwrap(), migrated);
+
+        let future = json!({SCHEMA_VERSION_FIELD: 3, DATA_FIELD: {}});
+        assert!(PoolExt::from_versioned_json(future).is_err());
+    }
+
+    #[test]
+    fn test_proposal_migration() {
+        let proposal = ProposalExt::new(
+            &test_blockchain(),
+            cosmos_sdk_proto::cosmos::gov::v1::Proposal { id: 7, ..Default::default() },
+        );
+        // Snapshot of a previous crate version, with the gov params as three `ParamsExt`.
+        let mut legacy = serde_json::to_value(&proposal).unwrap();
+        legacy["blockchain"] = legacy_blockchain();
+        legacy["params"] = json!({
+            "fraud_classification": 0.1,
+            "tally_result": null,
+            "voting_param": legacy_params("voting", QueryParamsResponse {
+                voting_params: Some(VotingParams {
+                    voting_period: Some(prost_types::Duration { seconds: 432000, nanos: 0 }),
+                }),
+                ..Default::default()
+            }),
+            "deposit_param": legacy_params("deposit", QueryParamsResponse {
+                deposit_params: Some(DepositParams {
+                    min_deposit: vec![Coin { denom: "uosmo".to_string(), amount: "500000000".to_string() }],
+                    max_deposit_period: Some(prost_types::Duration { seconds: 1209600, nanos: 0 }),
+                }),
+                ..Default::default()
+            }),
+            "tallying_param": legacy_params("tallying", QueryParamsResponse {
+                tally_params: Some(TallyParams {
+                    quorum: b"200000000000000000".to_vec(),
+                    threshold: b"500000000000000000".to_vec(),
+                    veto_threshold: b"334000000000000000".to_vec(),
+                }),
+                ..Default::default()
+            }),
+            "blockchain_pool": null
+        });
+
+        let migrated = ProposalExt::from_versioned_json(legacy.clone()).unwrap();
+        assert_eq!(migrated.get_proposal_id(), 7);
+        assert_eq!(migrated.blockchain.grpc_service.errors, vec!["no url".to_string()]);
+        let params = migrated.params.unwrap();
+        assert_eq!(params.fraud_classification, Some(0.1));
+        let gov_params = params.gov_params.unwrap();
+        assert_eq!(gov_params.version, GovParamsVersion::V1Beta1);
+        assert_eq!(gov_params.blockchain.grpc_service.errors, vec!["no url".to_string()]);
+        assert_eq!(gov_params.min_deposit_amount("uosmo", false), Some(500000000));
+        assert_eq!(gov_params.voting_period, Some(std::time::Duration::from_secs(432000)));
+        assert_eq!(gov_params.quorum, Decimal::new(2, 1));
+        assert_eq!(gov_params.veto_threshold, Decimal::new(334, 3));
+
+        // Incomplete legacy params are dropped.
+        legacy["params"]["tallying_param"] = Value::Null;
+        let migrated = ProposalExt::from_versioned_json(legacy).unwrap();
+        assert_eq!(migrated.params.unwrap().gov_params, None);
+    }
+}
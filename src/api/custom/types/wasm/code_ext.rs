@@ -0,0 +1,216 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::hash::Hash;
+use std::collections::HashMap;
+use std::io::Read;
+use std::sync::Mutex;
+
+use crypto::digest::Digest;
+use crypto::sha2::Sha256;
+
+// Same limit as wasmd's `MaxWasmSize` default (800 KiB) with some headroom, protects against gzip bombs.
+const MAX_WASM_SIZE: u64 = 3 * 1024 * 1024;
+
+// Number of inspected codes kept by `from_wasm_byte_code_cached`, the cache is reset when full.
+const WASM_CODE_CACHE_SIZE: usize = 32;
+
+lazy_static::lazy_static! {
+    // Keyed by the sha256 of the byte code as uploaded (possibly gzipped).
+    static ref WASM_CODE_CACHE: Mutex<HashMap<String, Result<WasmCodeMetadata, String>>> = Mutex::new(HashMap::new());
+}
+
+const ENTRY_POINTS: [&str; 13] = [
+    "instantiate",
+    "execute",
+    "query",
+    "migrate",
+    "sudo",
+    "reply",
+    "ibc_channel_open",
+    "ibc_channel_connect",
+    "ibc_channel_close",
+    "ibc_packet_receive",
+    "ibc_packet_ack",
+    "ibc_packet_timeout",
+    "ibc_source_callback",
+];
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WasmCodeMetadata {
+    // Hex encoded sha256 of the uncompressed wasm bytecode, as computed by wasmd and `sha256sum`.
+    pub checksum: String,
+    pub gzipped: bool,
+    pub size: usize,
+    pub exports: Vec<String>,
+    pub entry_points: Vec<String>,
+    // e.g. `interface_version_8` for cosmwasm-std 1.x contracts.
+    pub interface_version: Option<String>,
+    // Capabilities the contract requires from the chain, e.g. `iterator`, `stargate` or `cosmwasm_1_2`.
+    pub required_capabilities: Vec<String>,
+}
+
+impl WasmCodeMetadata {
+    /// Decompresses (if gzipped) and inspects the given wasm byte code.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the byte code can not be decompressed or is not a valid wasm module.
+    pub fn from_wasm_byte_code(wasm_byte_code: &[u8]) -> anyhow::Result<Self> {
+        let gzipped = wasm_byte_code.starts_with(&[0x1f, 0x8b]);
+        let wasm = if gzipped {
+            // One byte more than allowed, to tell a code of exactly `MAX_WASM_SIZE` from a larger one.
+            let mut wasm = Vec::new();
+            flate2::read::GzDecoder::new(wasm_byte_code)
+                .take(MAX_WASM_SIZE + 1)
+                .read_to_end(&mut wasm)?;
+            wasm
+        } else {
+            wasm_byte_code.to_vec()
+        };
+        if wasm.len() as u64 > MAX_WASM_SIZE {
+            return Err(anyhow::anyhow!(
+                "Error: Wasm code exceeds the maximum size of {} bytes.",
+                MAX_WASM_SIZE
+            ));
+        }
+
+        let mut sha = Sha256::new();
+        sha.input(&wasm);
+        let checksum = sha.result_str();
+
+        let mut exports = Vec::new();
+        for payload in wasmparser::Parser::new(0).parse_all(&wasm) {
+            if let wasmparser::Payload::ExportSection(reader) = payload? {
+                for export in reader {
+                    let export = export?;
+                    if export.kind == wasmparser::ExternalKind::Func {
+                        exports.push(export.name.to_string());
+                    }
+                }
+            }
+        }
+
+        let entry_points = exports
+            .iter()
+            .filter(|export| ENTRY_POINTS.contains(&export.as_str()))
+            .cloned()
+            .collect();
+        let interface_version = exports
+            .iter()
+            .find(|export| export.starts_with("interface_version_") || export.starts_with("cosmwasm_vm_version_"))
+            .cloned();
+        let required_capabilities = exports
+            .iter()
+            .filter_map(|export| export.strip_prefix("requires_"))
+            .map(|capability| capability.to_string())
+            .collect();
+
+        Ok(Self {
+            checksum,
+            gzipped,
+            size: wasm.len(),
+            exports,
+            entry_points,
+            interface_version,
+            required_capabilities,
+        })
+    }
+
+    /// Like `from_wasm_byte_code`, but inspects each byte code only once per process.
+    ///
+    /// Proposal descriptions are rebuilt from the raw messages on every call, this avoids
+    /// decompressing and parsing the same code again for every preview, render or scan.
+    pub fn from_wasm_byte_code_cached(wasm_byte_code: &[u8]) -> anyhow::Result<Self> {
+        let mut sha = Sha256::new();
+        sha.input(wasm_byte_code);
+        let key = sha.result_str();
+        if let Some(cached) = WASM_CODE_CACHE.lock().unwrap().get(&key) {
+            return cached.clone().map_err(|err| anyhow::anyhow!(err));
+        }
+        let result = Self::from_wasm_byte_code(wasm_byte_code);
+        let mut cache = WASM_CODE_CACHE.lock().unwrap();
+        if cache.len() >= WASM_CODE_CACHE_SIZE {
+            cache.clear();
+        }
+        cache.insert(key, result.as_ref().cloned().map_err(|err| err.to_string()));
+        result
+    }
+
+    pub fn matches_checksum(&self, checksum: &str) -> bool {
+        self.checksum.eq_ignore_ascii_case(checksum.trim())
+    }
+}
+
+impl fmt::Display for WasmCodeMetadata {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Wasm code:\n- Checksum (sha256): {}\n- Size: {} bytes{}\n- Interface version: {}\n- Entry points: {}",
+            self.checksum,
+            self.size,
+            if self.gzipped { " (uploaded gzipped)" } else { "" },
+            self.interface_version.as_deref().unwrap_or("unknown"),
+            self.entry_points.join(", ")
+        )?;
+        if !self.required_capabilities.is_empty() {
+            write!(f, "\n- Required capabilities: {}", self.required_capabilities.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    // cargo test api::custom::types::wasm::code_ext::test::test_from_wasm_byte_code -- --exact --nocapture
+
+    use super::*;
+    use std::io::Write;
+
+    fn section(id: u8, content: Vec<u8>) -> Vec<u8> {
+        let mut section = vec![id, content.len() as u8];
+        section.extend(content);
+        section
+    }
+
+    fn minimal_contract() -> Vec<u8> {
+        let mut wasm = b"\0asm\x01\0\0\0".to_vec();
+        wasm.extend(section(1, vec![1, 0x60, 0, 0]));
+        wasm.extend(section(3, vec![1, 0]));
+        let mut exports = vec![3];
+        for name in ["instantiate", "interface_version_8", "requires_iterator"] {
+            exports.push(name.len() as u8);
+            exports.extend(name.as_bytes());
+            exports.extend([0, 0]);
+        }
+        wasm.extend(section(7, exports));
+        wasm.extend(section(10, vec![1, 2, 0, 0x0b]));
+        wasm
+    }
+
+    #[test]
+    fn test_from_wasm_byte_code() {
+        let wasm = minimal_contract();
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(&wasm).unwrap();
+        let gzipped = encoder.finish().unwrap();
+
+        let raw = WasmCodeMetadata::from_wasm_byte_code(&wasm).unwrap();
+        let decompressed = WasmCodeMetadata::from_wasm_byte_code(&gzipped).unwrap();
+        println!("{}", decompressed);
+
+        assert!(!raw.gzipped && decompressed.gzipped);
+        assert_eq!(raw.checksum, decompressed.checksum);
+        assert_eq!(decompressed.size, wasm.len());
+        assert_eq!(decompressed.entry_points, vec!["instantiate".to_string()]);
+        assert_eq!(decompressed.interface_version.as_deref(), Some("interface_version_8"));
+        assert_eq!(decompressed.required_capabilities, vec!["iterator".to_string()]);
+        assert_eq!(WasmCodeMetadata::from_wasm_byte_code_cached(&gzipped).unwrap(), decompressed);
+        assert_eq!(WasmCodeMetadata::from_wasm_byte_code_cached(&gzipped).unwrap(), decompressed);
+
+        // Oversized code is rejected instead of being truncated.
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(&vec![0u8; MAX_WASM_SIZE as usize + 1]).unwrap();
+        assert!(WasmCodeMetadata::from_wasm_byte_code(&encoder.finish().unwrap()).is_err());
+    }
+}
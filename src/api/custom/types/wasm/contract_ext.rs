@@ -0,0 +1,145 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::hash::Hash;
+
+// The contract and code a wasm proposal message refers to.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct WasmTarget {
+    pub contract: Option<String>,
+    pub code_id: Option<u64>,
+    pub new_admin: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CodeInfoExt {
+    pub code_id: u64,
+    pub creator: String,
+    // Hex encoded sha256 of the stored wasm bytecode.
+    pub checksum: String,
+}
+
+impl CodeInfoExt {
+    pub fn from_code_info_response(
+        code_info: &cosmos_sdk_proto::cosmwasm::wasm::v1::CodeInfoResponse,
+    ) -> Self {
+        Self {
+            code_id: code_info.code_id,
+            creator: code_info.creator.to_owned(),
+            checksum: hex::encode(&code_info.data_hash),
+        }
+    }
+}
+
+impl fmt::Display for CodeInfoExt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Code ID {}\n- Creator: {}\n- Checksum: {}",
+            self.code_id, self.creator, self.checksum
+        )
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ContractHistoryEntry {
+    pub operation: String,
+    pub code_id: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ContractInfoExt {
+    pub address: String,
+    pub label: String,
+    pub creator: String,
+    pub admin: String,
+    pub code_id: u64,
+    pub history: Vec<ContractHistoryEntry>,
+}
+
+impl ContractInfoExt {
+    pub fn from_contract_info_response(
+        res: &cosmos_sdk_proto::cosmwasm::wasm::v1::QueryContractInfoResponse,
+    ) -> Option<Self> {
+        res.contract_info.as_ref().map(|info| Self {
+            address: res.address.to_owned(),
+            label: info.label.to_owned(),
+            creator: info.creator.to_owned(),
+            admin: info.admin.to_owned(),
+            code_id: info.code_id,
+            history: Vec::new(),
+        })
+    }
+
+    pub fn migration_count(&self) -> usize {
+        self.history
+            .iter()
+            .filter(|entry| entry.operation.contains("MIGRATE"))
+            .count()
+    }
+}
+
+impl fmt::Display for ContractInfoExt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Contract {}\n- Label: {}\n- Creator: {}\n- Admin: {}\n- Current Code ID: {}",
+            self.address,
+            self.label,
+            self.creator,
+            if self.admin.is_empty() { "none" } else { &self.admin },
+            self.code_id
+        )?;
+        if !self.history.is_empty() {
+            write!(f, "\n- Migrations: {}", self.migration_count())?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WasmDetailsExt {
+    pub contract: Option<ContractInfoExt>,
+    // Code currently used by the contract.
+    pub current_code: Option<CodeInfoExt>,
+    // Code referenced by the proposal, e.g. the migration target or the code to instantiate.
+    pub target_code: Option<CodeInfoExt>,
+    pub new_admin: Option<String>,
+}
+
+impl WasmDetailsExt {
+    pub fn is_migration(&self) -> bool {
+        match (&self.contract, &self.target_code) {
+            (Some(contract), Some(target)) => contract.code_id != target.code_id,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for WasmDetailsExt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        if let Some(contract) = &self.contract {
+            parts.push(contract.to_string());
+        }
+        if let Some(current_code) = &self.current_code {
+            parts.push(format!("Current code:\n{}", current_code));
+        }
+        if let Some(target_code) = &self.target_code {
+            if self.is_migration() {
+                parts.push(format!("Migration target:\n{}", target_code));
+            } else if self.contract.is_none() {
+                parts.push(format!("Code:\n{}", target_code));
+            }
+        }
+        if let Some(new_admin) = &self.new_admin {
+            let current_admin = self
+                .contract
+                .as_ref()
+                .map(|contract| contract.admin.as_str())
+                .filter(|admin| !admin.is_empty())
+                .unwrap_or("none");
+            parts.push(format!("Admin change: {} → {}", current_admin, if new_admin.is_empty() { "none" } else { new_admin }));
+        }
+        write!(f, "{}", parts.join("\n\n"))
+    }
+}
@@ -0,0 +1,2 @@
+pub mod code_ext;
+pub mod contract_ext;
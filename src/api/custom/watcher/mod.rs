@@ -0,0 +1,363 @@
+use std::collections::{HashMap, HashSet};
+use std::pin::Pin;
+use std::time::Duration;
+
+use chrono::{TimeZone, Utc};
+use futures::stream::Stream;
+use futures::task::*;
+use log::error;
+use rust_decimal::Decimal;
+use tokio::sync::mpsc;
+
+use crate::api::core::cosmos::channels::SupportedBlockchain;
+use crate::api::custom::query::gov::{get_active_proposals, get_proposal};
+use crate::api::custom::types::gov::common::ProposalStatus;
+use crate::api::custom::types::gov::proposal_diff::{diff_with_threshold, ProposalChange, DEFAULT_TALLY_THRESHOLD};
+use crate::api::custom::types::gov::proposal_ext::ProposalExt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GovernanceEvent {
+    NewProposal(ProposalExt),
+    EnteredVotingPeriod(ProposalExt),
+    // The live tally moved by at least the tally threshold of the watcher.
+    TallyUpdated(ProposalExt, Vec<ProposalChange>),
+    // Emitted once per proposal, with the time left until the voting period ends.
+    VotingEndingSoon(ProposalExt, Duration),
+    Passed(ProposalExt),
+    Rejected(ProposalExt),
+    Failed(ProposalExt),
+    // The proposal did not reach the min deposit in time and was removed by the chain.
+    DepositPeriodExpired(ProposalExt),
+    // Polling the chain failed, the last known state is kept and polled again next round.
+    PollFailed { blockchain: String, error: String },
+}
+
+type ProposalKey = (String, u64);
+
+fn proposal_key(proposal: &ProposalExt) -> ProposalKey {
+    (proposal.blockchain.get_name(), proposal.get_proposal_id())
+}
+
+// Time left until the voting period ends, `None` if it already ended or the end time is unknown.
+fn voting_time_remaining(proposal: &ProposalExt, now: i64) -> Option<Duration> {
+    let now = Utc.timestamp_opt(now, 0).single()?;
+    proposal
+        .time_remaining_at(now)?
+        .to_std()
+        .ok()
+        .filter(|remaining| !remaining.is_zero())
+}
+
+/// Polls the deposit and voting period proposals of a set of chains and turns the changes
+/// between two polls into `GovernanceEvent`s.
+///
+/// The first poll of a chain only records its state, events are emitted from the second poll on.
+pub struct GovernanceWatcher {
+    blockchains: Vec<SupportedBlockchain>,
+    poll_interval: Duration,
+    // Upper bound for polling one chain, so that a stuck chain can not stall the others.
+    poll_timeout: Duration,
+    ending_soon: Duration,
+    tally_threshold: Decimal,
+    known: HashMap<ProposalKey, ProposalExt>,
+    initialized: HashSet<String>,
+    ending_soon_sent: HashSet<ProposalKey>,
+    // Snapshot of the last reported tally, so that slow movements add up to the threshold.
+    tally_baseline: HashMap<ProposalKey, ProposalExt>,
+}
+
+impl GovernanceWatcher {
+    pub fn new(blockchains: Vec<SupportedBlockchain>) -> Self {
+        Self {
+            blockchains,
+            poll_interval: Duration::from_secs(60 * 5),
+            poll_timeout: Duration::from_secs(60 * 2),
+            ending_soon: Duration::from_secs(60 * 60 * 24),
+            tally_threshold: DEFAULT_TALLY_THRESHOLD,
+            known: HashMap::new(),
+            initialized: HashSet::new(),
+            ending_soon_sent: HashSet::new(),
+            tally_baseline: HashMap::new(),
+        }
+    }
+
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// How long polling the proposals of one chain may take before it is reported as `PollFailed`.
+    pub fn poll_timeout(mut self, poll_timeout: Duration) -> Self {
+        self.poll_timeout = poll_timeout;
+        self
+    }
+
+    /// How long before the end of the voting period `VotingEndingSoon` is emitted.
+    pub fn ending_soon(mut self, ending_soon: Duration) -> Self {
+        self.ending_soon = ending_soon;
+        self
+    }
+
+    pub fn tally_threshold(mut self, tally_threshold: Decimal) -> Self {
+        self.tally_threshold = tally_threshold;
+        self
+    }
+
+    /// The last known deposit and voting period proposals of all watched chains.
+    pub fn known_proposals(&self) -> Vec<&ProposalExt> {
+        self.known.values().collect()
+    }
+
+    /// Polls all chains once and returns the events since the previous poll.
+    pub async fn poll(&mut self) -> Vec<GovernanceEvent> {
+        let mut events = Vec::new();
+        for blockchain in self.blockchains.clone() {
+            let active = tokio::time::timeout(self.poll_timeout, get_active_proposals(blockchain.clone()))
+                .await
+                .map_err(|_| format!("Error: Polling timed out after {:?}.", self.poll_timeout))
+                .and_then(|res| res.map_err(|err| err.to_string()));
+            match active {
+                Ok(active) => {
+                    let (mut chain_events, missing) = self.apply(&blockchain, active, Utc::now().timestamp());
+                    for old in missing {
+                        match get_proposal(blockchain.clone(), old.get_proposal_id()).await {
+                            Ok(current) => chain_events.extend(self.resolve_missing(old, current)),
+                            Err(err) if err.code() == tonic::Code::NotFound => {
+                                chain_events.extend(self.resolve_missing(old, None))
+                            }
+                            Err(err) => {
+                                // Keep the proposal, it is resolved with the next poll.
+                                error!("Failed to query proposal {} of {}: {}", old.get_proposal_id(), blockchain.name, err);
+                                self.known.insert(proposal_key(&old), old);
+                            }
+                        }
+                    }
+                    events.append(&mut chain_events);
+                }
+                Err(err) => {
+                    error!("Failed to poll proposals of {}: {}", blockchain.name, err);
+                    events.push(GovernanceEvent::PollFailed {
+                        blockchain: blockchain.get_name(),
+                        error: err,
+                    });
+                }
+            }
+        }
+        events
+    }
+
+    /// Polls on the configured schedule and emits the events as a stream.
+    /// Polling stops when the stream is dropped.
+    pub fn watch(mut self) -> GovernanceEventStream {
+        let (sender, receiver) = mpsc::channel(1000);
+        let handle = tokio::spawn(async move {
+            let mut interval = tokio::time::interval(self.poll_interval);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                interval.tick().await;
+                for event in self.poll().await {
+                    if sender.send(event).await.is_err() {
+                        return;
+                    }
+                }
+            }
+        });
+        GovernanceEventStream { receiver, handle }
+    }
+
+    // Updates the known state of a chain with its current deposit and voting period proposals.
+    // Returns the events and the known proposals that are no longer active.
+    fn apply(
+        &mut self,
+        blockchain: &SupportedBlockchain,
+        active: Vec<ProposalExt>,
+        now: i64,
+    ) -> (Vec<GovernanceEvent>, Vec<ProposalExt>) {
+        let chain_name = blockchain.get_name();
+        let emit = self.initialized.contains(&chain_name);
+        let mut events = Vec::new();
+        let mut active_keys = HashSet::new();
+
+        for proposal in active {
+            let key = proposal_key(&proposal);
+            active_keys.insert(key.clone());
+            if emit {
+                match self.known.get(&key) {
+                    None => events.push(GovernanceEvent::NewProposal(proposal.clone())),
+                    Some(old) => {
+                        if !old.is_in_voting_period() && proposal.is_in_voting_period() {
+                            events.push(GovernanceEvent::EnteredVotingPeriod(proposal.clone()));
+                        } else if let Some(baseline) = self.tally_baseline.get(&key) {
+                            let tally_changes: Vec<ProposalChange> =
+                                diff_with_threshold(baseline, &proposal, self.tally_threshold)
+                                    .into_iter()
+                                    .filter(|change| matches!(change, ProposalChange::TallyMoved { .. }))
+                                    .collect();
+                            if !tally_changes.is_empty() {
+                                self.tally_baseline.insert(key.clone(), proposal.clone());
+                                events.push(GovernanceEvent::TallyUpdated(proposal.clone(), tally_changes));
+                            }
+                        }
+                    }
+                }
+            }
+            if proposal.is_in_voting_period() && !self.ending_soon_sent.contains(&key) {
+                if let Some(remaining) = voting_time_remaining(&proposal, now) {
+                    if remaining <= self.ending_soon {
+                        self.ending_soon_sent.insert(key.clone());
+                        if emit {
+                            events.push(GovernanceEvent::VotingEndingSoon(proposal.clone(), remaining));
+                        }
+                    }
+                }
+            }
+            if proposal.is_in_voting_period() && !self.tally_baseline.contains_key(&key) {
+                self.tally_baseline.insert(key.clone(), proposal.clone());
+            }
+            self.known.insert(key, proposal);
+        }
+
+        let missing_keys: Vec<ProposalKey> = self
+            .known
+            .keys()
+            .filter(|key| key.0 == chain_name && !active_keys.contains(*key))
+            .cloned()
+            .collect();
+        let missing = missing_keys
+            .iter()
+            .filter_map(|key| self.known.remove(key))
+            .collect();
+        self.initialized.insert(chain_name);
+        (events, missing)
+    }
+
+    // Turns a proposal that left the deposit or voting period into its final event.
+    fn resolve_missing(&mut self, old: ProposalExt, current: Option<ProposalExt>) -> Option<GovernanceEvent> {
+        let key = proposal_key(&old);
+        let event = match current {
+            None if old.is_in_deposit_period() => Some(GovernanceEvent::DepositPeriodExpired(old)),
+            None => None,
+            Some(current) => match current.get_proposal_status() {
+                ProposalStatus::StatusPassed => Some(GovernanceEvent::Passed(current)),
+                ProposalStatus::StatusRejected => Some(GovernanceEvent::Rejected(current)),
+                ProposalStatus::StatusFailed => Some(GovernanceEvent::Failed(current)),
+                ProposalStatus::StatusDepositPeriod | ProposalStatus::StatusVotingPeriod => {
+                    // Still active, it was missed by the paginated query.
+                    self.known.insert(key.clone(), current);
+                    return None;
+                }
+                ProposalStatus::StatusNil => None,
+            },
+        };
+        self.ending_soon_sent.remove(&key);
+        self.tally_baseline.remove(&key);
+        event
+    }
+}
+
+pub struct GovernanceEventStream {
+    receiver: mpsc::Receiver<GovernanceEvent>,
+    handle: tokio::task::JoinHandle<()>,
+}
+
+impl Stream for GovernanceEventStream {
+    type Item = GovernanceEvent;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+impl Drop for GovernanceEventStream {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    // cargo test api::custom::watcher::test::test_governance_watcher_events -- --exact --nocapture
+    // cargo test api::custom::watcher::test::test_poll_timeout -- --exact --nocapture
+
+    use super::*;
+    use crate::api::core::cosmos::channels::test_blockchain;
+    use cosmos_sdk_proto::cosmos::gov::v1::{Proposal, TallyResult};
+
+    fn proposal(blockchain: &SupportedBlockchain, id: u64, status: ProposalStatus, yes: &str, end: i64) -> ProposalExt {
+        ProposalExt::new(
+            blockchain,
+            Proposal {
+                id,
+                status: status.into(),
+                final_tally_result: Some(TallyResult {
+                    yes_count: yes.to_string(),
+                    abstain_count: "0".to_string(),
+                    no_count: "10".to_string(),
+                    no_with_veto_count: "0".to_string(),
+                }),
+                voting_end_time: Some(prost_types::Timestamp { seconds: end, nanos: 0 }),
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn test_governance_watcher_events() {
+        let blockchain = test_blockchain();
+        let mut watcher = GovernanceWatcher::new(vec![blockchain.clone()]).ending_soon(Duration::from_secs(3600));
+        let day = 60 * 60 * 24;
+
+        let (events, _) = watcher.apply(
+            &blockchain,
+            vec![proposal(&blockchain, 1, ProposalStatus::StatusDepositPeriod, "0", 0)],
+            0,
+        );
+        assert!(events.is_empty());
+
+        let (events, _) = watcher.apply(
+            &blockchain,
+            vec![
+                proposal(&blockchain, 1, ProposalStatus::StatusVotingPeriod, "10", day),
+                proposal(&blockchain, 2, ProposalStatus::StatusDepositPeriod, "0", 0),
+            ],
+            0,
+        );
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[0], GovernanceEvent::EnteredVotingPeriod(_)));
+        assert!(matches!(events[1], GovernanceEvent::NewProposal(_)));
+
+        let (events, missing) = watcher.apply(
+            &blockchain,
+            vec![proposal(&blockchain, 1, ProposalStatus::StatusVotingPeriod, "30", day)],
+            day - 60,
+        );
+        assert!(matches!(events[0], GovernanceEvent::TallyUpdated(_, _)));
+        assert!(matches!(events[1], GovernanceEvent::VotingEndingSoon(_, remaining) if remaining == Duration::from_secs(60)));
+        assert_eq!(missing.len(), 1);
+        assert!(matches!(
+            watcher.resolve_missing(missing[0].clone(), None),
+            Some(GovernanceEvent::DepositPeriodExpired(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_poll_timeout() {
+        // A node that accepts connections but never answers.
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut connections = Vec::new();
+            while let Ok((stream, _)) = listener.accept().await {
+                connections.push(stream);
+            }
+        });
+        let mut blockchain = test_blockchain();
+        blockchain.grpc_service.grpc_urls = vec![format!("http://{}", address)];
+
+        let mut watcher = GovernanceWatcher::new(vec![blockchain]).poll_timeout(Duration::from_millis(200));
+        let events = tokio::time::timeout(Duration::from_secs(10), watcher.poll()).await.unwrap();
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], GovernanceEvent::PollFailed { .. }));
+    }
+}